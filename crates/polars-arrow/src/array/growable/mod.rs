@@ -113,6 +113,8 @@ pub fn make_growable<'a>(
             use_validity,
             capacity
         ),
+        Utf8 => dyn_growable!(utf8::GrowableUtf8::<i32>, arrays, use_validity, capacity),
+        LargeUtf8 => dyn_growable!(utf8::GrowableUtf8::<i64>, arrays, use_validity, capacity),
         FixedSizeBinary => dyn_growable!(
             fixed_binary::GrowableFixedSizeBinary,
             arrays,
@@ -161,6 +163,6 @@ pub fn make_growable<'a>(
                 ))
             })
         },
-        Union | Map | Utf8 | LargeUtf8 | List => unimplemented!(),
+        Union | Map | List => unimplemented!(),
     }
 }
@@ -8,6 +8,7 @@ use polars_utils::pl_str::PlSmallStr;
 use super::deserialize::{read, skip};
 use super::Dictionaries;
 use crate::array::*;
+use crate::compute::concatenate::concatenate;
 use crate::datatypes::{ArrowDataType, ArrowSchema, Field};
 use crate::io::ipc::read::OutOfSpecKind;
 use crate::io::ipc::{IpcField, IpcSchema};
@@ -256,12 +257,9 @@ pub fn read_dictionary<R: Read + Seek>(
     file_size: u64,
     scratch: &mut Vec<u8>,
 ) -> PolarsResult<()> {
-    if batch
+    let is_delta = batch
         .is_delta()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?
-    {
-        polars_bail!(ComputeError: "delta dictionary batches not supported")
-    }
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?;
 
     let id = batch
         .id()
@@ -304,7 +302,17 @@ pub fn read_dictionary<R: Read + Seek>(
         scratch,
     )?;
 
-    dictionaries.insert(id, chunk.into_arrays().pop().unwrap());
+    let values = chunk.into_arrays().pop().unwrap();
+    let values = if is_delta {
+        // A delta dictionary batch appends values to the dictionary with the same id.
+        let Some(previous) = dictionaries.get(&id) else {
+            polars_bail!(ComputeError: "delta dictionary batch for dictionary {id} that was not read before")
+        };
+        concatenate(&[previous.as_ref(), values.as_ref()])?
+    } else {
+        values
+    };
+    dictionaries.insert(id, values);
 
     Ok(())
 }
@@ -5,13 +5,14 @@ use arrow_format::ipc::planus::ReadAsRoot;
 use arrow_format::ipc::FooterRef;
 use polars_error::{polars_bail, polars_err, PolarsResult};
 use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::pl_str::PlSmallStr;
 
 use super::super::{ARROW_MAGIC_V1, ARROW_MAGIC_V2, CONTINUATION_MARKER};
 use super::common::*;
 use super::schema::fb_to_schema;
 use super::{Dictionaries, OutOfSpecKind};
 use crate::array::Array;
-use crate::datatypes::ArrowSchemaRef;
+use crate::datatypes::{ArrowSchemaRef, Metadata};
 use crate::io::ipc::IpcSchema;
 use crate::record_batch::RecordBatchT;
 
@@ -282,6 +283,29 @@ fn get_message_from_block<'a, R: Read + Seek>(
     get_message_from_block_offset(reader, offset, message_scratch)
 }
 
+/// Reads the length and the custom metadata of the record batch at position `index`, without
+/// reading its body.
+pub fn read_batch_custom_metadata<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    index: usize,
+    message_scratch: &mut Vec<u8>,
+) -> PolarsResult<(i64, Metadata)> {
+    let message = get_message_from_block(reader, &metadata.blocks[index], message_scratch)?;
+    let length = get_record_batch(message)?.length()?;
+
+    let mut custom_metadata = Metadata::new();
+    if let Some(list) = message.custom_metadata()? {
+        for kv in list {
+            let kv = kv?;
+            if let (Some(k), Some(v)) = (kv.key()?, kv.value()?) {
+                custom_metadata.insert(PlSmallStr::from_str(k), PlSmallStr::from_str(v));
+            }
+        }
+    }
+    Ok((length, custom_metadata))
+}
+
 /// Reads the record batch at position `index` from the reader.
 ///
 /// This function is useful for random access to the file. For example, if
@@ -31,12 +31,15 @@ pub(crate) use common::first_dict_field;
 #[cfg(feature = "io_flight")]
 pub(crate) use common::{read_dictionary, read_record_batch};
 pub use file::{
-    deserialize_footer, read_batch, read_file_dictionaries, read_file_metadata, FileMetadata,
+    deserialize_footer, read_batch, read_batch_custom_metadata, read_file_dictionaries,
+    read_file_metadata, FileMetadata,
 };
 use polars_utils::aliases::PlHashMap;
 pub use reader::FileReader;
 pub use schema::deserialize_schema;
-pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
+pub use stream::{
+    get_stream_row_count, read_stream_metadata, StreamMetadata, StreamReader, StreamState,
+};
 
 /// how dictionaries are tracked in this crate
 pub type Dictionaries = PlHashMap<i64, Box<dyn Array>>;
@@ -1,7 +1,7 @@
 use std::io::Read;
 
 use arrow_format::ipc::planus::ReadAsRoot;
-use polars_error::{polars_bail, polars_err, PolarsError, PolarsResult};
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsError, PolarsResult};
 use polars_utils::aliases::PlHashMap;
 
 use super::super::CONTINUATION_MARKER;
@@ -54,6 +54,63 @@ pub fn read_stream_metadata<R: Read>(reader: &mut R) -> PolarsResult<StreamMetad
     deserialize_stream_metadata(&buffer)
 }
 
+/// Counts the rows of the record batches in a stream, without deserializing them.
+///
+/// The reader may contain multiple concatenated streams, which are all counted.
+pub fn get_stream_row_count<R: Read>(reader: &mut R) -> PolarsResult<i64> {
+    let mut message_buffer = vec![];
+    let mut count = 0;
+    loop {
+        let mut meta_length: [u8; 4] = [0; 4];
+        match reader.read_exact(&mut meta_length) {
+            Ok(()) => (),
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(count),
+            Err(e) => return Err(e.into()),
+        }
+        if meta_length == CONTINUATION_MARKER {
+            reader.read_exact(&mut meta_length)?;
+        }
+        let meta_length: usize = i32::from_le_bytes(meta_length)
+            .try_into()
+            .map_err(|_| polars_err!(oos = OutOfSpecKind::NegativeFooterLength))?;
+        if meta_length == 0 {
+            // The end of a stream, another stream may follow it.
+            continue;
+        }
+
+        message_buffer.clear();
+        message_buffer.try_reserve(meta_length)?;
+        reader
+            .by_ref()
+            .take(meta_length as u64)
+            .read_to_end(&mut message_buffer)?;
+        let message = arrow_format::ipc::MessageRef::read_as_root(message_buffer.as_ref())
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+
+        let header = message
+            .header()
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferHeader(err)))?
+            .ok_or_else(|| polars_err!(oos = OutOfSpecKind::MissingMessageHeader))?;
+        if let arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) = header {
+            count += batch.length()?;
+        }
+
+        let block_length: u64 = message
+            .body_length()
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferBodyLength(err)))?
+            .try_into()
+            .map_err(|_| polars_err!(oos = OutOfSpecKind::UnexpectedNegativeInteger))?;
+        let skipped = std::io::copy(
+            &mut reader.by_ref().take(block_length),
+            &mut std::io::sink(),
+        )?;
+        polars_ensure!(
+            skipped == block_length,
+            oos = "the body of an IPC message is truncated"
+        );
+    }
+}
+
 /// Encodes the stream's status after each read.
 ///
 /// A stream is an iterator, and an iterator returns `Option<Item>`. The `Item`
@@ -284,6 +341,11 @@ impl<R: Read> StreamReader<R> {
         self.finished
     }
 
+    /// Consumes itself, returning the inner reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
     fn maybe_next(&mut self) -> PolarsResult<Option<StreamState>> {
         if self.finished {
             return Ok(None);
@@ -311,3 +373,130 @@ impl<R: Read> Iterator for StreamReader<R> {
         self.maybe_next().transpose()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow_format::ipc::planus::Builder;
+
+    use super::*;
+    use crate::array::{DictionaryArray, PrimitiveArray, Utf8Array};
+    use crate::datatypes::{ArrowDataType, Field, IntegerType};
+    use crate::io::ipc::write::{StreamWriter, WriteOptions};
+
+    fn dict_type() -> ArrowDataType {
+        ArrowDataType::Dictionary(
+            IntegerType::UInt32,
+            Box::new(ArrowDataType::LargeUtf8),
+            false,
+        )
+    }
+
+    fn schema() -> ArrowSchema {
+        std::iter::once(Field::new("x".into(), dict_type(), true))
+            .map(|f| (f.name.clone(), f))
+            .collect()
+    }
+
+    /// Write a stream with a single batch and return its framed messages.
+    fn write_stream(keys: &[u32], values: &[&str]) -> Vec<Vec<u8>> {
+        let array = DictionaryArray::<u32>::try_new(
+            dict_type(),
+            PrimitiveArray::from_slice(keys),
+            Utf8Array::<i64>::from_slice(values).boxed(),
+        )
+        .unwrap();
+        let mut writer = StreamWriter::new(vec![], WriteOptions { compression: None });
+        writer.start(&schema(), None).unwrap();
+        writer
+            .write(&RecordBatchT::try_new(vec![array.boxed()]).unwrap(), None)
+            .unwrap();
+        writer.finish().unwrap();
+        split_messages(&writer.into_inner())
+    }
+
+    fn split_messages(mut bytes: &[u8]) -> Vec<Vec<u8>> {
+        let mut messages = vec![];
+        while !bytes.is_empty() {
+            let meta_length = i32::from_le_bytes(bytes[4..8].try_into().unwrap()) as usize;
+            let message = arrow_format::ipc::MessageRef::read_as_root(&bytes[8..8 + meta_length]);
+            let body_length = message.map_or(0, |m| m.body_length().unwrap() as usize);
+            let length = 8 + meta_length + body_length;
+            messages.push(bytes[..length].to_vec());
+            bytes = &bytes[length..];
+        }
+        messages
+    }
+
+    /// Mark a dictionary batch message as a delta.
+    fn to_delta(framed: &[u8]) -> Vec<u8> {
+        let meta_length = i32::from_le_bytes(framed[4..8].try_into().unwrap()) as usize;
+        let message =
+            arrow_format::ipc::MessageRef::read_as_root(&framed[8..8 + meta_length]).unwrap();
+        let mut message = arrow_format::ipc::Message::try_from(message).unwrap();
+        let Some(arrow_format::ipc::MessageHeader::DictionaryBatch(batch)) = &mut message.header
+        else {
+            panic!("not a dictionary batch")
+        };
+        batch.is_delta = true;
+
+        let mut meta = Builder::new().finish(&message, None).to_vec();
+        meta.resize(meta.len().next_multiple_of(8), 0);
+        let mut out = CONTINUATION_MARKER.to_vec();
+        out.extend_from_slice(&(meta.len() as i32).to_le_bytes());
+        out.extend_from_slice(&meta);
+        out.extend_from_slice(&framed[8 + meta_length..]);
+        out
+    }
+
+    #[test]
+    fn read_delta_dictionary() {
+        let first = write_stream(&[0, 1], &["a", "b"]);
+        let delta = to_delta(&write_stream(&[0], &["c"])[1]);
+        let second = write_stream(&[2, 0], &["a", "b", "c"]);
+        // Schema, dictionary, batch, delta dictionary, batch and end of stream.
+        let stream = [
+            first[0].as_slice(),
+            &first[1],
+            &first[2],
+            &delta,
+            &second[2],
+            &first[3],
+        ]
+        .concat();
+
+        let mut reader = std::io::Cursor::new(&stream);
+        let metadata = read_stream_metadata(&mut reader).unwrap();
+        let batches = StreamReader::new(reader, metadata, None)
+            .map(|state| state.unwrap().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(batches.len(), 2);
+        let array = batches[1].arrays()[0]
+            .as_any()
+            .downcast_ref::<DictionaryArray<u32>>()
+            .unwrap();
+        let values = array
+            .iter_typed::<Utf8Array<i64>>()
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(values, [Some("c"), Some("a")]);
+
+        assert_eq!(
+            get_stream_row_count(&mut std::io::Cursor::new(&stream)).unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn count_concatenated_streams() {
+        let stream = [
+            write_stream(&[0, 1], &["a", "b"]),
+            write_stream(&[0], &["c"]),
+        ]
+        .concat()
+        .concat();
+        assert_eq!(
+            get_stream_row_count(&mut std::io::Cursor::new(&stream)).unwrap(),
+            3
+        );
+    }
+}
@@ -183,6 +183,7 @@ pub fn encode_chunk(
         fields,
         dictionary_tracker,
         options,
+        None,
         &mut encoded_message,
     )?;
     Ok((encoded_dictionaries, encoded_message))
@@ -194,6 +195,7 @@ pub fn encode_chunk_amortized(
     fields: &[IpcField],
     dictionary_tracker: &mut DictionaryTracker,
     options: &WriteOptions,
+    custom_metadata: Option<&Metadata>,
     encoded_message: &mut EncodedData,
 ) -> PolarsResult<Vec<EncodedData>> {
     let mut encoded_dictionaries = vec![];
@@ -208,7 +210,7 @@ pub fn encode_chunk_amortized(
         )?;
     }
 
-    chunk_to_bytes_amortized(chunk, options, encoded_message);
+    chunk_to_bytes_amortized(chunk, options, custom_metadata, encoded_message);
 
     Ok(encoded_dictionaries)
 }
@@ -282,6 +284,7 @@ fn gc_bin_view<'a, T: ViewType + ?Sized>(
 fn chunk_to_bytes_amortized(
     chunk: &RecordBatchT<Box<dyn Array>>,
     options: &WriteOptions,
+    custom_metadata: Option<&Metadata>,
     encoded_message: &mut EncodedData,
 ) {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
@@ -339,7 +342,15 @@ fn chunk_to_bytes_amortized(
             },
         ))),
         body_length: arrow_data.len() as i64,
-        custom_metadata: None,
+        custom_metadata: custom_metadata.map(|metadata| {
+            metadata
+                .iter()
+                .map(|(k, v)| arrow_format::ipc::KeyValue {
+                    key: Some(k.to_string()),
+                    value: Some(v.to_string()),
+                })
+                .collect()
+        }),
     };
 
     let mut builder = Builder::new();
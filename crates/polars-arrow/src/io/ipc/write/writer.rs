@@ -131,6 +131,16 @@ impl<W: Write> FileWriter<W> {
         &mut self,
         chunk: &RecordBatchT<Box<dyn Array>>,
         ipc_fields: Option<&[IpcField]>,
+    ) -> PolarsResult<()> {
+        self.write_with_custom_metadata(chunk, ipc_fields, None)
+    }
+
+    /// Writes [`RecordBatchT`] to the file, with `custom_metadata` in the message of the batch.
+    pub fn write_with_custom_metadata(
+        &mut self,
+        chunk: &RecordBatchT<Box<dyn Array>>,
+        ipc_fields: Option<&[IpcField]>,
+        custom_metadata: Option<&Metadata>,
     ) -> PolarsResult<()> {
         if self.state != State::Started {
             polars_bail!(
@@ -148,6 +158,7 @@ impl<W: Write> FileWriter<W> {
             ipc_fields,
             &mut self.dictionary_tracker,
             &self.options,
+            custom_metadata,
             &mut self.encoded_message,
         )?;
 
@@ -195,6 +195,59 @@ fn sort_by_groups_multiple_by(
     Ok((*first, new_idx))
 }
 
+fn sort_by_groups_no_match_multiple<'a>(
+    mut ac_in: AggregationContext<'a>,
+    mut ac_sort_by: Vec<AggregationContext<'a>>,
+    descending: &[bool],
+    nulls_last: &[bool],
+    maintain_order: bool,
+    expr: &Expr,
+) -> PolarsResult<AggregationContext<'a>> {
+    let s_in = ac_in.aggregated();
+    let s_in = s_in.list().unwrap().clone();
+    let s_sort_by = ac_sort_by
+        .iter_mut()
+        .map(|ac| ac.aggregated().list().unwrap().clone())
+        .collect::<Vec<_>>();
+    let options = SortMultipleOptions {
+        descending: descending.to_owned(),
+        nulls_last: nulls_last.to_owned(),
+        // We are already in par iter.
+        multithreaded: false,
+        maintain_order,
+    };
+
+    let dtype = s_in.dtype().clone();
+    let ca: PolarsResult<ListChunked> = POOL.install(|| {
+        (0..s_in.len())
+            .into_par_iter()
+            .map(|i| {
+                let Some(s) = s_in.get_as_series(i) else {
+                    return Ok(None);
+                };
+                let Some(by) = s_sort_by
+                    .iter()
+                    .map(|ca| ca.get_as_series(i).map(Column::from))
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    return Ok(None);
+                };
+                polars_ensure!(
+                    by.iter().all(|by| by.len() == s.len()),
+                    ComputeError: "series lengths don't match in 'sort_by' expression"
+                );
+                let idx = by[0]
+                    .as_materialized_series()
+                    .arg_sort_multiple(&by[1..], &options)?;
+                Ok(Some(unsafe { s.take_unchecked(&idx) }))
+            })
+            .collect_ca_with_dtype(PlSmallStr::EMPTY, dtype)
+    });
+    let s = ca?.with_name(s_in.name().clone()).into_series();
+    ac_in.with_series(s, true, Some(expr))?;
+    Ok(ac_in)
+}
+
 impl PhysicalExpr for SortByExpr {
     fn as_expression(&self) -> Option<&Expr> {
         Some(&self.expr)
@@ -339,6 +392,19 @@ impl PhysicalExpr for SortByExpr {
 
             groups?
         } else {
+            // The groups of the lhs of the expressions do not match the series values,
+            // we must take the slower path.
+            if !matches!(ac_in.update_groups, UpdateGroups::No) {
+                return sort_by_groups_no_match_multiple(
+                    ac_in,
+                    ac_sort_by,
+                    &descending,
+                    &nulls_last,
+                    self.sort_options.maintain_order,
+                    &self.expr,
+                );
+            }
+
             let groups = ac_sort_by[0].groups();
 
             let groups = POOL.install(|| {
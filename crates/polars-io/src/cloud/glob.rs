@@ -7,6 +7,7 @@ use regex::Regex;
 use url::Url;
 
 use super::{parse_url, CloudOptions};
+use crate::path_utils::ListedFile;
 
 const DELIMITER: char = '/';
 
@@ -169,6 +170,18 @@ impl Matcher {
 
 /// List files with a prefix derived from the pattern.
 pub async fn glob(url: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<Vec<String>> {
+    Ok(glob_listed(url, cloud_options)
+        .await?
+        .into_iter()
+        .map(|file| file.path.to_string_lossy().into_owned())
+        .collect())
+}
+
+/// List files with a prefix derived from the pattern, with the metadata of the listing.
+pub async fn glob_listed(
+    url: &str,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Vec<ListedFile>> {
     // Find the fixed prefix, up to the first '*'.
 
     let (
@@ -190,21 +203,24 @@ pub async fn glob(url: &str, cloud_options: Option<&CloudOptions>) -> PolarsResu
         expansion.as_deref(),
     )?;
 
-    let mut locations = store
+    let mut files = store
         .list(Some(&Path::from(prefix)))
         .try_filter_map(|x| async move {
-            let out =
-                (x.size > 0 && matcher.is_matching(x.location.as_ref())).then_some(x.location);
+            let out = (x.size > 0 && matcher.is_matching(x.location.as_ref())).then_some(x);
             Ok(out)
         })
         .try_collect::<Vec<_>>()
         .await
         .map_err(to_compute_err)?;
 
-    locations.sort_unstable();
-    Ok(locations
+    files.sort_unstable_by(|a, b| a.location.cmp(&b.location));
+    Ok(files
         .into_iter()
-        .map(|l| full_url(&scheme, &bucket, l))
+        .map(|x| ListedFile {
+            path: full_url(&scheme, &bucket, x.location).into(),
+            size: x.size as u64,
+            last_modified: x.last_modified.timestamp_millis(),
+        })
         .collect::<Vec<_>>())
 }
 
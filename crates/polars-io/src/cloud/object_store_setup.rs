@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use object_store::limit::LimitStore;
 use object_store::local::LocalFileSystem;
 use object_store::ObjectStore;
 use once_cell::sync::Lazy;
@@ -8,6 +9,7 @@ use polars_utils::aliases::PlHashMap;
 use tokio::sync::RwLock;
 use url::Url;
 
+use super::rate_limit::RateLimitStore;
 use super::{parse_url, CloudLocation, CloudOptions, CloudType};
 
 /// Object stores must be cached. Every object-store will do DNS lookups and
@@ -45,6 +47,23 @@ pub fn object_path_from_str(path: &str) -> PolarsResult<object_store::path::Path
     object_store::path::Path::parse(path).map_err(to_compute_err)
 }
 
+/// Wrap the store so that it respects the request limits of `options`. The wrapped store is
+/// cached, so the limits apply to all queries that share it.
+fn with_request_limits(
+    mut store: Arc<dyn ObjectStore>,
+    options: &CloudOptions,
+) -> Arc<dyn ObjectStore> {
+    if let Some(max_concurrent_requests) = options.max_concurrent_requests {
+        store = Arc::new(LimitStore::new(store, max_concurrent_requests));
+    }
+    // Wrapped last, so that requests waiting on the rate limit don't hold on to a concurrency
+    // permit.
+    if let Some(max_requests_per_second) = options.max_requests_per_second {
+        store = Arc::new(RateLimitStore::new(store, max_requests_per_second));
+    }
+    store
+}
+
 /// Build an [`ObjectStore`] based on the URL and passed in url. Return the cloud location and an implementation of the object store.
 pub async fn build_object_store(
     url: &str,
@@ -69,6 +88,7 @@ pub async fn build_object_store(
     }
 
     let options = options.map(std::borrow::Cow::Borrowed).unwrap_or_default();
+    options.validate()?;
 
     let cloud_type = CloudType::from_url(&parsed)?;
     let store = match cloud_type {
@@ -120,6 +140,8 @@ pub async fn build_object_store(
         },
         CloudType::Hf => panic!("impl error: unresolved hf:// path"),
     }?;
+    let store = with_request_limits(store, &options);
+
     if allow_cache {
         let mut cache = OBJECT_STORE_CACHE.write().await;
         // Clear the cache if we surpass a certain amount of buckets.
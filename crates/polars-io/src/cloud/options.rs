@@ -3,6 +3,7 @@ use std::io::Read;
 #[cfg(feature = "aws")]
 use std::path::Path;
 use std::str::FromStr;
+use std::time::Duration;
 
 #[cfg(feature = "aws")]
 use object_store::aws::AmazonS3Builder;
@@ -18,7 +19,7 @@ use object_store::gcp::GoogleCloudStorageBuilder;
 pub use object_store::gcp::GoogleConfigKey;
 #[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
 use object_store::ClientOptions;
-#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+#[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
 use object_store::{BackoffConfig, RetryConfig};
 #[cfg(feature = "aws")]
 use once_cell::sync::Lazy;
@@ -72,6 +73,21 @@ pub(crate) enum CloudConfig {
 /// Options to connect to various cloud providers.
 pub struct CloudOptions {
     pub max_retries: usize,
+    /// Backoff between retries of a failed or throttled request.
+    pub backoff: CloudBackoff,
+    /// Stop retrying a request after this much time has passed since the first attempt.
+    pub retry_timeout: Duration,
+    /// Timeout of a single request, from sending it until its body has been received. [None]
+    /// disables the timeout.
+    pub request_timeout: Option<Duration>,
+    /// Timeout for establishing a connection. [None] disables the timeout.
+    pub connect_timeout: Option<Duration>,
+    /// Maximum number of concurrent requests to an object store. The limit is shared by all
+    /// queries that access the same store with the same options.
+    pub max_concurrent_requests: Option<usize>,
+    /// Maximum number of requests per second to an object store. Like
+    /// `max_concurrent_requests`, this is shared by all queries that use the same store.
+    pub max_requests_per_second: Option<u32>,
     #[cfg(feature = "file_cache")]
     pub file_cache_ttl: u64,
     pub(crate) config: Option<CloudConfig>,
@@ -81,6 +97,12 @@ impl Default for CloudOptions {
     fn default() -> Self {
         Self {
             max_retries: 2,
+            backoff: CloudBackoff::default(),
+            retry_timeout: Duration::from_secs(10),
+            request_timeout: None,
+            connect_timeout: None,
+            max_concurrent_requests: None,
+            max_requests_per_second: None,
             #[cfg(feature = "file_cache")]
             file_cache_ttl: get_env_file_cache_ttl(),
             config: None,
@@ -88,6 +110,26 @@ impl Default for CloudOptions {
     }
 }
 
+/// Exponential backoff of retried requests. The `n`-th retry waits a random duration of up to
+/// `initial * base^n`, capped at `max`.
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CloudBackoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub base: u32,
+}
+
+impl Default for CloudBackoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(100),
+            max: Duration::from_secs(15),
+            base: 2,
+        }
+    }
+}
+
 #[cfg(feature = "http")]
 pub(crate) fn try_build_http_header_map_from_items_slice<S: AsRef<str>>(
     headers: &[(S, S)],
@@ -187,25 +229,36 @@ impl FromStr for CloudType {
         polars_bail!(ComputeError: "at least one of the cloud features must be enabled");
     }
 }
-#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
-fn get_retry_config(max_retries: usize) -> RetryConfig {
+#[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
+fn get_retry_config(options: &CloudOptions) -> RetryConfig {
     RetryConfig {
-        backoff: BackoffConfig::default(),
-        max_retries,
-        retry_timeout: std::time::Duration::from_secs(10),
+        backoff: BackoffConfig {
+            init_backoff: options.backoff.initial,
+            max_backoff: options.backoff.max,
+            base: options.backoff.base as f64,
+        },
+        max_retries: options.max_retries,
+        retry_timeout: options.retry_timeout,
     }
 }
 
 #[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
-pub(super) fn get_client_options() -> ClientOptions {
-    ClientOptions::default()
-        // We set request timeout super high as the timeout isn't reset at ACK,
-        // but starts from the moment we start downloading a body.
-        // https://docs.rs/reqwest/latest/reqwest/struct.ClientBuilder.html#method.timeout
-        .with_timeout_disabled()
-        // Concurrency can increase connection latency, so set to None, similar to default.
-        .with_connect_timeout_disabled()
-        .with_allow_http(true)
+pub(super) fn get_client_options(options: &CloudOptions) -> ClientOptions {
+    let client_options = ClientOptions::default().with_allow_http(true);
+
+    // By default there is no request timeout as the timeout isn't reset at ACK,
+    // but starts from the moment we start downloading a body.
+    // https://docs.rs/reqwest/latest/reqwest/struct.ClientBuilder.html#method.timeout
+    let client_options = match options.request_timeout {
+        Some(timeout) => client_options.with_timeout(timeout),
+        None => client_options.with_timeout_disabled(),
+    };
+    // Concurrency can increase connection latency, so by default there is no connect timeout,
+    // similar to default.
+    match options.connect_timeout {
+        Some(timeout) => client_options.with_connect_timeout(timeout),
+        None => client_options.with_connect_timeout_disabled(),
+    }
 }
 
 #[cfg(feature = "aws")]
@@ -248,6 +301,61 @@ impl CloudOptions {
         self
     }
 
+    /// Set the backoff between retries.
+    pub fn with_backoff(mut self, backoff: CloudBackoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Set the time after which a request is no longer retried.
+    pub fn with_retry_timeout(mut self, retry_timeout: Duration) -> Self {
+        self.retry_timeout = retry_timeout;
+        self
+    }
+
+    /// Set the timeout of a single request.
+    pub fn with_request_timeout(mut self, request_timeout: Option<Duration>) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Set the timeout for establishing a connection.
+    pub fn with_connect_timeout(mut self, connect_timeout: Option<Duration>) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Set the maximum number of concurrent requests to an object store.
+    pub fn with_max_concurrent_requests(mut self, max_concurrent_requests: Option<usize>) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests;
+        self
+    }
+
+    /// Set the maximum number of requests per second to an object store.
+    pub fn with_max_requests_per_second(mut self, max_requests_per_second: Option<u32>) -> Self {
+        self.max_requests_per_second = max_requests_per_second;
+        self
+    }
+
+    /// Check that the request limits and backoff are valid.
+    #[cfg(feature = "cloud")]
+    pub(crate) fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.max_concurrent_requests != Some(0),
+            InvalidOperation: "'max_concurrent_requests' must be greater than 0"
+        );
+        polars_ensure!(
+            self.max_requests_per_second != Some(0),
+            InvalidOperation: "'max_requests_per_second' must be greater than 0"
+        );
+        polars_ensure!(
+            self.backoff.base >= 1 && self.backoff.initial <= self.backoff.max,
+            InvalidOperation: "invalid backoff: base must be at least 1 and the initial backoff \
+            must not exceed the maximum backoff"
+        );
+        Ok(())
+    }
+
     /// Set the configuration for AWS connections. This is the preferred API from rust.
     #[cfg(feature = "aws")]
     pub fn with_aws<I: IntoIterator<Item = (AmazonS3ConfigKey, impl Into<String>)>>(
@@ -347,8 +455,8 @@ impl CloudOptions {
         };
 
         builder
-            .with_client_options(get_client_options())
-            .with_retry(get_retry_config(self.max_retries))
+            .with_client_options(get_client_options(self))
+            .with_retry(get_retry_config(self))
             .build()
             .map_err(to_compute_err)
     }
@@ -379,9 +487,9 @@ impl CloudOptions {
         }
 
         builder
-            .with_client_options(get_client_options())
+            .with_client_options(get_client_options(self))
             .with_url(url)
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(get_retry_config(self))
             .build()
             .map_err(to_compute_err)
     }
@@ -412,9 +520,9 @@ impl CloudOptions {
         }
 
         builder
-            .with_client_options(get_client_options())
+            .with_client_options(get_client_options(self))
             .with_url(url)
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(get_retry_config(self))
             .build()
             .map_err(to_compute_err)
     }
@@ -423,8 +531,9 @@ impl CloudOptions {
     pub fn build_http(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
         object_store::http::HttpBuilder::new()
             .with_url(url)
+            .with_retry(get_retry_config(self))
             .with_client_options({
-                let mut opts = super::get_client_options();
+                let mut opts = super::get_client_options(self);
                 if let Some(CloudConfig::Http { headers }) = &self.config {
                     opts = opts.with_default_headers(try_build_http_header_map_from_items_slice(
                         headers.as_slice(),
@@ -553,7 +662,33 @@ impl CloudOptions {
 #[cfg(feature = "cloud")]
 #[cfg(test)]
 mod tests {
-    use super::parse_url;
+    use super::{parse_url, CloudBackoff, CloudOptions};
+
+    #[test]
+    fn test_validate_request_limits() {
+        assert!(CloudOptions::default().validate().is_ok());
+        assert!(CloudOptions::default()
+            .with_max_concurrent_requests(Some(8))
+            .with_max_requests_per_second(Some(100))
+            .validate()
+            .is_ok());
+        assert!(CloudOptions::default()
+            .with_max_concurrent_requests(Some(0))
+            .validate()
+            .is_err());
+        assert!(CloudOptions::default()
+            .with_max_requests_per_second(Some(0))
+            .validate()
+            .is_err());
+        assert!(CloudOptions::default()
+            .with_backoff(CloudBackoff {
+                initial: std::time::Duration::from_secs(10),
+                max: std::time::Duration::from_secs(1),
+                base: 2,
+            })
+            .validate()
+            .is_err());
+    }
 
     #[test]
     fn test_parse_url() {
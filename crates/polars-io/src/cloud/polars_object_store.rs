@@ -8,6 +8,7 @@ use object_store::{ObjectMeta, ObjectStore};
 use polars_error::{to_compute_err, PolarsResult};
 use tokio::io::AsyncWriteExt;
 
+use crate::file_cache::{BlockCache, BLOCK_CACHE};
 use crate::pl_async::{
     self, tune_with_concurrency_budget, with_concurrency_budget, MAX_BUDGET_PER_REQUEST,
 };
@@ -55,6 +56,92 @@ impl PolarsObjectStore {
         .map_err(to_compute_err)
     }
 
+    /// Fetch a byte range through the block cache. Without a `version` of the object, or if
+    /// the block cache is disabled, this is the same as [`PolarsObjectStore::get_range`].
+    pub async fn get_range_cached(
+        &self,
+        path: &Path,
+        version: Option<&str>,
+        range: Range<usize>,
+    ) -> PolarsResult<Bytes> {
+        let (Some(block_cache), Some(version)) = (BLOCK_CACHE.as_ref(), version) else {
+            return self.get_range(path, range).await;
+        };
+        let mut out = self
+            .get_ranges_with_block_cache(block_cache, path, version, &[range])
+            .await?;
+        Ok(out.pop().unwrap())
+    }
+
+    /// Fetch byte ranges through the block cache. Only the ranges that are not cached are
+    /// fetched from the object store.
+    pub async fn get_ranges_cached(
+        &self,
+        path: &Path,
+        version: Option<&str>,
+        ranges: &[Range<usize>],
+    ) -> PolarsResult<Vec<Bytes>> {
+        let (Some(block_cache), Some(version)) = (BLOCK_CACHE.as_ref(), version) else {
+            return self.get_ranges(path, ranges).await;
+        };
+        self.get_ranges_with_block_cache(block_cache, path, version, ranges)
+            .await
+    }
+
+    /// The block cache reads and writes files while holding the file cache lock, so this is done
+    /// in (one) blocking task for the lookups and (one) for the inserts of all ranges.
+    async fn get_ranges_with_block_cache(
+        &self,
+        block_cache: &'static BlockCache,
+        path: &Path,
+        version: &str,
+        ranges: &[Range<usize>],
+    ) -> PolarsResult<Vec<Bytes>> {
+        let uri = Arc::<str>::from(self.block_cache_uri(path));
+        let version = Arc::<str>::from(version);
+
+        let mut out = {
+            let (uri, version, ranges) = (uri.clone(), version.clone(), ranges.to_vec());
+            tokio::task::spawn_blocking(move || {
+                ranges
+                    .iter()
+                    .map(|range| block_cache.get(&uri, &version, range))
+                    .collect::<Vec<_>>()
+            })
+            .await
+            .map_err(to_compute_err)?
+        };
+        let (missing_idx, missing_ranges): (Vec<_>, Vec<_>) = out
+            .iter()
+            .zip(ranges)
+            .enumerate()
+            .filter(|(_, (bytes, _))| bytes.is_none())
+            .map(|(i, (_, range))| (i, range.clone()))
+            .unzip();
+
+        if !missing_ranges.is_empty() {
+            let fetched = self.get_ranges(path, &missing_ranges).await?;
+            for (i, bytes) in missing_idx.into_iter().zip(&fetched) {
+                out[i] = Some(bytes.clone());
+            }
+            tokio::task::spawn_blocking(move || {
+                for (range, bytes) in missing_ranges.iter().zip(&fetched) {
+                    block_cache.insert(&uri, &version, range, bytes);
+                }
+            })
+            .await
+            .map_err(to_compute_err)?;
+        }
+
+        Ok(out.into_iter().map(Option::unwrap).collect())
+    }
+
+    /// Key of `path` in the block cache. The `Display` of the object store identifies the
+    /// bucket or account.
+    fn block_cache_uri(&self, path: &Path) -> String {
+        format!("{}/{}", self.0, path)
+    }
+
     pub async fn download<F: tokio::io::AsyncWrite + std::marker::Unpin>(
         &self,
         path: &Path,
@@ -113,3 +200,41 @@ impl PolarsObjectStore {
         .map_err(to_compute_err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use object_store::memory::InMemory;
+
+    use super::*;
+
+    #[test]
+    fn test_get_ranges_with_block_cache_keeps_order() {
+        let dir = std::env::temp_dir().join("polars_test_get_ranges_with_block_cache");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let block_cache: &'static BlockCache =
+            Box::leak(Box::new(BlockCache::new(dir.into_boxed_path())));
+
+        let store = PolarsObjectStore::new(Arc::new(InMemory::new()));
+        let path = Path::from("data.bin");
+        let data = Bytes::from_static(b"0123456789");
+
+        pl_async::get_runtime().block_on(async {
+            store.0.put(&path, data.clone().into()).await.unwrap();
+
+            // The cached block differs from the object, so that it can be told apart.
+            let uri = store.block_cache_uri(&path);
+            block_cache.insert(&uri, "v1", &(2..4), b"ab");
+
+            let ranges = [0..2, 2..4, 6..8, 4..6];
+            let out = store
+                .get_ranges_with_block_cache(block_cache, &path, "v1", &ranges)
+                .await
+                .unwrap();
+            assert_eq!(out, [&b"01"[..], b"ab", b"67", b"45"]);
+
+            // The fetched ranges were inserted into the cache.
+            assert_eq!(block_cache.get(&uri, "v1", &(6..8)).unwrap(), &b"67"[..]);
+        });
+    }
+}
@@ -0,0 +1,168 @@
+//! Object store wrapper that limits the number of requests per second.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{FutureExt, StreamExt};
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts,
+    PutOptions, PutPayload, PutResult, Result, UploadPart,
+};
+
+use crate::pl_async::RateLimiter;
+
+/// Wraps an [`ObjectStore`] and delays requests so that at most `requests_per_second` are sent.
+///
+/// Every call is counted as a single request, as is every part of a multipart upload. Retries
+/// done by the inner store are not counted, these are already spaced out by its backoff.
+#[derive(Debug)]
+pub(super) struct RateLimitStore {
+    inner: Arc<dyn ObjectStore>,
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitStore {
+    pub(super) fn new(inner: Arc<dyn ObjectStore>, requests_per_second: u32) -> Self {
+        Self {
+            inner,
+            limiter: Arc::new(RateLimiter::new(requests_per_second)),
+        }
+    }
+}
+
+impl std::fmt::Display for RateLimitStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RateLimitStore({}, {})",
+            self.limiter.requests_per_second(),
+            self.inner
+        )
+    }
+}
+
+#[async_trait]
+impl ObjectStore for RateLimitStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> Result<PutResult> {
+        self.limiter.acquire().await;
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOpts,
+    ) -> Result<Box<dyn MultipartUpload>> {
+        self.limiter.acquire().await;
+        let upload = self.inner.put_multipart_opts(location, opts).await?;
+        Ok(Box::new(RateLimitUpload {
+            upload,
+            limiter: self.limiter.clone(),
+        }))
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        self.limiter.acquire().await;
+        self.inner.get_opts(location, options).await
+    }
+
+    async fn get_range(&self, location: &Path, range: Range<usize>) -> Result<Bytes> {
+        self.limiter.acquire().await;
+        self.inner.get_range(location, range).await
+    }
+
+    async fn get_ranges(&self, location: &Path, ranges: &[Range<usize>]) -> Result<Vec<Bytes>> {
+        // The inner store may coalesce the ranges, so this can be less requests than ranges.
+        for _ in ranges {
+            self.limiter.acquire().await;
+        }
+        self.inner.get_ranges(location, ranges).await
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        self.limiter.acquire().await;
+        self.inner.head(location).await
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        self.limiter.acquire().await;
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'_, Result<ObjectMeta>> {
+        let prefix = prefix.cloned();
+        self.limiter
+            .acquire()
+            .map(move |_| self.inner.list(prefix.as_ref()))
+            .into_stream()
+            .flatten()
+            .boxed()
+    }
+
+    fn list_with_offset(
+        &self,
+        prefix: Option<&Path>,
+        offset: &Path,
+    ) -> BoxStream<'_, Result<ObjectMeta>> {
+        let prefix = prefix.cloned();
+        let offset = offset.clone();
+        self.limiter
+            .acquire()
+            .map(move |_| self.inner.list_with_offset(prefix.as_ref(), &offset))
+            .into_stream()
+            .flatten()
+            .boxed()
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        self.limiter.acquire().await;
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        self.limiter.acquire().await;
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.limiter.acquire().await;
+        self.inner.copy_if_not_exists(from, to).await
+    }
+}
+
+#[derive(Debug)]
+struct RateLimitUpload {
+    upload: Box<dyn MultipartUpload>,
+    limiter: Arc<RateLimiter>,
+}
+
+#[async_trait]
+impl MultipartUpload for RateLimitUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        let limiter = self.limiter.clone();
+        let put_part = self.upload.put_part(data);
+        Box::pin(async move {
+            limiter.acquire().await;
+            put_part.await
+        })
+    }
+
+    async fn complete(&mut self) -> Result<PutResult> {
+        self.limiter.acquire().await;
+        self.upload.complete().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.limiter.acquire().await;
+        self.upload.abort().await
+    }
+}
@@ -0,0 +1,627 @@
+//! Reading of fixed-width (positional) text files.
+//!
+//! Every line of a fixed-width file holds the fields of a row at fixed byte positions. The
+//! fields are parsed with the same buffers and type inference as the CSV reader.
+//!
+//! # Example
+//!
+//! ```
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::io::Cursor;
+//!
+//! let data = "id  name  \n1   foo   \n2   bar   \n";
+//! let df = FixedWidthReadOptions::new(FixedWidthColumn::from_widths([("id", 4), ("name", 6)]))
+//!     .with_has_header(true)
+//!     .into_reader_with_file_handle(Cursor::new(data))
+//!     .finish()
+//!     .unwrap();
+//! assert_eq!(df.shape(), (2, 2));
+//! ```
+use std::fs::File;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_core::POOL;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::buffer::init_buffers;
+use super::options::{CommentPrefix, CsvEncoding, NullValues, NullValuesCompiled};
+use super::parser::{is_comment_line, skip_bom, SplitLines};
+use super::schema_inference::{finish_infer_field_schema, infer_field_schema};
+use super::utils::get_file_chunks;
+use crate::mmap::MmapBytesReader;
+use crate::path_utils::resolve_homedir;
+use crate::shared::SerReader;
+use crate::utils::compression::maybe_decompress_bytes;
+use crate::utils::get_reader_bytes;
+
+/// A column of a fixed-width file that spans the bytes `start..end` of every line.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedWidthColumn {
+    pub name: PlSmallStr,
+    /// Byte offset of the first byte of the field.
+    pub start: usize,
+    /// Byte offset one past the last byte of the field.
+    pub end: usize,
+    /// The data type of the column, inferred if `None`.
+    pub dtype: Option<DataType>,
+}
+
+impl FixedWidthColumn {
+    pub fn new(name: impl Into<PlSmallStr>, start: usize, end: usize) -> Self {
+        Self {
+            name: name.into(),
+            start,
+            end,
+            dtype: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_dtype(mut self, dtype: DataType) -> Self {
+        self.dtype = Some(dtype);
+        self
+    }
+
+    /// Create consecutive columns, starting at the first byte of a line, from their widths.
+    pub fn from_widths<I, S>(names_and_widths: I) -> Vec<Self>
+    where
+        I: IntoIterator<Item = (S, usize)>,
+        S: Into<PlSmallStr>,
+    {
+        let mut start = 0;
+        names_and_widths
+            .into_iter()
+            .map(|(name, width)| {
+                let column = Self::new(name, start, start + width);
+                start += width;
+                column
+            })
+            .collect()
+    }
+}
+
+/// Options for reading fixed-width files.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedWidthReadOptions {
+    pub path: Option<PathBuf>,
+    pub columns: Arc<[FixedWidthColumn]>,
+    pub rechunk: bool,
+    pub n_threads: Option<usize>,
+    pub n_rows: Option<usize>,
+    /// Only read these columns.
+    pub projection: Option<Arc<[PlSmallStr]>>,
+    /// Whether the first line (after `skip_rows`) is a header. The header is skipped, the
+    /// column names are always taken from `columns`.
+    pub has_header: bool,
+    pub skip_rows: usize,
+    pub infer_schema_length: Option<usize>,
+    pub eol_char: u8,
+    pub encoding: CsvEncoding,
+    pub null_values: Option<NullValues>,
+    pub comment_prefix: Option<CommentPrefix>,
+    pub ignore_errors: bool,
+    pub try_parse_dates: bool,
+    pub decimal_comma: bool,
+}
+
+impl FixedWidthReadOptions {
+    pub fn new(columns: impl Into<Arc<[FixedWidthColumn]>>) -> Self {
+        Self {
+            path: None,
+            columns: columns.into(),
+            rechunk: false,
+            n_threads: None,
+            n_rows: None,
+            projection: None,
+            has_header: false,
+            skip_rows: 0,
+            infer_schema_length: Some(100),
+            eol_char: b'\n',
+            encoding: CsvEncoding::Utf8,
+            null_values: None,
+            comment_prefix: None,
+            ignore_errors: false,
+            try_parse_dates: false,
+            decimal_comma: false,
+        }
+    }
+
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: Option<P>) -> Self {
+        self.path = path.map(|p| p.into());
+        self
+    }
+
+    /// Whether to makes the columns contiguous in memory.
+    pub fn with_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Number of threads to use for reading. Defaults to the size of the polars thread pool.
+    pub fn with_n_threads(mut self, n_threads: Option<usize>) -> Self {
+        self.n_threads = n_threads;
+        self
+    }
+
+    /// Limits the number of rows to read.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// Which columns to select.
+    pub fn with_projection(mut self, projection: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Set whether the file has a header line that should be skipped.
+    pub fn with_has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Number of lines to skip from the start of the file.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Number of rows to use for schema inference. Pass [None] to use all rows.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    /// Set the character used to indicate an end-of-line (eol).
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.eol_char = eol_char;
+        self
+    }
+
+    /// Set the encoding used by the file.
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null. Fields are trimmed before they are
+    /// compared with these values.
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Sets the comment prefix for this instance. Lines starting with this prefix will be ignored.
+    pub fn with_comment_prefix<T: Into<CommentPrefix>>(
+        mut self,
+        comment_prefix: Option<T>,
+    ) -> Self {
+        self.comment_prefix = comment_prefix.map(Into::into);
+        self
+    }
+
+    /// Continue with next batch when a ParserError is encountered.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+
+    /// Automatically try to parse dates/datetimes and time. If parsing fails,
+    /// columns remain of dtype [`DataType::String`].
+    pub fn with_try_parse_dates(mut self, try_parse_dates: bool) -> Self {
+        self.try_parse_dates = try_parse_dates;
+        self
+    }
+
+    /// Parse floats with a comma as decimal separator.
+    pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Creates a fixed-width reader using a file path.
+    ///
+    /// # Panics
+    /// If both self.path and the path parameter are non-null. Only one of them is
+    /// to be non-null.
+    pub fn try_into_reader_with_file_path(
+        mut self,
+        path: Option<PathBuf>,
+    ) -> PolarsResult<FixedWidthReader<File>> {
+        if self.path.is_some() {
+            assert!(
+                path.is_none(),
+                "impl error: only 1 of self.path or the path parameter is to be non-null"
+            );
+        } else {
+            self.path = path;
+        };
+
+        assert!(
+            self.path.is_some(),
+            "impl error: either one of self.path or the path parameter is to be non-null"
+        );
+
+        let path = resolve_homedir(self.path.as_ref().unwrap());
+        let reader = polars_utils::open_file(&path)?;
+        let options = self;
+
+        Ok(FixedWidthReader { reader, options })
+    }
+
+    /// Creates a fixed-width reader using a file handle.
+    pub fn into_reader_with_file_handle<R: MmapBytesReader>(
+        self,
+        reader: R,
+    ) -> FixedWidthReader<R> {
+        let options = self;
+
+        FixedWidthReader { reader, options }
+    }
+
+    /// Infer the schema of the fixed-width data in `bytes`.
+    pub fn infer_schema(&self, bytes: &[u8]) -> PolarsResult<Schema> {
+        self.validate()?;
+        let owned = &mut vec![];
+        let data = self.data_lines(maybe_decompress_bytes(bytes, owned)?);
+        self.infer_schema_from_data(data)
+    }
+
+    fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.columns.is_empty(),
+            InvalidOperation: "a fixed-width file needs at least one column"
+        );
+        let mut names = PlHashSet::with_capacity(self.columns.len());
+        for c in self.columns.iter() {
+            polars_ensure!(
+                c.start < c.end,
+                InvalidOperation: "fixed-width column '{}' has an empty byte range {}..{}",
+                c.name, c.start, c.end
+            );
+            polars_ensure!(names.insert(&c.name), Duplicate: "column with name '{}' has more than one occurrence", c.name);
+        }
+        Ok(())
+    }
+
+    /// Skips the BOM, leading rows and header and returns the remaining lines.
+    fn data_lines<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        let mut bytes = skip_bom(bytes);
+        for _ in 0..self.skip_rows + self.has_header as usize {
+            bytes = match memchr::memchr(self.eol_char, bytes) {
+                Some(pos) => &bytes[pos + 1..],
+                None => &[],
+            };
+        }
+        bytes
+    }
+
+    fn lines<'a>(&'a self, bytes: &'a [u8]) -> impl Iterator<Item = &'a [u8]> + 'a {
+        SplitLines::new(bytes, None, self.eol_char)
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .filter(|line| !line.is_empty() && !is_comment_line(line, self.comment_prefix.as_ref()))
+    }
+
+    fn infer_schema_from_data(&self, data: &[u8]) -> PolarsResult<Schema> {
+        let mut possibilities = vec![PlHashSet::<DataType>::new(); self.columns.len()];
+        let null_values = self.compile_null_values()?;
+
+        if self.columns.iter().any(|c| c.dtype.is_none()) {
+            let n = self.infer_schema_length.unwrap_or(usize::MAX);
+            for line in self.lines(data).take(n) {
+                for (i, c) in self.columns.iter().enumerate() {
+                    if c.dtype.is_some() {
+                        continue;
+                    }
+                    let field = field_bytes(line, c);
+                    if field.is_empty() || is_null(null_values.as_ref(), field, i) {
+                        continue;
+                    }
+                    let s = match simdutf8::basic::from_utf8(field) {
+                        Ok(s) => s,
+                        Err(_) => {
+                            possibilities[i].insert(DataType::String);
+                            continue;
+                        },
+                    };
+                    possibilities[i].insert(infer_field_schema(
+                        s,
+                        self.try_parse_dates,
+                        self.decimal_comma,
+                    ));
+                }
+            }
+        }
+
+        Ok(self
+            .columns
+            .iter()
+            .zip(possibilities)
+            .map(|(c, possibilities)| {
+                let dtype = match &c.dtype {
+                    Some(dtype) => dtype.clone(),
+                    None if possibilities.is_empty() => DataType::String,
+                    None => finish_infer_field_schema(&possibilities),
+                };
+                Field::new(c.name.clone(), dtype)
+            })
+            .collect())
+    }
+
+    fn compile_null_values(&self) -> PolarsResult<Option<NullValuesCompiled>> {
+        let schema = self
+            .columns
+            .iter()
+            .map(|c| Field::new(c.name.clone(), DataType::String))
+            .collect::<Schema>();
+        self.null_values
+            .clone()
+            .map(|nv| nv.compile(&schema))
+            .transpose()
+    }
+}
+
+/// Reads a fixed-width file into a DataFrame.
+///
+/// See [`FixedWidthReadOptions`] for the available options.
+#[must_use]
+pub struct FixedWidthReader<R>
+where
+    R: MmapBytesReader,
+{
+    reader: R,
+    options: FixedWidthReadOptions,
+}
+
+impl<R> FixedWidthReader<R>
+where
+    R: MmapBytesReader,
+{
+    /// Infer the schema of the file without parsing all of it.
+    pub fn schema(&mut self) -> PolarsResult<SchemaRef> {
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        Ok(Arc::new(self.options.infer_schema(&reader_bytes)?))
+    }
+}
+
+impl<R> SerReader<R> for FixedWidthReader<R>
+where
+    R: MmapBytesReader,
+{
+    /// Create a new FixedWidthReader without any columns. Use
+    /// [`FixedWidthReadOptions::into_reader_with_file_handle`] to configure the columns.
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            options: FixedWidthReadOptions::new(vec![]),
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.options.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let options = &self.options;
+        options.validate()?;
+
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let owned = &mut vec![];
+        let mut data = options.data_lines(maybe_decompress_bytes(&reader_bytes, owned)?);
+
+        let schema = options.infer_schema_from_data(data)?;
+        let projection = match &options.projection {
+            Some(columns) => columns
+                .iter()
+                .map(|name| schema.try_index_of(name))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            None => (0..schema.len()).collect(),
+        };
+
+        if let Some(n_rows) = options.n_rows {
+            data = truncate_to_n_lines(options, data, n_rows);
+        }
+
+        let n_threads = options
+            .n_threads
+            .unwrap_or_else(|| POOL.current_num_threads())
+            .max(1);
+        let n_chunks = if data.len() < (1 << 16) { 1 } else { n_threads };
+        let chunks = get_file_chunks(data, n_chunks, None, b',', None, options.eol_char);
+        let null_values = options.compile_null_values()?;
+
+        let dfs = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .map(|(start, end)| {
+                    let chunk = &data[start..end];
+                    let capacity = memchr::memchr_iter(options.eol_char, chunk).count() + 1;
+                    let mut buffers = init_buffers(
+                        &projection,
+                        capacity,
+                        &schema,
+                        None,
+                        options.encoding,
+                        options.decimal_comma,
+                    )?;
+
+                    for line in options.lines(chunk) {
+                        for (buf, &i) in buffers.iter_mut().zip(projection.iter()) {
+                            let field = field_bytes(line, &options.columns[i]);
+                            if field.is_empty() || is_null(null_values.as_ref(), field, i) {
+                                buf.add_null(false);
+                            } else {
+                                buf.add(field, options.ignore_errors, false, true)
+                                    .map_err(|e| {
+                                        polars_err!(
+                                            ComputeError: "could not parse `{}` as dtype `{}` at column '{}': {}",
+                                            String::from_utf8_lossy(field),
+                                            buf.dtype(),
+                                            options.columns[i].name,
+                                            e
+                                        )
+                                    })?;
+                            }
+                        }
+                    }
+
+                    let columns = buffers
+                        .into_iter()
+                        .map(|buf| buf.into_series().map(Column::from))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    DataFrame::new(columns)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        if options.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+/// The trimmed bytes of column `c` in `line`. Lines that are too short yield empty fields.
+fn field_bytes<'a>(line: &'a [u8], c: &FixedWidthColumn) -> &'a [u8] {
+    let start = c.start.min(line.len());
+    let end = c.end.min(line.len());
+    line[start..end].trim_ascii()
+}
+
+fn is_null(null_values: Option<&NullValuesCompiled>, field: &[u8], index: usize) -> bool {
+    // SAFETY: the null values are compiled against a schema with all columns.
+    null_values.is_some_and(|nv| unsafe { nv.is_null(field, index) })
+}
+
+fn truncate_to_n_lines<'a>(
+    options: &FixedWidthReadOptions,
+    data: &'a [u8],
+    n_rows: usize,
+) -> &'a [u8] {
+    let mut rest = data;
+    let mut n = 0;
+    while n < n_rows && !rest.is_empty() {
+        let (line, next) = match memchr::memchr(options.eol_char, rest) {
+            Some(pos) => (&rest[..pos], &rest[pos + 1..]),
+            None => (rest, &[][..]),
+        };
+        if options.lines(line).next().is_some() {
+            n += 1;
+        }
+        rest = next;
+    }
+    &data[..data.len() - rest.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const DATA: &str = "\
+id   name      amount  day
+1    alice     10.5    2024-01-01
+2    bob               2024-01-02
+# skipped
+333  carol     -3      2024-01-03
+";
+
+    fn options() -> FixedWidthReadOptions {
+        FixedWidthReadOptions::new(vec![
+            FixedWidthColumn::new("id", 0, 5),
+            FixedWidthColumn::new("name", 5, 15),
+            FixedWidthColumn::new("amount", 15, 23),
+            FixedWidthColumn::new("day", 23, 33),
+        ])
+        .with_has_header(true)
+        .with_comment_prefix(Some("#"))
+    }
+
+    #[test]
+    fn test_read_fixed_width() {
+        let df = options()
+            .into_reader_with_file_handle(Cursor::new(DATA))
+            .finish()
+            .unwrap();
+
+        assert_eq!(df.shape(), (3, 4));
+        assert_eq!(
+            df.dtypes(),
+            &[
+                DataType::Int64,
+                DataType::String,
+                DataType::Float64,
+                DataType::String
+            ]
+        );
+        let amount = df.column("amount").unwrap().f64().unwrap();
+        assert_eq!(Vec::from(amount), &[Some(10.5), None, Some(-3.0)]);
+        let name = df.column("name").unwrap().str().unwrap();
+        assert_eq!(
+            Vec::from(name),
+            &[Some("alice"), Some("bob"), Some("carol")]
+        );
+    }
+
+    #[test]
+    fn test_read_fixed_width_projection_and_n_rows() {
+        let df = options()
+            .with_projection(Some(["day".into(), "id".into()].into()))
+            .with_n_rows(Some(2))
+            .into_reader_with_file_handle(Cursor::new(DATA))
+            .finish()
+            .unwrap();
+
+        assert_eq!(df.get_column_names(), &["day", "id"]);
+        assert_eq!(df.height(), 2);
+    }
+
+    #[test]
+    fn test_read_fixed_width_dtypes_and_widths() {
+        let columns = FixedWidthColumn::from_widths([("id", 5), ("name", 10)]);
+        assert_eq!(columns[1], FixedWidthColumn::new("name", 5, 15));
+
+        let columns = vec![
+            columns[0].clone().with_dtype(DataType::UInt32),
+            columns[1].clone(),
+        ];
+        let df = FixedWidthReadOptions::new(columns)
+            .with_has_header(true)
+            .with_comment_prefix(Some("#"))
+            .with_null_values(Some(NullValues::AllColumnsSingle("bob".into())))
+            .into_reader_with_file_handle(Cursor::new(DATA))
+            .finish()
+            .unwrap();
+
+        assert_eq!(df.dtypes(), &[DataType::UInt32, DataType::String]);
+        assert_eq!(df.column("name").unwrap().null_count(), 1);
+    }
+
+    #[test]
+    fn test_read_fixed_width_parallel() {
+        let mut data = String::new();
+        for i in 0..20_000 {
+            data.push_str(&format!("{i:>8}{:<4}\r\n", i % 7));
+        }
+        let df = FixedWidthReadOptions::new(FixedWidthColumn::from_widths([("a", 8), ("b", 4)]))
+            .with_n_threads(Some(4))
+            .into_reader_with_file_handle(Cursor::new(data))
+            .finish()
+            .unwrap();
+
+        assert_eq!(df.height(), 20_000);
+        let a = df.column("a").unwrap().i64().unwrap();
+        assert_eq!(a.sum(), Some((0..20_000).sum()));
+    }
+}
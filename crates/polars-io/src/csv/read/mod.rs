@@ -17,6 +17,7 @@
 //! ```
 
 pub mod buffer;
+mod fixed_width;
 mod options;
 mod parser;
 mod read_impl;
@@ -25,6 +26,7 @@ pub mod schema_inference;
 mod splitfields;
 mod utils;
 
+pub use fixed_width::{FixedWidthColumn, FixedWidthReadOptions, FixedWidthReader};
 pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
 pub use parser::{count_rows, count_rows_from_slice};
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
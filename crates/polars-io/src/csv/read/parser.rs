@@ -14,7 +14,7 @@ use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
 use super::utils::get_file_chunks;
 use crate::path_utils::is_cloud_url;
-use crate::utils::compression::maybe_decompress_bytes;
+use crate::utils::compression::{decompress_chunk_size, DecompressedChunks};
 
 /// Read the number of rows without parsing columns
 /// useful for count(*) queries
@@ -39,11 +39,9 @@ pub fn count_rows(
     };
 
     let mmap = unsafe { memmap::Mmap::map(&file).unwrap() };
-    let owned = &mut vec![];
-    let reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
 
     count_rows_from_slice(
-        reader_bytes,
+        mmap.as_ref(),
         separator,
         quote_char,
         comment_prefix,
@@ -55,13 +53,38 @@ pub fn count_rows(
 /// Read the number of rows without parsing columns
 /// useful for count(*) queries
 pub fn count_rows_from_slice(
-    mut bytes: &[u8],
+    bytes: &[u8],
     separator: u8,
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
 ) -> PolarsResult<usize> {
+    // Compressed data is counted chunk by chunk, so that it never has to be fully
+    // decompressed in memory.
+    let Some(mut chunks) =
+        DecompressedChunks::try_new(bytes, decompress_chunk_size(), eol_char, quote_char)?
+    else {
+        let bytes = skip_leading_eol(bytes, eol_char);
+        let n_lines =
+            count_lines_from_slice(bytes, separator, quote_char, comment_prefix, eol_char)?;
+        return Ok(n_lines - (has_header as usize));
+    };
+
+    let mut n_lines = 0;
+    let mut at_start = true;
+    while let Some(chunk) = chunks.next_chunk()? {
+        let mut bytes = chunk.as_slice();
+        if at_start {
+            bytes = skip_leading_eol(bytes, eol_char);
+            at_start = bytes.is_empty();
+        }
+        n_lines += count_lines_from_slice(bytes, separator, quote_char, comment_prefix, eol_char)?;
+    }
+    Ok(n_lines - (has_header as usize))
+}
+
+fn skip_leading_eol(mut bytes: &[u8], eol_char: u8) -> &[u8] {
     for _ in 0..bytes.len() {
         if bytes[0] != eol_char {
             break;
@@ -69,7 +92,17 @@ pub fn count_rows_from_slice(
 
         bytes = &bytes[1..];
     }
+    bytes
+}
 
+/// Number of (non-comment) lines in `bytes`.
+fn count_lines_from_slice(
+    bytes: &[u8],
+    separator: u8,
+    quote_char: Option<u8>,
+    comment_prefix: Option<&CommentPrefix>,
+    eol_char: u8,
+) -> PolarsResult<usize> {
     const MIN_ROWS_PER_THREAD: usize = 1024;
     let max_threads = POOL.current_num_threads();
 
@@ -103,12 +136,7 @@ pub fn count_rows_from_slice(
         }
     });
 
-    let count_result: PolarsResult<usize> = POOL.install(|| iter.sum());
-
-    match count_result {
-        Ok(val) => Ok(val - (has_header as usize)),
-        Err(err) => Err(err),
-    }
+    POOL.install(|| iter.sum())
 }
 
 /// Skip the utf-8 Byte Order Mark.
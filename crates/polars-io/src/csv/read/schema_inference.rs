@@ -14,6 +14,7 @@ use super::parser::{is_comment_line, skip_bom, skip_line_ending, SplitLines};
 use super::splitfields::SplitFields;
 use super::CsvReadOptions;
 use crate::mmap::ReaderBytes;
+use crate::utils::compression::maybe_decompress_bytes;
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
 #[derive(Clone, Debug, Default)]
@@ -79,6 +80,45 @@ impl SchemaInferenceResult {
         Ok(this)
     }
 
+    /// Infer the schema from (possibly) compressed bytes. If `infer_schema_length` is set, only
+    /// the lines needed for inference are decompressed.
+    pub fn try_from_maybe_compressed_bytes_and_options(
+        bytes: &[u8],
+        options: &CsvReadOptions,
+    ) -> PolarsResult<Self> {
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        if let Some(infer_schema_length) = options.infer_schema_length {
+            let parse_options = options.get_parse_options();
+            let n_lines = options.skip_rows
+                + options.has_header as usize
+                + options.skip_rows_after_header
+                + infer_schema_length;
+
+            if let Some(decompressed) = super::utils::decompress(
+                bytes,
+                Some(n_lines),
+                parse_options.separator,
+                parse_options.quote_char,
+                parse_options.eol_char,
+            ) {
+                if decompressed.len() < 2 && options.raise_if_empty {
+                    polars_bail!(NoData: "empty CSV")
+                }
+                return Self::try_from_reader_bytes_and_options(
+                    &ReaderBytes::Owned(decompressed),
+                    options,
+                );
+            }
+        }
+
+        let owned = &mut vec![];
+        let bytes = maybe_decompress_bytes(bytes, owned)?;
+        if bytes.len() < 2 && options.raise_if_empty {
+            polars_bail!(NoData: "empty CSV")
+        }
+        Self::try_from_reader_bytes_and_options(&ReaderBytes::Borrowed(bytes), options)
+    }
+
     pub fn with_inferred_schema(mut self, inferred_schema: SchemaRef) -> Self {
         self.inferred_schema = inferred_schema;
         self
@@ -129,26 +129,11 @@ pub(crate) fn decompress(
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
-    use crate::utils::compression::SupportedCompression;
+    use crate::utils::compression::{decompressing_reader, SupportedCompression};
 
-    if let Some(algo) = SupportedCompression::check(bytes) {
-        match algo {
-            SupportedCompression::GZIP => {
-                let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-            SupportedCompression::ZLIB => {
-                let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-            SupportedCompression::ZSTD => {
-                let mut decoder = zstd::Decoder::new(bytes).ok()?;
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-        }
-    } else {
-        None
-    }
+    let algo = SupportedCompression::check(bytes)?;
+    let mut decoder = decompressing_reader(algo, bytes).ok()?;
+    decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
 }
 
 /// replace double quotes by single ones
@@ -0,0 +1,475 @@
+use std::io::Cursor;
+
+use bytes::Bytes;
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsResult};
+use polars_utils::pl_str::PlSmallStr;
+use serde_json::Value;
+
+use super::storage::DeltaStorage;
+use crate::parquet::read::ParquetReader;
+use crate::SerReader;
+
+/// A data file of a Delta table, as listed by an `add` action of the log.
+#[derive(Clone, Debug)]
+pub struct DeltaAddFile {
+    /// The path of the file as written in the log. This is relative to the table root, unless
+    /// it has a scheme.
+    pub path: PlSmallStr,
+    pub size: i64,
+    /// The partition values of the file, serialized as strings. `None` is a null value.
+    pub partition_values: Vec<(PlSmallStr, Option<PlSmallStr>)>,
+    /// The statistics of the file, serialized as JSON.
+    pub(super) stats: Option<String>,
+    pub(super) has_deletion_vector: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct DeltaProtocol {
+    pub(super) min_reader_version: i64,
+    pub(super) min_writer_version: i64,
+    pub(super) reader_features: Vec<String>,
+    pub(super) writer_features: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct DeltaMetadata {
+    pub(super) id: String,
+    pub(super) schema_string: String,
+    pub(super) partition_columns: Vec<PlSmallStr>,
+    pub(super) configuration: Vec<(String, String)>,
+}
+
+impl DeltaMetadata {
+    pub(super) fn config(&self, key: &str) -> Option<&str> {
+        self.configuration
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum Action {
+    Add(DeltaAddFile),
+    Remove(PlSmallStr),
+    Metadata(DeltaMetadata),
+    Protocol(DeltaProtocol),
+}
+
+pub(super) fn commit_file_name(version: i64) -> String {
+    format!("{version:020}.json")
+}
+
+fn get_str<'a>(value: &'a Value, key: &str) -> PolarsResult<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| polars_err!(ComputeError: "missing string '{}'", key))
+}
+
+fn get_i64(value: &Value, key: &str) -> PolarsResult<i64> {
+    value
+        .get(key)
+        .and_then(Value::as_i64)
+        .ok_or_else(|| polars_err!(ComputeError: "missing integer '{}'", key))
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::Array(values)) => values
+            .iter()
+            .filter_map(|v| v.as_str().map(String::from))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn parse_action(value: &Value) -> PolarsResult<Option<Action>> {
+    if let Some(add) = value.get("add") {
+        let partition_values = match add.get("partitionValues") {
+            Some(Value::Object(values)) => values
+                .iter()
+                .map(|(k, v)| (k.as_str().into(), v.as_str().map(PlSmallStr::from)))
+                .collect(),
+            _ => vec![],
+        };
+        return Ok(Some(Action::Add(DeltaAddFile {
+            path: get_str(add, "path")?.into(),
+            size: get_i64(add, "size")?,
+            partition_values,
+            stats: add.get("stats").and_then(Value::as_str).map(String::from),
+            has_deletion_vector: add.get("deletionVector").map_or(false, |dv| !dv.is_null()),
+        })));
+    }
+    if let Some(remove) = value.get("remove") {
+        return Ok(Some(Action::Remove(get_str(remove, "path")?.into())));
+    }
+    if let Some(metadata) = value.get("metaData") {
+        let configuration = match metadata.get("configuration") {
+            Some(Value::Object(config)) => config
+                .iter()
+                .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                .collect(),
+            _ => vec![],
+        };
+        return Ok(Some(Action::Metadata(DeltaMetadata {
+            id: get_str(metadata, "id")?.to_string(),
+            schema_string: get_str(metadata, "schemaString")?.to_string(),
+            partition_columns: string_list(metadata.get("partitionColumns"))
+                .into_iter()
+                .map(PlSmallStr::from)
+                .collect(),
+            configuration,
+        })));
+    }
+    if let Some(protocol) = value.get("protocol") {
+        return Ok(Some(Action::Protocol(DeltaProtocol {
+            min_reader_version: get_i64(protocol, "minReaderVersion")?,
+            min_writer_version: get_i64(protocol, "minWriterVersion")?,
+            reader_features: string_list(protocol.get("readerFeatures")),
+            writer_features: string_list(protocol.get("writerFeatures")),
+        })));
+    }
+    // `commitInfo`, `txn`, `cdc` and other actions don't affect the files of the table.
+    Ok(None)
+}
+
+/// Parse a commit file, which has an action as JSON object on every line.
+pub(super) fn parse_commit(bytes: &[u8]) -> PolarsResult<Vec<Action>> {
+    let text = std::str::from_utf8(bytes)
+        .map_err(|_| polars_err!(ComputeError: "delta commit is not valid utf-8"))?;
+    let mut actions = vec![];
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        let value: Value = serde_json::from_str(line)
+            .map_err(|err| polars_err!(ComputeError: "invalid delta commit: {}", err))?;
+        actions.extend(parse_action(&value)?);
+    }
+    Ok(actions)
+}
+
+fn struct_column(df: &DataFrame, name: &str) -> PolarsResult<Option<StructChunked>> {
+    match df.column(name) {
+        Ok(column) => Ok(Some(column.as_materialized_series().struct_()?.clone())),
+        Err(_) => Ok(None),
+    }
+}
+
+fn opt_field(ca: &StructChunked, name: &str) -> Option<Series> {
+    ca.field_by_name(name).ok()
+}
+
+fn str_at(s: &Series, i: usize) -> PolarsResult<Option<&str>> {
+    Ok(s.str()?.get(i))
+}
+
+fn string_list_at(s: Option<&Series>, i: usize) -> PolarsResult<Vec<String>> {
+    let Some(values) = s.and_then(|s| s.list().ok()?.get_as_series(i)) else {
+        return Ok(vec![]);
+    };
+    Ok(values
+        .str()?
+        .into_iter()
+        .flatten()
+        .map(String::from)
+        .collect())
+}
+
+/// Read a map column, which is a `list<struct<key, value>>` with string keys and values.
+fn string_map_at(s: Option<&Series>, i: usize) -> PolarsResult<Vec<(String, Option<String>)>> {
+    let Some(entries) = s.and_then(|s| s.list().ok()?.get_as_series(i)) else {
+        return Ok(vec![]);
+    };
+    let entries = entries.struct_()?;
+    let keys = entries.field_by_name("key")?;
+    let values = entries.field_by_name("value")?;
+    Ok(keys
+        .str()?
+        .into_iter()
+        .zip(values.str()?)
+        .filter_map(|(k, v)| Some((k?.to_string(), v.map(String::from))))
+        .collect())
+}
+
+/// Read the actions of a (part of a) checkpoint. Only the `add` actions are live files, the
+/// `remove` actions of checkpoints are tombstones.
+pub(super) fn read_checkpoint(bytes: Bytes) -> PolarsResult<Vec<Action>> {
+    let df = ParquetReader::new(Cursor::new(bytes)).finish()?;
+    let mut actions = vec![];
+
+    if let Some(add) = struct_column(&df, "add")? {
+        let paths = add.field_by_name("path")?;
+        let sizes = add.field_by_name("size")?.cast(&DataType::Int64)?;
+        let sizes = sizes.i64()?;
+        let partition_values = opt_field(&add, "partitionValues");
+        let stats = opt_field(&add, "stats");
+        let deletion_vectors = opt_field(&add, "deletionVector");
+        for i in 0..df.height() {
+            let Some(path) = str_at(&paths, i)? else {
+                continue;
+            };
+            actions.push(Action::Add(DeltaAddFile {
+                path: path.into(),
+                size: sizes.get(i).unwrap_or_default(),
+                partition_values: string_map_at(partition_values.as_ref(), i)?
+                    .into_iter()
+                    .map(|(k, v)| (k.into(), v.map(PlSmallStr::from)))
+                    .collect(),
+                stats: match &stats {
+                    Some(stats) => str_at(stats, i)?.map(String::from),
+                    None => None,
+                },
+                has_deletion_vector: deletion_vectors
+                    .as_ref()
+                    .map_or(false, |dv| dv.is_not_null().get(i) == Some(true)),
+            }));
+        }
+    }
+
+    if let Some(metadata) = struct_column(&df, "metaData")? {
+        let ids = metadata.field_by_name("id")?;
+        let schema_strings = metadata.field_by_name("schemaString")?;
+        let partition_columns = opt_field(&metadata, "partitionColumns");
+        let configuration = opt_field(&metadata, "configuration");
+        for i in 0..df.height() {
+            let (Some(id), Some(schema_string)) = (str_at(&ids, i)?, str_at(&schema_strings, i)?)
+            else {
+                continue;
+            };
+            actions.push(Action::Metadata(DeltaMetadata {
+                id: id.to_string(),
+                schema_string: schema_string.to_string(),
+                partition_columns: string_list_at(partition_columns.as_ref(), i)?
+                    .into_iter()
+                    .map(PlSmallStr::from)
+                    .collect(),
+                configuration: string_map_at(configuration.as_ref(), i)?
+                    .into_iter()
+                    .filter_map(|(k, v)| Some((k, v?)))
+                    .collect(),
+            }));
+        }
+    }
+
+    if let Some(protocol) = struct_column(&df, "protocol")? {
+        let reader_versions = protocol
+            .field_by_name("minReaderVersion")?
+            .cast(&DataType::Int64)?;
+        let writer_versions = protocol
+            .field_by_name("minWriterVersion")?
+            .cast(&DataType::Int64)?;
+        let reader_features = opt_field(&protocol, "readerFeatures");
+        let writer_features = opt_field(&protocol, "writerFeatures");
+        for i in 0..df.height() {
+            let Some(min_reader_version) = reader_versions.i64()?.get(i) else {
+                continue;
+            };
+            actions.push(Action::Protocol(DeltaProtocol {
+                min_reader_version,
+                min_writer_version: writer_versions.i64()?.get(i).unwrap_or_default(),
+                reader_features: string_list_at(reader_features.as_ref(), i)?,
+                writer_features: string_list_at(writer_features.as_ref(), i)?,
+            }));
+        }
+    }
+
+    Ok(actions)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum LogFile {
+    Commit(i64),
+    /// `(version, part, number of parts)`.
+    Checkpoint(i64, u32, u32),
+}
+
+fn parse_log_file_name(name: &str) -> Option<LogFile> {
+    let (version, rest) = name.split_once('.')?;
+    if version.len() != 20 {
+        return None;
+    }
+    let version = version.parse().ok()?;
+    match rest.split('.').collect::<Vec<_>>().as_slice() {
+        ["json"] => Some(LogFile::Commit(version)),
+        ["checkpoint", "parquet"] => Some(LogFile::Checkpoint(version, 1, 1)),
+        ["checkpoint", part, parts, "parquet"] if part.len() == 10 && parts.len() == 10 => Some(
+            LogFile::Checkpoint(version, part.parse().ok()?, parts.parse().ok()?),
+        ),
+        // V2 checkpoints and other files can be skipped, the commits are replayed instead.
+        _ => None,
+    }
+}
+
+/// The files of the log that have to be replayed to get a version of the table.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct LogSegment {
+    pub(super) version: i64,
+    pub(super) checkpoint: Vec<String>,
+    pub(super) commits: Vec<String>,
+}
+
+/// Select the files of the log to replay. Returns `None` if the log has no commits, which means
+/// the table doesn't exist.
+pub(super) fn log_segment(
+    file_names: &[String],
+    version: Option<i64>,
+) -> PolarsResult<Option<LogSegment>> {
+    let mut commits = PlHashSet::new();
+    let mut checkpoints: PlHashMap<(i64, u32), Vec<(u32, &str)>> = PlHashMap::new();
+    for name in file_names {
+        match parse_log_file_name(name) {
+            Some(LogFile::Commit(v)) => {
+                commits.insert(v);
+            },
+            Some(LogFile::Checkpoint(v, part, parts)) => {
+                checkpoints
+                    .entry((v, parts))
+                    .or_default()
+                    .push((part, name.as_str()));
+            },
+            None => {},
+        }
+    }
+
+    let Some(&latest) = commits.iter().max() else {
+        return Ok(None);
+    };
+    let version = version.unwrap_or(latest);
+    polars_ensure!(
+        version >= 0 && version <= latest,
+        ComputeError: "delta table version {} not found, the latest version is {}", version, latest
+    );
+
+    // Use the latest complete checkpoint at or before the version.
+    let checkpoint = checkpoints
+        .into_iter()
+        .filter(|((v, parts), files)| *v <= version && files.len() == *parts as usize)
+        .max_by_key(|((v, _), _)| *v)
+        .map(|((v, _), mut files)| {
+            files.sort_unstable();
+            (
+                v,
+                files
+                    .into_iter()
+                    .map(|(_, name)| name.to_string())
+                    .collect(),
+            )
+        });
+    let (start, checkpoint) = match checkpoint {
+        Some((v, files)) => (v + 1, files),
+        None => (0, vec![]),
+    };
+
+    let commits = (start..=version)
+        .map(|v| {
+            if commits.contains(&v) {
+                Ok(commit_file_name(v))
+            } else {
+                polars_bail!(ComputeError: "delta commit {} is missing from the log", v)
+            }
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    Ok(Some(LogSegment {
+        version,
+        checkpoint,
+        commits,
+    }))
+}
+
+/// The state of a table at a version.
+#[derive(Clone, Debug)]
+pub(super) struct Snapshot {
+    pub(super) version: i64,
+    pub(super) protocol: DeltaProtocol,
+    pub(super) metadata: DeltaMetadata,
+    pub(super) files: Vec<DeltaAddFile>,
+}
+
+/// Replay the log up to the version, or the latest version. Returns `None` if the table doesn't
+/// exist.
+pub(super) fn replay(
+    storage: &DeltaStorage,
+    version: Option<i64>,
+) -> PolarsResult<Option<Snapshot>> {
+    let Some(segment) = log_segment(&storage.list_log()?, version)? else {
+        return Ok(None);
+    };
+
+    let mut protocol = None;
+    let mut metadata = None;
+    let mut files = PlIndexMap::new();
+    let mut apply = |actions: Vec<Action>| {
+        for action in actions {
+            match action {
+                Action::Add(file) => {
+                    files.insert(file.path.clone(), file);
+                },
+                Action::Remove(path) => {
+                    files.shift_remove(&path);
+                },
+                Action::Metadata(m) => metadata = Some(m),
+                Action::Protocol(p) => protocol = Some(p),
+            }
+        }
+    };
+
+    for name in &segment.checkpoint {
+        apply(read_checkpoint(
+            storage.read(&format!("_delta_log/{name}"))?,
+        )?);
+    }
+    for name in &segment.commits {
+        apply(parse_commit(&storage.read(&format!("_delta_log/{name}"))?)?);
+    }
+
+    let (Some(protocol), Some(metadata)) = (protocol, metadata) else {
+        polars_bail!(ComputeError: "delta log has no protocol or metadata");
+    };
+    Ok(Some(Snapshot {
+        version: segment.version,
+        protocol,
+        metadata,
+        files: files.into_values().collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_segment() {
+        let names = [
+            "00000000000000000000.json",
+            "00000000000000000001.json",
+            "00000000000000000002.json",
+            "00000000000000000002.checkpoint.parquet",
+            "00000000000000000003.json",
+            "00000000000000000003.checkpoint.0000000001.0000000002.parquet",
+            "00000000000000000004.json",
+            "_last_checkpoint",
+        ]
+        .map(String::from);
+
+        let segment = log_segment(&names, None).unwrap().unwrap();
+        assert_eq!(segment.version, 4);
+        // The multi-part checkpoint of version 3 is incomplete.
+        assert_eq!(
+            segment.checkpoint,
+            ["00000000000000000002.checkpoint.parquet"]
+        );
+        assert_eq!(
+            segment.commits,
+            ["00000000000000000003.json", "00000000000000000004.json"]
+        );
+
+        let segment = log_segment(&names, Some(1)).unwrap().unwrap();
+        assert!(segment.checkpoint.is_empty());
+        assert_eq!(segment.commits.len(), 2);
+
+        assert!(log_segment(&names, Some(5)).is_err());
+        assert!(log_segment(&names[4..], Some(4)).is_err());
+        assert_eq!(log_segment(&[], None).unwrap(), None);
+    }
+}
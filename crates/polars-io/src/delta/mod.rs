@@ -0,0 +1,186 @@
+//! Reading and appending to [Delta Lake](https://delta.io) tables.
+//!
+//! The transaction log in `_delta_log` is replayed to get the live data files of a version of
+//! the table. The data files themselves are Parquet files that are read by the Parquet scan.
+//! Appends write new Parquet files and commit them to the log with optimistic concurrency: a
+//! commit only succeeds if no other writer committed the same version first.
+mod log;
+mod schema;
+mod stats;
+mod storage;
+mod write;
+
+pub use log::DeltaAddFile;
+use log::{DeltaMetadata, DeltaProtocol};
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure, PolarsResult};
+use storage::DeltaStorage;
+pub use write::DeltaWriter;
+
+use crate::cloud::CloudOptions;
+use crate::predicates::{BatchStats, PhysicalIoExpr};
+
+/// Reader features that don't change how the data files are read.
+const SUPPORTED_READER_FEATURES: &[&str] = &[
+    "timestampNtz",
+    "columnMapping",
+    "deletionVectors",
+    "vacuumProtocolCheck",
+];
+
+/// A random identifier in the format of a UUID, for the names of new files.
+fn unique_id() -> String {
+    let time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let hash = |seed: u64| ahash::RandomState::new().hash_one((time, std::process::id(), seed));
+    let id = ((hash(0) as u128) << 64) | hash(1) as u128;
+    let hex = format!("{id:032x}");
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+fn check_reader_protocol(protocol: &DeltaProtocol, metadata: &DeltaMetadata) -> PolarsResult<()> {
+    let column_mapping = metadata
+        .config("delta.columnMapping.mode")
+        .unwrap_or("none");
+    polars_ensure!(
+        column_mapping == "none",
+        ComputeError: "delta tables with column mapping mode '{}' are not supported", column_mapping
+    );
+    match protocol.min_reader_version {
+        1 | 2 => Ok(()),
+        3 => {
+            for feature in &protocol.reader_features {
+                polars_ensure!(
+                    SUPPORTED_READER_FEATURES.contains(&feature.as_str()),
+                    ComputeError: "delta reader feature '{}' is not supported", feature
+                );
+            }
+            Ok(())
+        },
+        version => polars_bail!(ComputeError: "delta reader version {} is not supported", version),
+    }
+}
+
+/// A version of a Delta table.
+#[derive(Clone, Debug)]
+pub struct DeltaTable {
+    storage: DeltaStorage,
+    version: i64,
+    partition_columns: Vec<PlSmallStr>,
+    schema: SchemaRef,
+    files: Vec<DeltaAddFile>,
+}
+
+impl DeltaTable {
+    /// Open a Delta table at `path`, the root directory of the table. The latest version is
+    /// read if `version` is `None`.
+    pub fn open(
+        path: &str,
+        version: Option<i64>,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let storage = DeltaStorage::new(path, cloud_options)?;
+        let Some(snapshot) = log::replay(&storage, version)? else {
+            polars_bail!(ComputeError: "no delta table found at {}", path);
+        };
+        check_reader_protocol(&snapshot.protocol, &snapshot.metadata)?;
+        if let Some(file) = snapshot.files.iter().find(|file| file.has_deletion_vector) {
+            polars_bail!(
+                ComputeError: "delta data file {} has a deletion vector, which is not supported",
+                file.path
+            );
+        }
+
+        Ok(Self {
+            storage,
+            version: snapshot.version,
+            schema: Arc::new(schema::parse_schema(&snapshot.metadata.schema_string)?),
+            partition_columns: snapshot.metadata.partition_columns,
+            files: snapshot.files,
+        })
+    }
+
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    /// The schema of the table, including the partition columns.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    pub fn partition_columns(&self) -> &[PlSmallStr] {
+        &self.partition_columns
+    }
+
+    /// The live data files of this version.
+    pub fn files(&self) -> &[DeltaAddFile] {
+        &self.files
+    }
+
+    /// The location of a data file, as it can be passed to a reader.
+    pub fn location(&self, file: &DeltaAddFile) -> String {
+        // Paths in the log are URIs, which are percent-encoded.
+        let path = percent_encoding::percent_decode_str(&file.path).decode_utf8_lossy();
+        if path.contains("://") {
+            path.into_owned()
+        } else {
+            self.storage.location(&path)
+        }
+    }
+
+    /// Statistics of a data file, from its `stats` and partition values.
+    pub fn statistics(&self, file: &DeltaAddFile) -> BatchStats {
+        stats::collect_file_statistics(file, &self.schema)
+    }
+
+    /// Whether the data file may contain rows that match the predicate.
+    pub fn should_read(
+        &self,
+        file: &DeltaAddFile,
+        predicate: &dyn PhysicalIoExpr,
+    ) -> PolarsResult<bool> {
+        let Some(evaluator) = predicate.as_stats_evaluator() else {
+            return Ok(true);
+        };
+        match evaluator.should_read(&self.statistics(file)) {
+            // The predicate may refer to columns that are not in the table.
+            Err(PolarsError::ColumnNotFound(_)) => Ok(true),
+            result => result,
+        }
+    }
+
+    /// The values of the partition columns of a data file, as single value Series.
+    pub fn partition_values(&self, file: &DeltaAddFile) -> PolarsResult<Vec<Series>> {
+        self.partition_columns
+            .iter()
+            .map(|name| {
+                let dtype = self.schema.try_get(name)?;
+                let value = file
+                    .partition_values
+                    .iter()
+                    .find(|(k, _)| k == name)
+                    .and_then(|(_, v)| v.as_deref())
+                    .filter(|v| *v != stats::NULL_PARTITION);
+                match value {
+                    Some(value) => match stats::parse_scalar(name, dtype, value) {
+                        Some(s) => Ok(s),
+                        None => polars_bail!(
+                            ComputeError: "invalid value '{}' of partition column '{}'", value, name
+                        ),
+                    },
+                    None => Ok(Series::full_null(name.clone(), 1, dtype)),
+                }
+            })
+            .collect()
+    }
+}
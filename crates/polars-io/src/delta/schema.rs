@@ -0,0 +1,171 @@
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_err, PolarsResult};
+use serde_json::{json, Value};
+
+/// Parse the `schemaString` of the table metadata.
+pub(super) fn parse_schema(schema_string: &str) -> PolarsResult<Schema> {
+    let value: Value = serde_json::from_str(schema_string)
+        .map_err(|err| polars_err!(ComputeError: "invalid delta schema: {}", err))?;
+    match delta_type_to_dtype(&value)? {
+        DataType::Struct(fields) => Ok(fields.into_iter().collect()),
+        _ => polars_bail!(ComputeError: "delta schema must be a struct"),
+    }
+}
+
+fn delta_type_to_dtype(ty: &Value) -> PolarsResult<DataType> {
+    if let Some(ty) = ty.as_str() {
+        return Ok(match ty {
+            "string" => DataType::String,
+            "long" => DataType::Int64,
+            "integer" => DataType::Int32,
+            "short" => DataType::Int16,
+            "byte" => DataType::Int8,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "boolean" => DataType::Boolean,
+            "binary" => DataType::Binary,
+            "date" => DataType::Date,
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            "timestamp_ntz" => DataType::Datetime(TimeUnit::Microseconds, None),
+            ty if ty.starts_with("decimal(") => {
+                let parse = || -> Option<(usize, usize)> {
+                    let (precision, scale) = ty
+                        .strip_prefix("decimal(")?
+                        .strip_suffix(')')?
+                        .split_once(',')?;
+                    Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+                };
+                let Some((precision, scale)) = parse() else {
+                    polars_bail!(ComputeError: "invalid delta type '{}'", ty);
+                };
+                DataType::Decimal(Some(precision), Some(scale))
+            },
+            ty => polars_bail!(ComputeError: "unsupported delta type '{}'", ty),
+        });
+    }
+
+    let get = |key: &str| {
+        ty.get(key)
+            .ok_or_else(|| polars_err!(ComputeError: "invalid delta type: missing key '{}'", key))
+    };
+    Ok(match get("type")?.as_str() {
+        Some("struct") => {
+            let Value::Array(fields) = get("fields")? else {
+                polars_bail!(ComputeError: "struct fields must be an array");
+            };
+            DataType::Struct(
+                fields
+                    .iter()
+                    .map(|field| {
+                        let Some(name) = field.get("name").and_then(Value::as_str) else {
+                            polars_bail!(ComputeError: "struct field without a name");
+                        };
+                        let dtype = field.get("type").ok_or_else(
+                            || polars_err!(ComputeError: "struct field '{}' without a type", name),
+                        )?;
+                        Ok(Field::new(name.into(), delta_type_to_dtype(dtype)?))
+                    })
+                    .collect::<PolarsResult<_>>()?,
+            )
+        },
+        Some("array") => DataType::List(Box::new(delta_type_to_dtype(get("elementType")?)?)),
+        Some("map") => {
+            let key = delta_type_to_dtype(get("keyType")?)?;
+            let value = delta_type_to_dtype(get("valueType")?)?;
+            DataType::List(Box::new(DataType::Struct(vec![
+                Field::new("key".into(), key),
+                Field::new("value".into(), value),
+            ])))
+        },
+        _ => polars_bail!(ComputeError: "invalid delta type {}", ty),
+    })
+}
+
+/// Convert the schema of a DataFrame to the `schemaString` of new table metadata.
+pub(super) fn to_schema_string(schema: &Schema) -> PolarsResult<String> {
+    let fields = schema
+        .iter()
+        .map(|(name, dtype)| field_to_json(name, dtype))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(json!({"type": "struct", "fields": fields}).to_string())
+}
+
+fn field_to_json(name: &str, dtype: &DataType) -> PolarsResult<Value> {
+    Ok(json!({
+        "name": name,
+        "type": dtype_to_delta_type(dtype)?,
+        "nullable": true,
+        "metadata": {},
+    }))
+}
+
+fn dtype_to_delta_type(dtype: &DataType) -> PolarsResult<Value> {
+    Ok(match dtype {
+        DataType::String => "string".into(),
+        DataType::Int64 => "long".into(),
+        DataType::Int32 => "integer".into(),
+        DataType::Int16 => "short".into(),
+        DataType::Int8 => "byte".into(),
+        DataType::Float32 => "float".into(),
+        DataType::Float64 => "double".into(),
+        DataType::Boolean => "boolean".into(),
+        DataType::Binary => "binary".into(),
+        DataType::Date => "date".into(),
+        DataType::Datetime(_, Some(_)) => "timestamp".into(),
+        DataType::Datetime(_, None) => "timestamp_ntz".into(),
+        DataType::Decimal(Some(precision), Some(scale)) => {
+            format!("decimal({precision},{scale})").into()
+        },
+        DataType::List(inner) => json!({
+            "type": "array",
+            "elementType": dtype_to_delta_type(inner)?,
+            "containsNull": true,
+        }),
+        DataType::Struct(fields) => json!({
+            "type": "struct",
+            "fields": fields
+                .iter()
+                .map(|f| field_to_json(f.name(), f.dtype()))
+                .collect::<PolarsResult<Vec<_>>>()?,
+        }),
+        dtype => {
+            polars_bail!(ComputeError: "data type {} can't be written to a delta table", dtype)
+        },
+    })
+}
+
+/// Whether the table schema has a `timestamp_ntz` column, which requires a table feature.
+pub(super) fn has_timestamp_ntz(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::Datetime(_, None) => true,
+        DataType::List(inner) => has_timestamp_ntz(inner),
+        DataType::Struct(fields) => fields.iter().any(|f| has_timestamp_ntz(f.dtype())),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_roundtrip() {
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new(
+                "b".into(),
+                DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            ),
+            Field::new("c".into(), DataType::List(Box::new(DataType::String))),
+            Field::new(
+                "d".into(),
+                DataType::Struct(vec![Field::new(
+                    "x".into(),
+                    DataType::Decimal(Some(10), Some(2)),
+                )]),
+            ),
+        ]);
+        let schema_string = to_schema_string(&schema).unwrap();
+        assert_eq!(parse_schema(&schema_string).unwrap(), schema);
+    }
+}
@@ -0,0 +1,157 @@
+use polars_core::export::chrono::{DateTime, NaiveDate, NaiveDateTime};
+use polars_core::prelude::*;
+use serde_json::{Map, Value};
+
+use super::log::DeltaAddFile;
+use crate::predicates::{BatchStats, ColumnStats};
+
+/// The literal that Hive-style partition paths use for null partition values.
+pub(super) const NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+pub(super) fn parse_date(s: &str) -> Option<i32> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?;
+    Some((date - NaiveDate::from_ymd_opt(1970, 1, 1)?).num_days() as i32)
+}
+
+/// Parse a timestamp to microseconds since the epoch. Statistics are written as RFC 3339,
+/// partition values as `yyyy-MM-dd HH:mm:ss[.SSSSSS]`.
+pub(super) fn parse_timestamp(s: &str) -> Option<i64> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(s) {
+        return Some(ts.timestamp_micros());
+    }
+    ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+        .map(|ts| ts.and_utc().timestamp_micros())
+}
+
+/// Parse a scalar of a primitive column, serialized as a string.
+pub(super) fn parse_scalar(name: &PlSmallStr, dtype: &DataType, s: &str) -> Option<Series> {
+    let s = match dtype {
+        DataType::Boolean => Series::new(name.clone(), [s.parse::<bool>().ok()?]),
+        DataType::Date => Series::new(name.clone(), [parse_date(s)?]),
+        DataType::Datetime(_, _) => Series::new(name.clone(), [parse_timestamp(s)?]),
+        DataType::String => Series::new(name.clone(), [s]),
+        DataType::Binary => return None,
+        dtype if dtype.is_numeric() || dtype.is_decimal() => {
+            return Series::new(name.clone(), [s]).strict_cast(dtype).ok();
+        },
+        _ => return None,
+    };
+    s.cast(dtype).ok()
+}
+
+fn json_to_series(name: &PlSmallStr, dtype: &DataType, value: &Value) -> Option<Series> {
+    match value {
+        Value::String(s) => parse_scalar(name, dtype, s),
+        Value::Number(n) if dtype.is_integer() => {
+            Series::new(name.clone(), [n.as_i64()?]).cast(dtype).ok()
+        },
+        Value::Number(n) if dtype.is_float() => {
+            Series::new(name.clone(), [n.as_f64()?]).cast(dtype).ok()
+        },
+        Value::Number(n) => parse_scalar(name, dtype, &n.to_string()),
+        Value::Bool(b) => Series::new(name.clone(), [*b]).cast(dtype).ok(),
+        _ => None,
+    }
+}
+
+/// Collect the statistics of a data file from its `stats` JSON and partition values.
+pub(super) fn collect_file_statistics(file: &DeltaAddFile, schema: &SchemaRef) -> BatchStats {
+    let stats = file
+        .stats
+        .as_deref()
+        .and_then(|stats| serde_json::from_str::<Value>(stats).ok());
+    let values =
+        |key: &str| -> Option<&Map<String, Value>> { stats.as_ref()?.get(key)?.as_object() };
+    let (min_values, max_values, null_counts) = (
+        values("minValues"),
+        values("maxValues"),
+        values("nullCount"),
+    );
+    let num_records = stats
+        .as_ref()
+        .and_then(|stats| stats.get("numRecords")?.as_u64());
+
+    let column_stats = schema
+        .iter_fields()
+        .map(|field| {
+            let name = &field.name().clone();
+            let dtype = &field.dtype().clone();
+
+            if let Some((_, value)) = file.partition_values.iter().find(|(k, _)| k == name) {
+                // All rows of the file have the same partition value.
+                let value = match value.as_deref() {
+                    None | Some(NULL_PARTITION) => None,
+                    Some(value) => parse_scalar(name, dtype, value),
+                };
+                let null_count = num_records
+                    .filter(|_| value.is_none())
+                    .map_or(0, |n| n as IdxSize);
+                return match value {
+                    Some(value) => ColumnStats::new(
+                        field,
+                        Some(Series::new(name.clone(), [0 as IdxSize])),
+                        Some(value.clone()),
+                        Some(value),
+                    ),
+                    None if num_records.is_some() => ColumnStats::new(
+                        field,
+                        Some(Series::new(name.clone(), [null_count])),
+                        None,
+                        None,
+                    ),
+                    None => ColumnStats::from_field(field),
+                };
+            }
+
+            let bound = |values: Option<&Map<String, Value>>| {
+                json_to_series(name, dtype, values?.get(name.as_str())?)
+            };
+            let min = bound(min_values);
+            let mut max = bound(max_values);
+            // Timestamps in statistics are truncated to milliseconds, the timestamps of delta
+            // tables are in microseconds.
+            if let (Some(m), DataType::Datetime(_, _)) = (&max, dtype) {
+                max = m.to_physical_repr().as_ref().i64().ok().and_then(|ca| {
+                    let v = ca.get(0)? + 999;
+                    Series::new(name.clone(), [v]).cast(dtype).ok()
+                });
+            }
+            let null_count = null_counts
+                .and_then(|counts| counts.get(name.as_str())?.as_u64())
+                .map(|n| Series::new(name.clone(), [n as IdxSize]));
+            ColumnStats::new(field, null_count, min, max)
+        })
+        .collect();
+
+    BatchStats::new(
+        schema.clone(),
+        column_stats,
+        num_records.map(|n| n as usize),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scalar() {
+        let name = PlSmallStr::from_static("a");
+        let ts = DataType::Datetime(TimeUnit::Microseconds, None);
+        assert_eq!(parse_date("1970-01-02"), Some(1));
+        assert_eq!(parse_timestamp("1970-01-01T00:00:01.000Z"), Some(1_000_000));
+        assert_eq!(parse_timestamp("1970-01-01 00:00:00.5"), Some(500_000));
+        assert_eq!(
+            parse_scalar(&name, &ts, "1970-01-01 00:00:01")
+                .unwrap()
+                .to_physical_repr()
+                .i64()
+                .unwrap()
+                .get(0),
+            Some(1_000_000)
+        );
+        assert!(parse_scalar(&name, &DataType::Int32, "x").is_none());
+    }
+}
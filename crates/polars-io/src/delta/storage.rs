@@ -0,0 +1,185 @@
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use polars_error::{polars_bail, to_compute_err, PolarsResult};
+
+use super::unique_id;
+use crate::cloud::CloudOptions;
+use crate::path_utils::is_cloud_url;
+
+/// The directory of a Delta table, either on the local file system or in an object store.
+#[derive(Clone, Debug)]
+pub(super) enum DeltaStorage {
+    Local(PathBuf),
+    #[cfg(feature = "cloud")]
+    Cloud {
+        uri: String,
+        prefix: String,
+        store: std::sync::Arc<dyn object_store::ObjectStore>,
+    },
+}
+
+impl DeltaStorage {
+    pub(super) fn new(
+        path: &str,
+        #[cfg_attr(not(feature = "cloud"), allow(unused_variables))] cloud_options: Option<
+            &CloudOptions,
+        >,
+    ) -> PolarsResult<Self> {
+        let path = path.trim_end_matches('/');
+        if is_cloud_url(path) {
+            #[cfg(feature = "cloud")]
+            {
+                use crate::cloud::{build_object_store, CloudLocation};
+
+                let (CloudLocation { prefix, .. }, store) = crate::pl_async::get_runtime()
+                    .block_on_potential_spawn(build_object_store(path, cloud_options, false))?;
+                return Ok(Self::Cloud {
+                    uri: path.to_string(),
+                    prefix: prefix.trim_end_matches('/').to_string(),
+                    store,
+                });
+            }
+            #[cfg(not(feature = "cloud"))]
+            polars_bail!(ComputeError: "the 'cloud' feature is required to read {}", path);
+        }
+        let path = path
+            .strip_prefix("file://")
+            .or_else(|| path.strip_prefix("file:"))
+            .unwrap_or(path);
+        Ok(Self::Local(PathBuf::from(path)))
+    }
+
+    /// The location of a file of the table, as it can be passed to a reader.
+    pub(super) fn location(&self, relative: &str) -> String {
+        match self {
+            Self::Local(root) => root.join(relative).to_string_lossy().into_owned(),
+            #[cfg(feature = "cloud")]
+            Self::Cloud { uri, .. } => format!("{uri}/{relative}"),
+        }
+    }
+
+    #[cfg(feature = "cloud")]
+    fn object_path(prefix: &str, relative: &str) -> PolarsResult<object_store::path::Path> {
+        if prefix.is_empty() {
+            crate::cloud::object_path_from_str(relative)
+        } else {
+            crate::cloud::object_path_from_str(&format!("{prefix}/{relative}"))
+        }
+    }
+
+    /// The names of the files in the `_delta_log` directory.
+    pub(super) fn list_log(&self) -> PolarsResult<Vec<String>> {
+        match self {
+            Self::Local(root) => {
+                let dir = root.join("_delta_log");
+                let entries = match std::fs::read_dir(&dir) {
+                    Ok(entries) => entries,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+                    Err(err) => {
+                        polars_bail!(ComputeError: "could not read {}: {}", dir.display(), err)
+                    },
+                };
+                Ok(entries
+                    .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                    .collect())
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { prefix, store, .. } => {
+                use futures::TryStreamExt;
+
+                let log_prefix = Self::object_path(prefix, "_delta_log")?;
+                let objects = crate::pl_async::get_runtime().block_on_potential_spawn(async {
+                    store.list(Some(&log_prefix)).try_collect::<Vec<_>>().await
+                })?;
+                Ok(objects
+                    .into_iter()
+                    .filter_map(|object| object.location.filename().map(String::from))
+                    .collect())
+            },
+        }
+    }
+
+    pub(super) fn read(&self, relative: &str) -> PolarsResult<Bytes> {
+        match self {
+            Self::Local(root) => {
+                let path = root.join(relative);
+                std::fs::read(&path)
+                    .map(Bytes::from)
+                    .map_err(|err| io_error(&path.to_string_lossy(), err))
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { prefix, store, .. } => {
+                let path = Self::object_path(prefix, relative)?;
+                crate::pl_async::get_runtime()
+                    .block_on_potential_spawn(async { Ok(store.get(&path).await?.bytes().await?) })
+            },
+        }
+    }
+
+    pub(super) fn write(&self, relative: &str, bytes: Vec<u8>) -> PolarsResult<()> {
+        match self {
+            Self::Local(root) => {
+                let path = root.join(relative);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent).map_err(to_compute_err)?;
+                }
+                std::fs::write(&path, bytes).map_err(|err| io_error(&path.to_string_lossy(), err))
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { prefix, store, .. } => {
+                let path = Self::object_path(prefix, relative)?;
+                crate::pl_async::get_runtime().block_on_potential_spawn(async {
+                    store.put(&path, bytes.into()).await?;
+                    Ok(())
+                })
+            },
+        }
+    }
+
+    /// Write a file only if it doesn't exist yet. Returns `false` if it already exists.
+    pub(super) fn write_if_absent(&self, relative: &str, bytes: Vec<u8>) -> PolarsResult<bool> {
+        match self {
+            Self::Local(root) => {
+                let path = root.join(relative);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent).map_err(to_compute_err)?;
+                }
+                // The file is written under a temporary name and then linked to its final name, so
+                // that readers never see a partially written file.
+                let file_name = path.file_name().unwrap().to_string_lossy();
+                let tmp_path = path.with_file_name(format!(".{file_name}.{}.tmp", unique_id()));
+                std::fs::write(&tmp_path, bytes)
+                    .map_err(|err| io_error(&tmp_path.to_string_lossy(), err))?;
+                let linked = std::fs::hard_link(&tmp_path, &path);
+                let _ = std::fs::remove_file(&tmp_path);
+                match linked {
+                    Ok(()) => Ok(true),
+                    Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+                    Err(err) => Err(io_error(&path.to_string_lossy(), err)),
+                }
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { prefix, store, .. } => {
+                use object_store::{PutMode, PutOptions};
+
+                let path = Self::object_path(prefix, relative)?;
+                let opts = PutOptions {
+                    mode: PutMode::Create,
+                    ..Default::default()
+                };
+                crate::pl_async::get_runtime().block_on_potential_spawn(async {
+                    match store.put_opts(&path, bytes.into(), opts).await {
+                        Ok(_) => Ok(true),
+                        Err(object_store::Error::AlreadyExists { .. }) => Ok(false),
+                        Err(err) => Err(err.into()),
+                    }
+                })
+            },
+        }
+    }
+}
+
+fn io_error(path: &str, err: std::io::Error) -> polars_error::PolarsError {
+    polars_error::polars_err!(ComputeError: "could not access {}: {}", path, err)
+}
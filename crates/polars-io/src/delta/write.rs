@@ -0,0 +1,433 @@
+use polars_core::export::chrono::DateTime;
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsResult};
+use serde_json::{json, Map, Value};
+
+use super::log::{commit_file_name, parse_commit, replay, Action, DeltaMetadata, DeltaProtocol};
+use super::schema::{has_timestamp_ntz, parse_schema, to_schema_string};
+use super::stats::NULL_PARTITION;
+use super::storage::DeltaStorage;
+use super::unique_id;
+use crate::cloud::CloudOptions;
+use crate::parquet::write::{ParquetCompression, ParquetWriter};
+use crate::utils::URL_ENCODE_CHAR_SET;
+
+/// Characters that are escaped in the paths of the log, which are URIs.
+const URI_ENCODE_CHAR_SET: &percent_encoding::AsciiSet = &percent_encoding::CONTROLS
+    .add(b' ')
+    .add(b'%')
+    .add(b'#')
+    .add(b'?');
+
+/// Writer features that don't require anything of an append, as long as the table doesn't
+/// have generated columns, constraints or identity columns. Those are checked separately.
+const SUPPORTED_WRITER_FEATURES: &[&str] = &[
+    "appendOnly",
+    "invariants",
+    "checkConstraints",
+    "changeDataFeed",
+    "generatedColumns",
+    "columnMapping",
+    "identityColumns",
+    "timestampNtz",
+    "deletionVectors",
+    "vacuumProtocolCheck",
+];
+
+/// Column metadata keys of features that an append would have to enforce.
+const UNSUPPORTED_COLUMN_METADATA: &[&str] = &[
+    "delta.generationExpression",
+    "delta.identity.",
+    "delta.invariants",
+];
+
+/// Append a DataFrame to a Delta table, creating the table if it doesn't exist.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::delta::DeltaWriter;
+///
+/// fn example(df: &DataFrame) -> PolarsResult<i64> {
+///     DeltaWriter::new("/tmp/table")
+///         .with_partition_by(["year"])
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct DeltaWriter {
+    path: String,
+    cloud_options: Option<CloudOptions>,
+    partition_by: Vec<PlSmallStr>,
+    max_commit_attempts: usize,
+}
+
+impl DeltaWriter {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            cloud_options: None,
+            partition_by: vec![],
+            max_commit_attempts: 10,
+        }
+    }
+
+    pub fn with_cloud_options(mut self, cloud_options: Option<CloudOptions>) -> Self {
+        self.cloud_options = cloud_options;
+        self
+    }
+
+    /// The partition columns of a new table. When appending to an existing table, these must be
+    /// empty or equal to the partition columns of the table.
+    pub fn with_partition_by(
+        mut self,
+        partition_by: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+    ) -> Self {
+        self.partition_by = partition_by.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The number of times a commit is attempted before giving up, when other writers commit
+    /// concurrently.
+    pub fn with_max_commit_attempts(mut self, max_commit_attempts: usize) -> Self {
+        self.max_commit_attempts = max_commit_attempts.max(1);
+        self
+    }
+
+    /// Write the DataFrame to new data files and commit them to the table. Returns the version
+    /// of the commit.
+    pub fn finish(&self, df: &DataFrame) -> PolarsResult<i64> {
+        let storage = DeltaStorage::new(&self.path, self.cloud_options.as_ref())?;
+
+        let (mut read_version, mut table_actions, schema, partition_by) = match replay(
+            &storage, None,
+        )? {
+            Some(snapshot) => {
+                check_writer_protocol(&snapshot.protocol, &snapshot.metadata)?;
+                let partition_by = snapshot.metadata.partition_columns;
+                polars_ensure!(
+                    self.partition_by.is_empty() || self.partition_by == partition_by,
+                    ComputeError: "delta table is partitioned by {:?}, not by {:?}",
+                    partition_by, self.partition_by
+                );
+                let schema = parse_schema(&snapshot.metadata.schema_string)?;
+                (snapshot.version, vec![], schema, partition_by)
+            },
+            None => {
+                for name in &self.partition_by {
+                    df.schema().try_get(name)?;
+                }
+                polars_ensure!(
+                    self.partition_by.len() < df.width(),
+                    ComputeError: "a delta table must have a column that is not a partition column"
+                );
+                let schema_string = to_schema_string(&df.schema())?;
+                let schema = parse_schema(&schema_string)?;
+                (
+                    -1,
+                    new_table_actions(&schema, schema_string, &self.partition_by),
+                    schema,
+                    self.partition_by.clone(),
+                )
+            },
+        };
+
+        let df = conform_to_schema(df, &schema)?;
+        let adds = self.write_data_files(&storage, &df, &partition_by)?;
+
+        for _ in 0..self.max_commit_attempts {
+            let version = read_version + 1;
+            let commit_info = json!({"commitInfo": {
+                "timestamp": now_millis(),
+                "operation": if table_actions.is_empty() { "WRITE" } else { "CREATE TABLE" },
+                "operationParameters": {"mode": "Append"},
+                "readVersion": read_version,
+                "isBlindAppend": true,
+                "engineInfo": concat!("polars/", env!("CARGO_PKG_VERSION")),
+            }});
+            let mut commit = String::new();
+            for action in std::iter::once(&commit_info)
+                .chain(&table_actions)
+                .chain(&adds)
+            {
+                commit.push_str(&action.to_string());
+                commit.push('\n');
+            }
+
+            if storage.write_if_absent(
+                &format!("_delta_log/{}", commit_file_name(version)),
+                commit.into_bytes(),
+            )? {
+                return Ok(version);
+            }
+
+            // Another writer committed this version first. Appends don't conflict with other
+            // appends or removes, only with changes of the schema or partitioning.
+            let winner =
+                parse_commit(&storage.read(&format!("_delta_log/{}", commit_file_name(version)))?)?;
+            for action in winner {
+                match action {
+                    Action::Metadata(metadata) => {
+                        polars_ensure!(
+                            parse_schema(&metadata.schema_string)? == schema
+                                && metadata.partition_columns == partition_by,
+                            ComputeError: "delta table schema or partitioning was changed by a concurrent commit"
+                        );
+                        // The table was created concurrently with the same schema, this write
+                        // becomes an append to it.
+                        table_actions.clear();
+                    },
+                    Action::Protocol(protocol) => {
+                        let Some(snapshot) = replay(&storage, Some(version))? else {
+                            unreachable!()
+                        };
+                        check_writer_protocol(&protocol, &snapshot.metadata)?;
+                    },
+                    Action::Add(_) | Action::Remove(_) => {},
+                }
+            }
+            read_version = version;
+        }
+        polars_bail!(
+            ComputeError: "could not commit to delta table at {} after {} attempts",
+            self.path, self.max_commit_attempts
+        )
+    }
+
+    /// Write a Parquet file for every partition of the DataFrame and return their `add`
+    /// actions.
+    fn write_data_files(
+        &self,
+        storage: &DeltaStorage,
+        df: &DataFrame,
+        partition_by: &[PlSmallStr],
+    ) -> PolarsResult<Vec<Value>> {
+        let partitions = if partition_by.is_empty() {
+            vec![df.clone()]
+        } else {
+            df.partition_by_stable(partition_by.iter().cloned(), true)?
+        };
+
+        partitions
+            .into_iter()
+            .filter(|part| part.height() > 0 || partition_by.is_empty())
+            .map(|part| {
+                let mut partition_values = Map::new();
+                let mut dir = String::new();
+                for name in partition_by {
+                    let value = partition_value(part.column(name)?.as_materialized_series())?;
+                    let encoded = percent_encoding::percent_encode(
+                        value.as_deref().unwrap_or(NULL_PARTITION).as_bytes(),
+                        URL_ENCODE_CHAR_SET,
+                    );
+                    dir.push_str(&format!("{name}={encoded}/"));
+                    partition_values.insert(name.to_string(), value.into());
+                }
+                let mut part = part.drop_many(partition_by.iter().cloned());
+
+                let relative = format!("{dir}part-00000-{}-c000.snappy.parquet", unique_id());
+                let mut bytes = vec![];
+                ParquetWriter::new(&mut bytes)
+                    .with_compression(ParquetCompression::Snappy)
+                    .finish(&mut part)?;
+                let size = bytes.len();
+                storage.write(&relative, bytes)?;
+
+                Ok(json!({"add": {
+                    "path": percent_encoding::utf8_percent_encode(&relative, URI_ENCODE_CHAR_SET)
+                        .to_string(),
+                    "partitionValues": partition_values,
+                    "size": size,
+                    "modificationTime": now_millis(),
+                    "dataChange": true,
+                    "stats": file_statistics(&part)?.to_string(),
+                }}))
+            })
+            .collect()
+    }
+}
+
+fn now_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
+
+fn new_table_actions(
+    schema: &Schema,
+    schema_string: String,
+    partition_by: &[PlSmallStr],
+) -> Vec<Value> {
+    let protocol = if schema.iter_values().any(has_timestamp_ntz) {
+        json!({"protocol": {
+            "minReaderVersion": 3,
+            "minWriterVersion": 7,
+            "readerFeatures": ["timestampNtz"],
+            "writerFeatures": ["timestampNtz"],
+        }})
+    } else {
+        json!({"protocol": {"minReaderVersion": 1, "minWriterVersion": 2}})
+    };
+    let metadata = json!({"metaData": {
+        "id": unique_id(),
+        "format": {"provider": "parquet", "options": {}},
+        "schemaString": schema_string,
+        "partitionColumns": partition_by.iter().map(|name| name.as_str()).collect::<Vec<_>>(),
+        "configuration": {},
+        "createdTime": now_millis(),
+    }});
+    vec![protocol, metadata]
+}
+
+fn check_writer_protocol(protocol: &DeltaProtocol, metadata: &DeltaMetadata) -> PolarsResult<()> {
+    polars_ensure!(
+        protocol.min_writer_version <= 7,
+        ComputeError: "delta writer version {} is not supported", protocol.min_writer_version
+    );
+    if protocol.min_writer_version == 7 {
+        for feature in &protocol.writer_features {
+            polars_ensure!(
+                SUPPORTED_WRITER_FEATURES.contains(&feature.as_str()),
+                ComputeError: "delta writer feature '{}' is not supported", feature
+            );
+        }
+    }
+
+    let column_mapping = metadata
+        .config("delta.columnMapping.mode")
+        .unwrap_or("none");
+    polars_ensure!(
+        column_mapping == "none",
+        ComputeError: "delta tables with column mapping mode '{}' are not supported", column_mapping
+    );
+    if let Some((key, _)) = metadata
+        .configuration
+        .iter()
+        .find(|(key, _)| key.starts_with("delta.constraints."))
+    {
+        polars_bail!(ComputeError: "delta table constraint '{}' is not supported", key);
+    }
+    let schema: Value = serde_json::from_str(&metadata.schema_string)
+        .map_err(|err| polars_err!(ComputeError: "invalid delta schema: {}", err))?;
+    if let Some(key) = find_column_metadata(&schema) {
+        polars_bail!(ComputeError: "delta column metadata '{}' is not supported", key);
+    }
+    Ok(())
+}
+
+/// Find a column metadata key that an append would have to enforce, in any nested field.
+fn find_column_metadata(value: &Value) -> Option<&str> {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::Object(metadata)) = map.get("metadata") {
+                if let Some(key) = metadata.keys().find(|key| {
+                    UNSUPPORTED_COLUMN_METADATA
+                        .iter()
+                        .any(|prefix| key.starts_with(prefix))
+                }) {
+                    return Some(key);
+                }
+            }
+            map.values().find_map(find_column_metadata)
+        },
+        Value::Array(values) => values.iter().find_map(find_column_metadata),
+        _ => None,
+    }
+}
+
+/// Select the columns of the table schema and cast them to its types.
+fn conform_to_schema(df: &DataFrame, schema: &Schema) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        df.width() == schema.len(),
+        SchemaMismatch: "DataFrame has {} columns, the delta table has {}", df.width(), schema.len()
+    );
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| {
+            let column = df.column(name)?;
+            if column.dtype() == dtype {
+                Ok(column.clone())
+            } else {
+                column.strict_cast(dtype).map_err(|_| {
+                    polars_err!(
+                        SchemaMismatch: "column '{}' of type {} can't be written to delta type {}",
+                        name, column.dtype(), dtype
+                    )
+                })
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new(columns)
+}
+
+/// The serialized partition value of a partition, which has the same value in every row.
+fn partition_value(s: &Series) -> PolarsResult<Option<String>> {
+    let s = s.slice(0, 1);
+    Ok(match s.dtype() {
+        DataType::Datetime(tu, _) => s
+            .to_physical_repr()
+            .i64()?
+            .get(0)
+            .and_then(|v| datetime(v, *tu))
+            .map(|ts| ts.format("%Y-%m-%d %H:%M:%S%.6f").to_string()),
+        _ => s.cast(&DataType::String)?.str()?.get(0).map(String::from),
+    })
+}
+
+fn datetime(v: i64, tu: TimeUnit) -> Option<DateTime<polars_core::export::chrono::Utc>> {
+    match tu {
+        TimeUnit::Nanoseconds => Some(DateTime::from_timestamp_nanos(v)),
+        TimeUnit::Microseconds => DateTime::from_timestamp_micros(v),
+        TimeUnit::Milliseconds => DateTime::from_timestamp_millis(v),
+    }
+}
+
+fn stat_to_json(value: AnyValue) -> Option<Value> {
+    Some(match value {
+        AnyValue::Int8(v) => v.into(),
+        AnyValue::Int16(v) => v.into(),
+        AnyValue::Int32(v) => v.into(),
+        AnyValue::Int64(v) => v.into(),
+        AnyValue::Float32(v) if v.is_finite() => v.into(),
+        AnyValue::Float64(v) if v.is_finite() => v.into(),
+        AnyValue::String(v) => v.into(),
+        AnyValue::StringOwned(v) => v.as_str().into(),
+        AnyValue::Date(v) => DateTime::from_timestamp(v as i64 * 86400, 0)?
+            .format("%Y-%m-%d")
+            .to_string()
+            .into(),
+        AnyValue::Datetime(v, tu, _) => datetime(v, tu)?
+            .format("%Y-%m-%dT%H:%M:%S%.3fZ")
+            .to_string()
+            .into(),
+        _ => return None,
+    })
+}
+
+/// The `stats` of a data file: the number of records, and the bounds and null counts of its
+/// primitive columns.
+fn file_statistics(df: &DataFrame) -> PolarsResult<Value> {
+    let mut min_values = Map::new();
+    let mut max_values = Map::new();
+    let mut null_count = Map::new();
+    for column in df.get_columns() {
+        let s = column.as_materialized_series();
+        if s.dtype().is_nested() {
+            continue;
+        }
+        null_count.insert(s.name().to_string(), s.null_count().into());
+        if let Some(min) = stat_to_json(s.min_reduce()?.value().clone()) {
+            min_values.insert(s.name().to_string(), min);
+        }
+        if let Some(max) = stat_to_json(s.max_reduce()?.value().clone()) {
+            max_values.insert(s.name().to_string(), max);
+        }
+    }
+    Ok(json!({
+        "numRecords": df.height(),
+        "minValues": min_values,
+        "maxValues": max_values,
+        "nullCount": null_count,
+    }))
+}
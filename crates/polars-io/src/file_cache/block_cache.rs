@@ -0,0 +1,193 @@
+use std::io::Write;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use bytes::Bytes;
+use object_store::ObjectMeta;
+use once_cell::sync::Lazy;
+use polars_core::config;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::cache::FILE_CACHE;
+use super::cache_lock::GLOBAL_FILE_CACHE_LOCK;
+use super::entry::BLOCK_PREFIX;
+use super::stats::FILE_CACHE_COUNTERS;
+use super::utils::FILE_CACHE_PREFIX;
+
+/// Cache of byte ranges of remote files on local disk, keyed by (URI, version, range).
+///
+/// Enabled by setting `POLARS_BLOCK_CACHE=1`. Blocks are stored next to the cached files and
+/// are evicted by the same TTL and size limit.
+pub static BLOCK_CACHE: Lazy<Option<BlockCache>> = Lazy::new(|| {
+    if std::env::var("POLARS_BLOCK_CACHE").as_deref() != Ok("1") {
+        return None;
+    }
+    // Starts the eviction manager and creates the block directory.
+    Lazy::force(&FILE_CACHE);
+
+    let dir = FILE_CACHE_PREFIX
+        .join(std::str::from_utf8(&[BLOCK_PREFIX]).unwrap())
+        .into_boxed_path();
+
+    if config::verbose() {
+        eprintln!("block cache directory: {}", dir.to_str().unwrap());
+    }
+
+    Some(BlockCache::new(dir))
+});
+
+pub struct BlockCache {
+    dir: Box<Path>,
+}
+
+/// The version of a remote object that cached blocks belong to. Blocks of other versions are
+/// never returned.
+pub fn object_version(meta: &ObjectMeta) -> PlSmallStr {
+    match &meta.e_tag {
+        Some(e_tag) => e_tag.as_str().into(),
+        None => format!("{}", meta.last_modified.timestamp_millis()).into(),
+    }
+}
+
+impl BlockCache {
+    /// Create a block cache that stores its blocks in `dir`, which must exist.
+    pub(crate) fn new(dir: Box<Path>) -> Self {
+        Self { dir }
+    }
+
+    fn block_path(&self, uri: &str, version: &str, range: &Range<usize>) -> PathBuf {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(uri.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(version.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(&range.start.to_le_bytes());
+        hasher.update(&range.end.to_le_bytes());
+        self.dir.join(&hasher.finalize().to_hex()[..32])
+    }
+
+    /// Returns the cached block, if there is one.
+    pub fn get(&self, uri: &str, version: &str, range: &Range<usize>) -> Option<Bytes> {
+        let path = self.block_path(uri, version, range);
+        let _cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_any();
+
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(cfg!(target_family = "windows"))
+            .open(&path)
+            .ok()?;
+        let mut bytes = Vec::with_capacity(range.len());
+        std::io::Read::read_to_end(&mut &file, &mut bytes).ok()?;
+
+        // A block from an aborted write is ignored and will be overwritten.
+        if bytes.len() != range.len() {
+            return None;
+        }
+        super::utils::update_last_accessed(&file);
+        FILE_CACHE_COUNTERS.record_block_hit(bytes.len() as u64);
+        Some(bytes.into())
+    }
+
+    /// Stores a block that was fetched from the remote. Errors are ignored, the block is then
+    /// simply fetched again next time.
+    pub fn insert(&self, uri: &str, version: &str, range: &Range<usize>, bytes: &[u8]) {
+        FILE_CACHE_COUNTERS.record_block_miss(bytes.len() as u64);
+        if bytes.len() != range.len() {
+            return;
+        }
+
+        let path = self.block_path(uri, version, range);
+        let _cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_any();
+
+        // Write to a temporary file first so that other processes never see a partial block.
+        let tmp_path = path.with_extension(format!("tmp{}", std::process::id()));
+        let result = std::fs::File::create(&tmp_path)
+            .and_then(|mut file| file.write_all(bytes))
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+
+        if let Err(err) = result {
+            let _ = std::fs::remove_file(&tmp_path);
+            if config::verbose() {
+                eprintln!(
+                    "[block_cache] failed to write block to {}: {}",
+                    path.to_str().unwrap(),
+                    err
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_block_cache(name: &str) -> BlockCache {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        BlockCache::new(dir.into_boxed_path())
+    }
+
+    #[test]
+    fn test_block_cache_hit_and_miss() {
+        let cache = new_block_cache("polars_test_block_cache_hit_and_miss");
+        let range = 10..14;
+
+        assert!(cache.get("s3://bucket/a", "v1", &range).is_none());
+        let stats = FILE_CACHE_COUNTERS.snapshot();
+        cache.insert("s3://bucket/a", "v1", &range, b"abcd");
+        assert_eq!(
+            cache.get("s3://bucket/a", "v1", &range).unwrap(),
+            &b"abcd"[..]
+        );
+
+        let new_stats = FILE_CACHE_COUNTERS.snapshot();
+        assert!(new_stats.block_misses > stats.block_misses);
+        assert!(new_stats.block_hits > stats.block_hits);
+
+        // Other files and ranges are not hits.
+        assert!(cache.get("s3://bucket/b", "v1", &range).is_none());
+        assert!(cache.get("s3://bucket/a", "v1", &(10..13)).is_none());
+    }
+
+    #[test]
+    fn test_block_cache_ignores_other_version() {
+        let cache = new_block_cache("polars_test_block_cache_other_version");
+        let range = 0..4;
+
+        cache.insert("s3://bucket/a", "etag-1", &range, b"abcd");
+        // The object was overwritten, so its ETag changed.
+        assert!(cache.get("s3://bucket/a", "etag-2", &range).is_none());
+
+        cache.insert("s3://bucket/a", "etag-2", &range, b"efgh");
+        assert_eq!(
+            cache.get("s3://bucket/a", "etag-2", &range).unwrap(),
+            &b"efgh"[..]
+        );
+        assert_eq!(
+            cache.get("s3://bucket/a", "etag-1", &range).unwrap(),
+            &b"abcd"[..]
+        );
+    }
+
+    #[test]
+    fn test_block_cache_refetches_partial_block() {
+        let cache = new_block_cache("polars_test_block_cache_partial_block");
+        let range = 0..4;
+
+        // A fetch that returned fewer bytes than requested is not stored.
+        cache.insert("s3://bucket/a", "v1", &range, b"ab");
+        assert!(cache.get("s3://bucket/a", "v1", &range).is_none());
+
+        // A block that was left behind by an aborted write is ignored, and overwritten by the
+        // next fetch.
+        std::fs::write(cache.block_path("s3://bucket/a", "v1", &range), b"abc").unwrap();
+        assert!(cache.get("s3://bucket/a", "v1", &range).is_none());
+        cache.insert("s3://bucket/a", "v1", &range, b"abcd");
+        assert_eq!(
+            cache.get("s3://bucket/a", "v1", &range).unwrap(),
+            &b"abcd"[..]
+        );
+    }
+}
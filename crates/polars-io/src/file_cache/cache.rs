@@ -4,12 +4,13 @@ use std::sync::{Arc, RwLock};
 
 use once_cell::sync::Lazy;
 use polars_core::config;
-use polars_error::PolarsResult;
+use polars_error::{polars_err, PolarsResult};
 use polars_utils::aliases::PlHashMap;
 
-use super::entry::{FileCacheEntry, DATA_PREFIX, METADATA_PREFIX};
+use super::entry::{FileCacheEntry, BLOCK_PREFIX, DATA_PREFIX, METADATA_PREFIX};
 use super::eviction::EvictionManager;
 use super::file_fetcher::FileFetcher;
+use super::stats::{FileCacheStats, FILE_CACHE_COUNTERS};
 use super::utils::FILE_CACHE_PREFIX;
 use crate::path_utils::{ensure_directory_init, is_cloud_url};
 
@@ -23,6 +24,14 @@ pub static FILE_CACHE: Lazy<FileCache> = Lazy::new(|| {
 
     let min_ttl = Arc::new(AtomicU64::from(get_env_file_cache_ttl()));
     let notify_ttl_updated = Arc::new(tokio::sync::Notify::new());
+    // The size limit is validated in `init_entries_from_uri_list` before the cache is used.
+    let size_limit = get_env_file_cache_size_limit().unwrap_or_else(|err| {
+        if config::verbose() {
+            eprintln!("file cache: {}, the size limit is ignored", err);
+        }
+        None
+    });
+    let notify_file_fetched = Arc::new(tokio::sync::Notify::new());
 
     let metadata_dir = prefix
         .as_ref()
@@ -49,17 +58,40 @@ pub static FILE_CACHE: Lazy<FileCache> = Lazy::new(|| {
         )
     }
 
+    let block_dir = prefix
+        .as_ref()
+        .join(std::str::from_utf8(&[BLOCK_PREFIX]).unwrap())
+        .into_boxed_path();
+
+    if let Err(err) = ensure_directory_init(&block_dir) {
+        panic!(
+            "failed to create file cache block directory: path = {}, err = {}",
+            block_dir.to_str().unwrap(),
+            err
+        )
+    }
+
     EvictionManager {
         data_dir,
         metadata_dir,
+        block_dir,
         files_to_remove: None,
         min_ttl: min_ttl.clone(),
         notify_ttl_updated: notify_ttl_updated.clone(),
+        size_limit,
+        notify_file_fetched: notify_file_fetched.clone(),
     }
     .run_in_background();
 
     // Safety: We have created the data and metadata directories.
-    unsafe { FileCache::new_unchecked(prefix, min_ttl, notify_ttl_updated) }
+    unsafe {
+        FileCache::new_unchecked(
+            prefix,
+            min_ttl,
+            notify_ttl_updated,
+            size_limit.map(|_| notify_file_fetched),
+        )
+    }
 });
 
 pub struct FileCache {
@@ -67,6 +99,9 @@ pub struct FileCache {
     entries: Arc<RwLock<PlHashMap<Arc<str>, Arc<FileCacheEntry>>>>,
     min_ttl: Arc<AtomicU64>,
     notify_ttl_updated: Arc<tokio::sync::Notify>,
+    /// Wakes up the eviction manager when a file was fetched. Only set if the cache has a
+    /// size limit.
+    notify_file_fetched: Option<Arc<tokio::sync::Notify>>,
 }
 
 impl FileCache {
@@ -78,12 +113,14 @@ impl FileCache {
         prefix: Arc<Path>,
         min_ttl: Arc<AtomicU64>,
         notify_ttl_updated: Arc<tokio::sync::Notify>,
+        notify_file_fetched: Option<Arc<tokio::sync::Notify>>,
     ) -> Self {
         Self {
             prefix,
             entries: Default::default(),
             min_ttl,
             notify_ttl_updated,
+            notify_file_fetched,
         }
     }
 
@@ -157,6 +194,7 @@ impl FileCache {
                 self.prefix.clone(),
                 get_file_fetcher()?,
                 ttl,
+                self.notify_file_fetched.clone(),
             ));
             entries.insert(uri, entry.clone());
             Ok(entry.clone())
@@ -176,6 +214,11 @@ impl FileCache {
                 .map(Arc::clone)
         }
     }
+
+    /// Statistics of the file cache, counted since the start of this process.
+    pub fn stats(&self) -> FileCacheStats {
+        FILE_CACHE_COUNTERS.snapshot()
+    }
 }
 
 pub fn get_env_file_cache_ttl() -> u64 {
@@ -183,3 +226,18 @@ pub fn get_env_file_cache_ttl() -> u64 {
         .map(|x| x.parse::<u64>().expect("integer"))
         .unwrap_or(60 * 60)
 }
+
+/// The maximum total size in bytes of the files in the cache. When it is exceeded, the least
+/// recently accessed files are evicted, regardless of their TTL.
+pub fn get_env_file_cache_size_limit() -> PolarsResult<Option<u64>> {
+    std::env::var("POLARS_FILE_CACHE_SIZE_LIMIT")
+        .ok()
+        .map(|x| {
+            x.parse::<u64>().map_err(|_| {
+                polars_err!(
+                    InvalidOperation: "invalid value for POLARS_FILE_CACHE_SIZE_LIMIT: '{}', expected a number of bytes", x
+                )
+            })
+        })
+        .transpose()
+}
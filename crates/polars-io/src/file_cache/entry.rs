@@ -12,10 +12,12 @@ use super::cache_lock::{self, GLOBAL_FILE_CACHE_LOCK};
 use super::file_fetcher::{FileFetcher, RemoteMetadata};
 use super::file_lock::{FileLock, FileLockAnyGuard};
 use super::metadata::{EntryMetadata, FileVersion};
+use super::stats::FILE_CACHE_COUNTERS;
 use super::utils::update_last_accessed;
 
 pub(super) const DATA_PREFIX: u8 = b'd';
 pub(super) const METADATA_PREFIX: u8 = b'm';
+pub(super) const BLOCK_PREFIX: u8 = b'b';
 
 struct CachedData {
     last_modified: u64,
@@ -31,6 +33,7 @@ struct Inner {
     cached_data: Option<CachedData>,
     ttl: Arc<AtomicU64>,
     file_fetcher: Arc<dyn FileFetcher>,
+    notify_file_fetched: Option<Arc<tokio::sync::Notify>>,
 }
 
 struct EntryData {
@@ -65,6 +68,7 @@ impl Inner {
                     if verbose {
                         eprintln!("[file_cache::entry] try_open_assume_latest: opening already fetched file for uri = {}", self.uri.clone());
                     }
+                    FILE_CACHE_COUNTERS.record_hit(metadata.local_size);
                     return Ok(finish_open(data_file_path, metadata_file));
                 }
             }
@@ -97,6 +101,7 @@ impl Inner {
                         if verbose {
                             eprintln!("[file_cache::entry] try_open_check_latest: opening already fetched file for uri = {}", self.uri.clone());
                         }
+                        FILE_CACHE_COUNTERS.record_hit(metadata.local_size);
                         return Ok(finish_open(data_file_path, metadata_file));
                     }
                 }
@@ -124,6 +129,7 @@ impl Inner {
                         self.uri.clone()
                     );
                 }
+                FILE_CACHE_COUNTERS.record_hit(metadata.local_size);
                 return Ok(finish_open(data_file_path, metadata_file));
             }
         }
@@ -199,6 +205,11 @@ impl Inner {
             .try_write(&mut **metadata_file)
             .map_err(to_compute_err)?;
 
+        FILE_CACHE_COUNTERS.record_miss(local_size);
+        if let Some(notify) = &self.notify_file_fetched {
+            notify.notify_one();
+        }
+
         Ok(data_file)
     }
 
@@ -284,6 +295,7 @@ impl FileCacheEntry {
         path_prefix: Arc<Path>,
         file_fetcher: Arc<dyn FileFetcher>,
         file_cache_ttl: u64,
+        notify_file_fetched: Option<Arc<tokio::sync::Notify>>,
     ) -> Self {
         let metadata = FileLock::from(get_metadata_file_path(
             path_prefix.to_str().unwrap().as_bytes(),
@@ -307,6 +319,7 @@ impl FileCacheEntry {
                 cached_data: None,
                 ttl: ttl.clone(),
                 file_fetcher,
+                notify_file_fetched,
             }),
             ttl,
         })
@@ -8,28 +8,40 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::cache_lock::{GlobalFileCacheGuardExclusive, GLOBAL_FILE_CACHE_LOCK};
 use super::metadata::EntryMetadata;
+use super::stats::FILE_CACHE_COUNTERS;
 use crate::pl_async;
 
 #[derive(Debug, Clone)]
 pub(super) struct EvictionCandidate {
     path: PathBuf,
-    metadata_path: PathBuf,
+    /// `None` for blocks of the block cache, which have no metadata and a fixed TTL.
+    metadata_path: Option<PathBuf>,
     metadata_last_modified: SystemTime,
     ttl: u64,
+    /// Set if the file is evicted to keep the cache within its size limit rather than because
+    /// its TTL expired. Holds the last accessed time of the file when it was selected.
+    lru_last_accessed: Option<SystemTime>,
 }
 
 pub(super) struct EvictionManager {
     pub(super) data_dir: Box<Path>,
     pub(super) metadata_dir: Box<Path>,
+    pub(super) block_dir: Box<Path>,
     pub(super) files_to_remove: Option<Vec<EvictionCandidate>>,
     pub(super) min_ttl: Arc<AtomicU64>,
     pub(super) notify_ttl_updated: Arc<tokio::sync::Notify>,
+    /// Maximum total size in bytes of the data files and blocks.
+    pub(super) size_limit: Option<u64>,
+    pub(super) notify_file_fetched: Arc<tokio::sync::Notify>,
 }
 
 impl EvictionCandidate {
     fn update_ttl(&mut self) {
+        let Some(metadata_path) = &self.metadata_path else {
+            return;
+        };
         let Ok(metadata_last_modified) =
-            std::fs::metadata(&self.metadata_path).map(|md| md.modified().unwrap())
+            std::fs::metadata(metadata_path).map(|md| md.modified().unwrap())
         else {
             self.ttl = 0;
             return;
@@ -39,10 +51,7 @@ impl EvictionCandidate {
             return;
         }
 
-        let Ok(ref mut file) = std::fs::OpenOptions::new()
-            .read(true)
-            .open(&self.metadata_path)
-        else {
+        let Ok(ref mut file) = std::fs::OpenOptions::new().read(true).open(metadata_path) else {
             self.ttl = 0;
             return;
         };
@@ -91,28 +100,21 @@ impl EvictionCandidate {
         }
 
         let metadata = std::fs::metadata(path).unwrap();
+        let last_accessed = metadata
+            .accessed()
+            .unwrap_or_else(|_| metadata.modified().unwrap());
 
-        let since_last_accessed = match now.duration_since(
-            metadata
-                .accessed()
-                .unwrap_or_else(|_| metadata.modified().unwrap()),
-        ) {
-            Ok(v) => v.as_secs(),
-            Err(_) => {
+        if let Some(lru_last_accessed) = self.lru_last_accessed {
+            if last_accessed != lru_last_accessed {
                 if verbose {
-                    eprintln!("[EvictionManager] evict_files: skipping {} (last accessed time was updated)", path.to_str().unwrap());
+                    eprintln!(
+                        "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
+                        path.to_str().unwrap()
+                    );
                 }
                 return;
-            },
-        };
-
-        if since_last_accessed < self.ttl {
-            if verbose {
-                eprintln!(
-                    "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
-                    path.to_str().unwrap()
-                );
             }
+        } else if !self.ttl_expired(now, last_accessed, verbose) {
             return;
         }
 
@@ -138,13 +140,44 @@ impl EvictionCandidate {
                     err
                 );
             }
-        } else if verbose {
-            eprintln!(
-                "[EvictionManager] evict_files: removed file at {}",
-                path.to_str().unwrap()
-            );
+        } else {
+            if self.metadata_path.as_ref() != Some(&self.path) {
+                FILE_CACHE_COUNTERS.record_eviction(metadata.len());
+            }
+            if verbose {
+                eprintln!(
+                    "[EvictionManager] evict_files: removed file at {}",
+                    path.to_str().unwrap()
+                );
+            }
         }
     }
+
+    fn ttl_expired(&self, now: &SystemTime, last_accessed: SystemTime, verbose: bool) -> bool {
+        let path = &self.path;
+
+        let since_last_accessed = match now.duration_since(last_accessed) {
+            Ok(v) => v.as_secs(),
+            Err(_) => {
+                if verbose {
+                    eprintln!("[EvictionManager] evict_files: skipping {} (last accessed time was updated)", path.to_str().unwrap());
+                }
+                return false;
+            },
+        };
+
+        if since_last_accessed < self.ttl {
+            if verbose {
+                eprintln!(
+                    "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
+                    path.to_str().unwrap()
+                );
+            }
+            return false;
+        }
+
+        true
+    }
 }
 
 impl EvictionManager {
@@ -221,6 +254,9 @@ impl EvictionManager {
                         _ = self.notify_ttl_updated.notified() => {
                             continue;
                         }
+                        _ = self.notify_file_fetched.notified() => {
+                            break;
+                        }
                         _ = tokio::time::sleep(sleep_interval) => {
                             break;
                         }
@@ -267,6 +303,10 @@ impl EvictionManager {
         );
 
         let now = SystemTime::now();
+        // Data files and blocks that are kept after TTL eviction, as
+        // `(last accessed, size, candidate)`.
+        let mut kept_data_files = vec![];
+        let mut kept_size = 0;
 
         for file in data_files_iter {
             let file = file?;
@@ -283,27 +323,86 @@ impl EvictionManager {
 
             let mut eviction_candidate = EvictionCandidate {
                 path,
-                metadata_path,
+                metadata_path: Some(metadata_path),
                 metadata_last_modified: UNIX_EPOCH,
                 ttl: 0,
+                lru_last_accessed: None,
             };
             eviction_candidate.update_ttl();
 
             if eviction_candidate.should_remove(&now) {
                 files_to_remove.push(eviction_candidate);
+            } else if self.size_limit.is_some() {
+                // Symlinks to local files don't take up space in the cache.
+                let Ok(metadata) = std::fs::symlink_metadata(&eviction_candidate.path) else {
+                    continue;
+                };
+                if metadata.is_file() {
+                    let last_accessed = metadata
+                        .accessed()
+                        .unwrap_or_else(|_| metadata.modified().unwrap());
+                    kept_size += metadata.len();
+                    kept_data_files.push((last_accessed, metadata.len(), eviction_candidate));
+                }
+            }
+        }
+
+        if let Ok(block_files_iter) = std::fs::read_dir(self.block_dir.as_ref()) {
+            let ttl = self.min_ttl.load(std::sync::atomic::Ordering::Relaxed);
+
+            for file in block_files_iter {
+                let file = file?;
+
+                let eviction_candidate = EvictionCandidate {
+                    path: file.path(),
+                    metadata_path: None,
+                    metadata_last_modified: UNIX_EPOCH,
+                    ttl,
+                    lru_last_accessed: None,
+                };
+
+                if eviction_candidate.should_remove(&now) {
+                    files_to_remove.push(eviction_candidate);
+                } else if self.size_limit.is_some() {
+                    let Ok(metadata) = file.metadata() else {
+                        continue;
+                    };
+                    let last_accessed = metadata
+                        .accessed()
+                        .unwrap_or_else(|_| metadata.modified().unwrap());
+                    kept_size += metadata.len();
+                    kept_data_files.push((last_accessed, metadata.len(), eviction_candidate));
+                }
+            }
+        }
+
+        if let Some(size_limit) = self.size_limit {
+            if kept_size > size_limit {
+                // Evict the least recently accessed files until the cache fits.
+                kept_data_files.sort_unstable_by_key(|(last_accessed, _, _)| *last_accessed);
+
+                for (last_accessed, size, mut eviction_candidate) in kept_data_files {
+                    if kept_size <= size_limit {
+                        break;
+                    }
+                    kept_size -= size;
+                    eviction_candidate.lru_last_accessed = Some(last_accessed);
+                    files_to_remove.push(eviction_candidate);
+                }
             }
         }
 
         for file in metadata_files_iter {
             let file = file?;
             let path = file.path();
-            let metadata_path = path.clone();
+            let metadata_path = Some(path.clone());
 
             let mut eviction_candidate = EvictionCandidate {
                 path,
                 metadata_path,
                 metadata_last_modified: UNIX_EPOCH,
                 ttl: 0,
+                lru_last_accessed: None,
             };
 
             eviction_candidate.update_ttl();
@@ -330,3 +429,117 @@ impl EvictionManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_manager(name: &str, size_limit: u64) -> EvictionManager {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        let [data_dir, metadata_dir, block_dir] = ["d", "m", "b"].map(|x| {
+            let dir = dir.join(x);
+            std::fs::create_dir_all(&dir).unwrap();
+            dir.into_boxed_path()
+        });
+
+        EvictionManager {
+            data_dir,
+            metadata_dir,
+            block_dir,
+            files_to_remove: None,
+            min_ttl: Arc::new(AtomicU64::new(3600)),
+            notify_ttl_updated: Default::default(),
+            size_limit: Some(size_limit),
+            notify_file_fetched: Default::default(),
+        }
+    }
+
+    /// Write a data file of `len` bytes with a metadata file that keeps it for an hour.
+    fn write_entry(
+        manager: &EvictionManager,
+        hash: &str,
+        len: usize,
+        last_accessed: SystemTime,
+    ) -> PathBuf {
+        let metadata = EntryMetadata::new(hash.into(), 3600);
+        let mut metadata_file = std::fs::File::create(manager.metadata_dir.join(hash)).unwrap();
+        metadata.try_write(&mut metadata_file).unwrap();
+
+        let path = manager.data_dir.join(format!("{hash}0"));
+        std::fs::write(&path, vec![0u8; len]).unwrap();
+        set_last_accessed(&path, last_accessed);
+        path
+    }
+
+    fn set_last_accessed(path: &Path, last_accessed: SystemTime) {
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_times(
+                std::fs::FileTimes::new()
+                    .set_accessed(last_accessed)
+                    .set_modified(last_accessed),
+            )
+            .unwrap();
+    }
+
+    fn evict(manager: &mut EvictionManager) {
+        let guard = loop {
+            if let Some(guard) = GLOBAL_FILE_CACHE_LOCK.try_lock_exclusive() {
+                break guard;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        manager.evict_files(&guard);
+    }
+
+    #[test]
+    fn test_evict_least_recently_accessed() {
+        let mut manager = new_manager("polars_test_evict_lru", 150);
+        let now = SystemTime::now();
+        let ago = |secs| now - Duration::from_secs(secs);
+        let a = write_entry(&manager, &"a".repeat(32), 100, ago(300));
+        let b = write_entry(&manager, &"b".repeat(32), 100, ago(100));
+        let c = write_entry(&manager, &"c".repeat(32), 100, ago(200));
+
+        manager.update_file_list().unwrap();
+        let selected = manager
+            .files_to_remove
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|candidate| candidate.path.clone())
+            .collect::<Vec<_>>();
+        // The least recently accessed files are evicted until the cache fits its size limit.
+        assert_eq!(selected, [a.clone(), c.clone()]);
+
+        let evicted_files = FILE_CACHE_COUNTERS.snapshot().evicted_files;
+        evict(&mut manager);
+        assert!(!a.exists());
+        assert!(b.exists());
+        assert!(!c.exists());
+        assert!(FILE_CACHE_COUNTERS.snapshot().evicted_files >= evicted_files + 2);
+    }
+
+    #[test]
+    fn test_evict_skips_file_accessed_after_selection() {
+        let mut manager = new_manager("polars_test_evict_accessed", 0);
+        let now = SystemTime::now();
+        let a = write_entry(
+            &manager,
+            &"a".repeat(32),
+            100,
+            now - Duration::from_secs(300),
+        );
+
+        manager.update_file_list().unwrap();
+        assert_eq!(manager.files_to_remove.as_ref().unwrap().len(), 1);
+
+        // The file was read by another process in the meantime.
+        set_last_accessed(&a, now);
+        evict(&mut manager);
+        assert!(a.exists());
+    }
+}
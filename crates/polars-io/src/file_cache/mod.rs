@@ -1,3 +1,4 @@
+mod block_cache;
 mod cache;
 mod cache_lock;
 mod entry;
@@ -5,7 +6,10 @@ mod eviction;
 mod file_fetcher;
 mod file_lock;
 mod metadata;
+mod stats;
 mod utils;
-pub use cache::{get_env_file_cache_ttl, FILE_CACHE};
+pub use block_cache::{object_version, BlockCache, BLOCK_CACHE};
+pub use cache::{get_env_file_cache_size_limit, get_env_file_cache_ttl, FILE_CACHE};
 pub use entry::FileCacheEntry;
+pub use stats::FileCacheStats;
 pub use utils::{init_entries_from_uri_list, FILE_CACHE_PREFIX};
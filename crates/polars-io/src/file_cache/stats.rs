@@ -0,0 +1,128 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counters of the file cache of this process.
+#[derive(Debug, Default)]
+pub(super) struct FileCacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    hit_bytes: AtomicU64,
+    fetched_bytes: AtomicU64,
+    evicted_files: AtomicU64,
+    evicted_bytes: AtomicU64,
+    block_hits: AtomicU64,
+    block_misses: AtomicU64,
+    block_hit_bytes: AtomicU64,
+    block_fetched_bytes: AtomicU64,
+}
+
+pub(super) static FILE_CACHE_COUNTERS: FileCacheCounters = FileCacheCounters {
+    hits: AtomicU64::new(0),
+    misses: AtomicU64::new(0),
+    hit_bytes: AtomicU64::new(0),
+    fetched_bytes: AtomicU64::new(0),
+    evicted_files: AtomicU64::new(0),
+    evicted_bytes: AtomicU64::new(0),
+    block_hits: AtomicU64::new(0),
+    block_misses: AtomicU64::new(0),
+    block_hit_bytes: AtomicU64::new(0),
+    block_fetched_bytes: AtomicU64::new(0),
+};
+
+impl FileCacheCounters {
+    pub(super) fn record_hit(&self, size: u64) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        self.hit_bytes.fetch_add(size, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_miss(&self, size: u64) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        self.fetched_bytes.fetch_add(size, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_eviction(&self, size: u64) {
+        self.evicted_files.fetch_add(1, Ordering::Relaxed);
+        self.evicted_bytes.fetch_add(size, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_block_hit(&self, size: u64) {
+        self.block_hits.fetch_add(1, Ordering::Relaxed);
+        self.block_hit_bytes.fetch_add(size, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_block_miss(&self, size: u64) {
+        self.block_misses.fetch_add(1, Ordering::Relaxed);
+        self.block_fetched_bytes.fetch_add(size, Ordering::Relaxed);
+    }
+
+    pub(super) fn snapshot(&self) -> FileCacheStats {
+        FileCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            hit_bytes: self.hit_bytes.load(Ordering::Relaxed),
+            fetched_bytes: self.fetched_bytes.load(Ordering::Relaxed),
+            evicted_files: self.evicted_files.load(Ordering::Relaxed),
+            evicted_bytes: self.evicted_bytes.load(Ordering::Relaxed),
+            block_hits: self.block_hits.load(Ordering::Relaxed),
+            block_misses: self.block_misses.load(Ordering::Relaxed),
+            block_hit_bytes: self.block_hit_bytes.load(Ordering::Relaxed),
+            block_fetched_bytes: self.block_fetched_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Statistics of the file cache, counted since the start of this process.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FileCacheStats {
+    /// Number of files opened from the cache.
+    pub hits: u64,
+    /// Number of files that had to be fetched.
+    pub misses: u64,
+    /// Bytes of the files opened from the cache.
+    pub hit_bytes: u64,
+    /// Bytes fetched into the cache.
+    pub fetched_bytes: u64,
+    /// Number of data files and blocks evicted by this process.
+    pub evicted_files: u64,
+    /// Bytes of the data files and blocks evicted by this process.
+    pub evicted_bytes: u64,
+    /// Number of byte ranges read from the block cache.
+    pub block_hits: u64,
+    /// Number of byte ranges that had to be fetched.
+    pub block_misses: u64,
+    /// Bytes read from the block cache.
+    pub block_hit_bytes: u64,
+    /// Bytes of the byte ranges that had to be fetched.
+    pub block_fetched_bytes: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_cache_counters() {
+        let counters = FileCacheCounters::default();
+        counters.record_hit(10);
+        counters.record_hit(5);
+        counters.record_miss(100);
+        counters.record_eviction(100);
+        counters.record_block_hit(3);
+        counters.record_block_miss(7);
+
+        assert_eq!(
+            counters.snapshot(),
+            FileCacheStats {
+                hits: 2,
+                misses: 1,
+                hit_bytes: 15,
+                fetched_bytes: 100,
+                evicted_files: 1,
+                evicted_bytes: 100,
+                block_hits: 1,
+                block_misses: 1,
+                block_hit_bytes: 3,
+                block_fetched_bytes: 7,
+            }
+        );
+    }
+}
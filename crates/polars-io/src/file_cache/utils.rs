@@ -5,7 +5,7 @@ use std::time::UNIX_EPOCH;
 use once_cell::sync::Lazy;
 use polars_error::{PolarsError, PolarsResult};
 
-use super::cache::{get_env_file_cache_ttl, FILE_CACHE};
+use super::cache::{get_env_file_cache_size_limit, get_env_file_cache_ttl, FILE_CACHE};
 use super::entry::FileCacheEntry;
 use super::file_fetcher::{CloudFileFetcher, LocalFileFetcher};
 use crate::cloud::{
@@ -59,6 +59,9 @@ pub fn init_entries_from_uri_list(
         return Ok(Default::default());
     }
 
+    // Surface an invalid size limit before the cache is started.
+    get_env_file_cache_size_limit()?;
+
     let first_uri = uri_list.first().unwrap().as_ref();
 
     let file_cache_ttl = cloud_options
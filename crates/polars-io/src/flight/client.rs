@@ -0,0 +1,63 @@
+use arrow::io::flight::deserialize_schemas;
+use arrow_format::flight::data::flight_descriptor::DescriptorType;
+use arrow_format::flight::data::{FlightDescriptor, Ticket};
+use arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use tonic::transport::Channel;
+
+use super::flight_data_to_dataframe;
+
+/// A client of an Arrow Flight service.
+#[derive(Clone, Debug)]
+pub struct FlightClient {
+    inner: FlightServiceClient<Channel>,
+}
+
+impl FlightClient {
+    /// Connect to the Flight service at `uri`, e.g. `http://localhost:8815`.
+    pub async fn connect(uri: &str) -> PolarsResult<Self> {
+        let inner = FlightServiceClient::connect(uri.to_string())
+            .await
+            .map_err(|err| {
+                polars_err!(ComputeError: "could not connect to flight service at '{}': {}", uri, err)
+            })?;
+        Ok(Self { inner })
+    }
+
+    /// Get the schema of the dataset of `ticket` with `GetSchema`.
+    pub async fn get_schema(&mut self, ticket: &[u8]) -> PolarsResult<SchemaRef> {
+        let descriptor = FlightDescriptor {
+            r#type: DescriptorType::Cmd as i32,
+            cmd: ticket.to_vec(),
+            path: vec![],
+        };
+        let result = self
+            .inner
+            .get_schema(descriptor)
+            .await
+            .map_err(to_compute_err)?
+            .into_inner();
+        let (arrow_schema, _) = deserialize_schemas(&result.schema)?;
+        Ok(Arc::new(Schema::from_arrow_schema(&arrow_schema)))
+    }
+
+    /// Read the dataset of `ticket` with `DoGet`.
+    pub async fn do_get(&mut self, ticket: &[u8]) -> PolarsResult<DataFrame> {
+        let ticket = Ticket {
+            ticket: ticket.to_vec(),
+        };
+        let mut stream = self
+            .inner
+            .do_get(ticket)
+            .await
+            .map_err(to_compute_err)?
+            .into_inner();
+
+        let mut messages = vec![];
+        while let Some(message) = stream.message().await.map_err(to_compute_err)? {
+            messages.push(message);
+        }
+        flight_data_to_dataframe(messages)
+    }
+}
@@ -0,0 +1,87 @@
+//! Reading from and serving [Arrow Flight](https://arrow.apache.org/docs/format/Flight.html)
+//! services.
+//!
+//! A dataset is identified by a ticket. The client reads a dataset with `DoGet` and its schema
+//! with `GetSchema`, with the ticket as the command of the descriptor. The server serves the
+//! datasets of a [`FlightSource`], where tickets are UTF-8 strings.
+mod client;
+mod server;
+
+use arrow::io::flight::{
+    default_ipc_fields, deserialize_message, deserialize_schemas, serialize_batch,
+    serialize_schema, WriteOptions,
+};
+use arrow::io::ipc::read::Dictionaries;
+use arrow_format::flight::data::FlightData;
+pub use client::FlightClient;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+pub use server::{FlightServer, FlightSource};
+
+/// Serialize a [`DataFrame`] to the messages of a `DoGet` stream: the schema, followed by the
+/// dictionaries and record batches of the chunks.
+pub(crate) fn dataframe_to_flight_data(
+    df: &DataFrame,
+    compat_level: CompatLevel,
+) -> PolarsResult<Vec<FlightData>> {
+    let schema = df.schema().to_arrow(compat_level);
+    let ipc_fields = default_ipc_fields(schema.iter_values());
+    let options = WriteOptions { compression: None };
+
+    let mut messages = vec![serialize_schema(&schema, Some(&ipc_fields))];
+    for chunk in df.iter_chunks(compat_level, true) {
+        let (dictionaries, batch) = serialize_batch(&chunk, &ipc_fields, &options)?;
+        messages.extend(dictionaries);
+        messages.push(batch);
+    }
+    Ok(messages)
+}
+
+/// Deserialize the messages of a `DoGet` stream to a [`DataFrame`].
+pub(crate) fn flight_data_to_dataframe(
+    messages: impl IntoIterator<Item = FlightData>,
+) -> PolarsResult<DataFrame> {
+    let mut messages = messages.into_iter();
+    let Some(schema_message) = messages.next() else {
+        polars_bail!(ComputeError: "flight stream did not contain a schema message");
+    };
+    let (arrow_schema, ipc_schema) = deserialize_schemas(&schema_message.data_header)?;
+
+    let mut dictionaries = Dictionaries::default();
+    let mut dfs = vec![];
+    for message in messages {
+        if let Some(batch) =
+            deserialize_message(&message, &arrow_schema, &ipc_schema, &mut dictionaries)?
+        {
+            dfs.push(DataFrame::try_from((batch, &arrow_schema))?);
+        }
+    }
+
+    if dfs.is_empty() {
+        let schema = Schema::from_arrow_schema(&arrow_schema);
+        return Ok(DataFrame::empty_with_schema(&schema));
+    }
+    Ok(accumulate_dataframes_vertical_unchecked(dfs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flight_data_roundtrip() {
+        let df = df![
+            "a" => [1i64, 2, 3],
+            "b" => [Some("x"), None, Some("z")],
+        ]
+        .unwrap();
+        for compat_level in [CompatLevel::oldest(), CompatLevel::newest()] {
+            let messages = dataframe_to_flight_data(&df, compat_level).unwrap();
+            assert_eq!(flight_data_to_dataframe(messages).unwrap(), df);
+        }
+
+        let empty = df.clear();
+        let messages = dataframe_to_flight_data(&empty, CompatLevel::newest()).unwrap();
+        assert_eq!(flight_data_to_dataframe(messages).unwrap(), empty);
+    }
+}
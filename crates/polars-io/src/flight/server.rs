@@ -0,0 +1,255 @@
+use std::net::SocketAddr;
+
+use arrow::io::flight::{serialize_schema_to_info, serialize_schema_to_result};
+use arrow_format::flight::data::flight_descriptor::DescriptorType;
+use arrow_format::flight::data::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
+};
+use arrow_format::flight::service::flight_service_server::{FlightService, FlightServiceServer};
+use futures::stream::{self, BoxStream};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tonic::{Request, Response, Status, Streaming};
+
+use super::dataframe_to_flight_data;
+use crate::pl_async::get_runtime;
+
+/// The datasets that are served by a [`FlightServer`].
+pub trait FlightSource: Send + Sync + 'static {
+    /// The tickets that are listed with `ListFlights`. Other tickets may be served as well.
+    fn tickets(&self) -> Vec<String>;
+
+    /// The schema of the dataset of `ticket`.
+    fn schema(&self, ticket: &str) -> PolarsResult<SchemaRef>;
+
+    /// Materialize the dataset of `ticket`.
+    fn collect(&self, ticket: &str) -> PolarsResult<DataFrame>;
+}
+
+/// An Arrow Flight service that serves the datasets of a [`FlightSource`] with `DoGet`,
+/// `GetSchema`, `GetFlightInfo` and `ListFlights`.
+pub struct FlightServer<S> {
+    source: Arc<S>,
+    compat_level: CompatLevel,
+}
+
+impl<S> Clone for FlightServer<S> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            compat_level: self.compat_level,
+        }
+    }
+}
+
+fn to_status(err: PolarsError) -> Status {
+    Status::internal(err.to_string())
+}
+
+fn parse_ticket(ticket: &[u8]) -> Result<String, Status> {
+    String::from_utf8(ticket.to_vec())
+        .map_err(|_| Status::invalid_argument("flight ticket is not valid UTF-8"))
+}
+
+/// The ticket of a descriptor, either its command or its single path element.
+fn descriptor_ticket(descriptor: &FlightDescriptor) -> Result<String, Status> {
+    match descriptor.path.as_slice() {
+        [] => parse_ticket(&descriptor.cmd),
+        [path] => Ok(path.clone()),
+        _ => Err(Status::invalid_argument(
+            "flight descriptor paths must have a single element",
+        )),
+    }
+}
+
+impl<S: FlightSource> FlightServer<S> {
+    pub fn new(source: S) -> Self {
+        Self {
+            source: Arc::new(source),
+            compat_level: CompatLevel::newest(),
+        }
+    }
+
+    /// Set the [`CompatLevel`] of the served data. Use [`CompatLevel::oldest`] for clients that
+    /// don't support string and binary views.
+    pub fn with_compat_level(mut self, compat_level: CompatLevel) -> Self {
+        self.compat_level = compat_level;
+        self
+    }
+
+    /// Serve on `addr` until the future is dropped.
+    pub async fn serve(self, addr: SocketAddr) -> PolarsResult<()> {
+        tonic::transport::Server::builder()
+            .add_service(FlightServiceServer::new(self))
+            .serve(addr)
+            .await
+            .map_err(to_compute_err)
+    }
+
+    /// Serve the connections of `listener` until the future is dropped.
+    pub async fn serve_with_listener(self, listener: TcpListener) -> PolarsResult<()> {
+        let incoming = stream::unfold(listener, |listener| async move {
+            let stream = listener.accept().await.map(|(stream, _)| stream);
+            Some((stream, listener))
+        });
+        tonic::transport::Server::builder()
+            .add_service(FlightServiceServer::new(self))
+            .serve_with_incoming(incoming)
+            .await
+            .map_err(to_compute_err)
+    }
+
+    /// Serve on `addr` in the background, on the async runtime of Polars. Returns the address
+    /// that is served on, with the port that was assigned if the port of `addr` is 0.
+    pub fn spawn(
+        self,
+        addr: SocketAddr,
+    ) -> PolarsResult<(SocketAddr, JoinHandle<PolarsResult<()>>)> {
+        let runtime = get_runtime();
+        let listener = runtime.block_on_potential_spawn(TcpListener::bind(addr))?;
+        let local_addr = listener.local_addr()?;
+        Ok((
+            local_addr,
+            runtime.spawn(self.serve_with_listener(listener)),
+        ))
+    }
+
+    /// Run a blocking function of the source on the blocking thread pool.
+    async fn with_source<F, T>(&self, f: F) -> Result<T, Status>
+    where
+        F: FnOnce(&S) -> PolarsResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let source = self.source.clone();
+        tokio::task::spawn_blocking(move || f(&source))
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .map_err(to_status)
+    }
+
+    async fn flight_info(&self, ticket: String) -> Result<FlightInfo, Status> {
+        let schema = {
+            let ticket = ticket.clone();
+            self.with_source(move |source| source.schema(&ticket))
+                .await?
+        };
+        let arrow_schema = schema.to_arrow(self.compat_level);
+        let schema = serialize_schema_to_info(&arrow_schema, None).map_err(to_status)?;
+        Ok(FlightInfo {
+            schema,
+            flight_descriptor: Some(FlightDescriptor {
+                r#type: DescriptorType::Cmd as i32,
+                cmd: ticket.clone().into_bytes(),
+                path: vec![],
+            }),
+            endpoint: vec![FlightEndpoint {
+                ticket: Some(Ticket {
+                    ticket: ticket.into_bytes(),
+                }),
+                location: vec![],
+            }],
+            total_records: -1,
+            total_bytes: -1,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl<S: FlightSource> FlightService for FlightServer<S> {
+    type HandshakeStream = BoxStream<'static, Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = BoxStream<'static, Result<FlightInfo, Status>>;
+    type DoGetStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, Result<PutResult, Status>>;
+    type DoExchangeStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoActionStream = BoxStream<'static, Result<arrow_format::flight::data::Result, Status>>;
+    type ListActionsStream = BoxStream<'static, Result<ActionType, Status>>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("handshake is not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        let mut infos = vec![];
+        for ticket in self.source.tickets() {
+            infos.push(self.flight_info(ticket).await);
+        }
+        Ok(Response::new(Box::pin(stream::iter(infos))))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let ticket = descriptor_ticket(request.get_ref())?;
+        Ok(Response::new(self.flight_info(ticket).await?))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        let ticket = descriptor_ticket(request.get_ref())?;
+        let schema = self
+            .with_source(move |source| source.schema(&ticket))
+            .await?;
+        let arrow_schema = schema.to_arrow(self.compat_level);
+        Ok(Response::new(serialize_schema_to_result(
+            &arrow_schema,
+            None,
+        )))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let ticket = parse_ticket(&request.get_ref().ticket)?;
+        let compat_level = self.compat_level;
+        let messages = self
+            .with_source(move |source| {
+                let df = source.collect(&ticket)?;
+                dataframe_to_flight_data(&df, compat_level)
+            })
+            .await?;
+        Ok(Response::new(Box::pin(stream::iter(
+            messages.into_iter().map(Ok),
+        ))))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("do_put is not supported"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("do_exchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("do_action is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Ok(Response::new(Box::pin(stream::empty())))
+    }
+}
@@ -0,0 +1,229 @@
+use std::fs::File;
+
+use polars_core::prelude::*;
+use polars_error::{polars_bail, PolarsResult};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::metadata::IcebergSchema;
+use crate::avro::AvroReader;
+use crate::SerReader;
+
+/// The content of a file of an Iceberg table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IcebergFileContent {
+    Data,
+    /// Deletes rows of data files by their position.
+    PositionDeletes,
+    /// Deletes rows of data files that are equal to a row of this file.
+    EqualityDeletes,
+}
+
+impl IcebergFileContent {
+    fn from_id(id: i32) -> PolarsResult<Self> {
+        Ok(match id {
+            0 => Self::Data,
+            1 => Self::PositionDeletes,
+            2 => Self::EqualityDeletes,
+            _ => polars_bail!(ComputeError: "unknown iceberg file content {}", id),
+        })
+    }
+}
+
+/// A data or delete file of an Iceberg table, as listed in a manifest.
+#[derive(Clone, Debug)]
+pub struct IcebergDataFile {
+    pub path: PlSmallStr,
+    pub content: IcebergFileContent,
+    pub record_count: u64,
+    pub sequence_number: i64,
+    /// The id of the schema the file was written with, if it is known.
+    pub schema_id: Option<i32>,
+    /// The snapshot that added the file.
+    pub(super) snapshot_id: Option<i64>,
+    pub(super) spec_id: i32,
+    pub(super) partition: Vec<AnyValue<'static>>,
+    /// Serialized lower and upper bounds, keyed by field id.
+    pub(super) lower_bounds: Vec<(i32, Vec<u8>)>,
+    pub(super) upper_bounds: Vec<(i32, Vec<u8>)>,
+    pub(super) null_counts: Vec<(i32, u64)>,
+    /// Names of the columns that rows are compared on, for equality delete files.
+    pub equality_columns: Vec<PlSmallStr>,
+}
+
+/// An entry of a manifest list.
+#[derive(Clone, Debug)]
+pub(super) struct ManifestFile {
+    pub(super) path: PlSmallStr,
+    spec_id: i32,
+    sequence_number: i64,
+    added_snapshot_id: Option<i64>,
+}
+
+fn opt_column<'a>(df: &'a DataFrame, name: &str) -> Option<&'a Series> {
+    df.column(name).ok().map(|c| c.as_materialized_series())
+}
+
+fn opt_field(data_file: &StructChunked, name: &str) -> Option<Series> {
+    data_file.field_by_name(name).ok()
+}
+
+pub(super) fn read_manifest_list(
+    file: File,
+    snapshot_sequence_number: i64,
+) -> PolarsResult<Vec<ManifestFile>> {
+    let df = AvroReader::new(file).finish()?;
+
+    let paths = df
+        .column("manifest_path")?
+        .as_materialized_series()
+        .str()?
+        .clone();
+    let spec_ids = df
+        .column("partition_spec_id")?
+        .as_materialized_series()
+        .i32()?
+        .clone();
+    let sequence_numbers = opt_column(&df, "sequence_number")
+        .map(|s| s.i64().cloned())
+        .transpose()?;
+    let added_snapshot_ids = opt_column(&df, "added_snapshot_id")
+        .map(|s| s.i64().cloned())
+        .transpose()?;
+
+    Ok((0..df.height())
+        .map(|i| ManifestFile {
+            path: paths.get(i).unwrap_or_default().into(),
+            spec_id: spec_ids.get(i).unwrap_or_default(),
+            // Manifests of v1 tables don't have sequence numbers.
+            sequence_number: sequence_numbers
+                .as_ref()
+                .and_then(|s| s.get(i))
+                .unwrap_or(snapshot_sequence_number),
+            added_snapshot_id: added_snapshot_ids.as_ref().and_then(|s| s.get(i)),
+        })
+        .collect())
+}
+
+/// Read a `list<struct<key, value>>` column, which is how Iceberg stores maps in Avro.
+fn read_map<K, V>(
+    column: Option<&Series>,
+    i: usize,
+    mut f: impl FnMut(AnyValue, AnyValue) -> Option<(K, V)>,
+) -> PolarsResult<Vec<(K, V)>> {
+    let Some(column) = column else {
+        return Ok(vec![]);
+    };
+    let Some(entries) = column.list()?.get_as_series(i) else {
+        return Ok(vec![]);
+    };
+    let entries = entries.struct_()?;
+    let keys = entries.field_by_name("key")?;
+    let values = entries.field_by_name("value")?;
+
+    Ok((0..entries.len())
+        .filter_map(|j| f(keys.get(j).ok()?, values.get(j).ok()?))
+        .collect())
+}
+
+fn read_bounds(column: Option<&Series>, i: usize) -> PolarsResult<Vec<(i32, Vec<u8>)>> {
+    read_map(column, i, |k, v| match (k, v) {
+        (AnyValue::Int32(k), AnyValue::Binary(v)) => Some((k, v.to_vec())),
+        (AnyValue::Int32(k), AnyValue::BinaryOwned(v)) => Some((k, v)),
+        _ => None,
+    })
+}
+
+/// Read the live files of a manifest. Deleted entries are skipped.
+pub(super) fn read_manifest(
+    file: File,
+    manifest: &ManifestFile,
+    schema: &IcebergSchema,
+) -> PolarsResult<Vec<IcebergDataFile>> {
+    const STATUS_DELETED: i32 = 2;
+
+    let df = AvroReader::new(file).finish()?;
+    let status = df.column("status")?.as_materialized_series().i32()?.clone();
+    let sequence_numbers = opt_column(&df, "sequence_number")
+        .map(|s| s.i64().cloned())
+        .transpose()?;
+    let snapshot_ids = opt_column(&df, "snapshot_id")
+        .map(|s| s.i64().cloned())
+        .transpose()?;
+
+    let data_file = df
+        .column("data_file")?
+        .as_materialized_series()
+        .struct_()?
+        .clone();
+    let content = opt_field(&data_file, "content")
+        .map(|s| s.i32().cloned())
+        .transpose()?;
+    let paths = data_file.field_by_name("file_path")?;
+    let paths = paths.str()?;
+    let record_counts = data_file.field_by_name("record_count")?;
+    let record_counts = record_counts.i64()?;
+    let partition = opt_field(&data_file, "partition")
+        .map(|s| s.struct_().map(|s| s.fields_as_series()))
+        .transpose()?
+        .unwrap_or_default();
+    let lower_bounds = opt_field(&data_file, "lower_bounds");
+    let upper_bounds = opt_field(&data_file, "upper_bounds");
+    let null_counts = opt_field(&data_file, "null_value_counts");
+    let equality_ids = opt_field(&data_file, "equality_ids");
+
+    let mut files = Vec::with_capacity(df.height());
+    for i in 0..df.height() {
+        if status.get(i) == Some(STATUS_DELETED) {
+            continue;
+        }
+        let content =
+            IcebergFileContent::from_id(content.as_ref().and_then(|c| c.get(i)).unwrap_or(0))?;
+
+        let equality_columns = match (&equality_ids, content) {
+            (Some(ids), IcebergFileContent::EqualityDeletes) => {
+                let ids = ids.list()?.get_as_series(i).unwrap_or_default();
+                ids.i32()?
+                    .into_iter()
+                    .flatten()
+                    .map(|id| match schema.field_by_id(id) {
+                        Some(field) => Ok(field.name.clone()),
+                        None => polars_bail!(
+                            ComputeError: "equality delete field {} is not a top-level column", id
+                        ),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?
+            },
+            _ => vec![],
+        };
+
+        files.push(IcebergDataFile {
+            path: paths.get(i).unwrap_or_default().into(),
+            content,
+            record_count: record_counts.get(i).unwrap_or_default() as u64,
+            // A missing sequence number is inherited from the manifest.
+            sequence_number: sequence_numbers
+                .as_ref()
+                .and_then(|s| s.get(i))
+                .unwrap_or(manifest.sequence_number),
+            // Resolved when the manifests of the snapshot are collected.
+            schema_id: None,
+            // Like the sequence number, a missing snapshot id is inherited from the manifest.
+            snapshot_id: snapshot_ids
+                .as_ref()
+                .and_then(|s| s.get(i))
+                .or(manifest.added_snapshot_id),
+            spec_id: manifest.spec_id,
+            partition: partition
+                .iter()
+                .map(|s| Ok(s.get(i)?.into_static()))
+                .collect::<PolarsResult<_>>()?,
+            lower_bounds: read_bounds(lower_bounds.as_ref(), i)?,
+            upper_bounds: read_bounds(upper_bounds.as_ref(), i)?,
+            null_counts: read_map(null_counts.as_ref(), i, |k, v| {
+                Some((k.extract::<i32>()?, v.extract::<u64>()?))
+            })?,
+            equality_columns,
+        });
+    }
+    Ok(files)
+}
@@ -0,0 +1,563 @@
+use std::io::Read;
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_core::POOL;
+use polars_error::{polars_bail, polars_err, to_compute_err, PolarsResult};
+use polars_utils::pl_str::PlSmallStr;
+use serde_json::Value;
+
+use super::manifest::{read_manifest, read_manifest_list, IcebergFileContent};
+use super::IcebergScanFiles;
+use crate::cloud::CloudOptions;
+use crate::path_utils::is_cloud_url;
+
+/// Selects the snapshot of an Iceberg table that is read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IcebergSnapshotSelector {
+    /// The current snapshot of the table.
+    #[default]
+    Current,
+    /// The snapshot with the given id.
+    Id(i64),
+    /// The snapshot that was current at the given time, in milliseconds since the epoch.
+    AsOfTimestamp(i64),
+}
+
+/// A snapshot of an Iceberg table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcebergSnapshot {
+    pub snapshot_id: i64,
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    pub manifest_list: PlSmallStr,
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Transform {
+    Identity,
+    Year,
+    Month,
+    Day,
+    Hour,
+    Truncate(i64),
+    /// `bucket`, `void` and unknown transforms, which can't be used for pruning.
+    Other,
+}
+
+impl Transform {
+    fn parse(transform: &str) -> Self {
+        match transform {
+            "identity" => Self::Identity,
+            "year" => Self::Year,
+            "month" => Self::Month,
+            "day" => Self::Day,
+            "hour" => Self::Hour,
+            _ => transform
+                .strip_prefix("truncate[")
+                .and_then(|w| w.strip_suffix(']'))
+                .and_then(|w| w.parse().ok())
+                .map_or(Self::Other, Self::Truncate),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct PartitionField {
+    pub(super) source_id: i32,
+    pub(super) transform: Transform,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct PartitionSpec {
+    pub(super) spec_id: i32,
+    pub(super) fields: Vec<PartitionField>,
+}
+
+impl PartitionSpec {
+    pub(super) fn is_unpartitioned(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+/// A top-level field of an Iceberg schema.
+#[derive(Clone, Debug)]
+pub(super) struct IcebergField {
+    pub(super) id: i32,
+    pub(super) name: PlSmallStr,
+    /// The Iceberg type, `None` for nested types.
+    pub(super) primitive: Option<PlSmallStr>,
+    pub(super) dtype: DataType,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct IcebergSchema {
+    pub(super) schema_id: i32,
+    pub(super) fields: Vec<IcebergField>,
+}
+
+impl IcebergSchema {
+    pub(super) fn field_by_id(&self, id: i32) -> Option<&IcebergField> {
+        self.fields.iter().find(|f| f.id == id)
+    }
+
+    fn to_schema(&self) -> Schema {
+        self.fields
+            .iter()
+            .map(|f| Field::new(f.name.clone(), f.dtype.clone()))
+            .collect()
+    }
+}
+
+/// The metadata of an Apache Iceberg table.
+#[derive(Clone, Debug)]
+pub struct IcebergTable {
+    metadata_location: PlSmallStr,
+    format_version: i64,
+    current_schema_id: i32,
+    schemas: Vec<IcebergSchema>,
+    specs: Vec<PartitionSpec>,
+    current_snapshot_id: Option<i64>,
+    snapshots: Vec<IcebergSnapshot>,
+    /// `(timestamp_ms, snapshot_id)` of the snapshots that were current, in order.
+    snapshot_log: Vec<(i64, i64)>,
+    cloud_options: Option<CloudOptions>,
+}
+
+impl IcebergTable {
+    /// Read the metadata of the table at `path`. This is either a `*.metadata.json` file, or the
+    /// root directory of the table, in which case the latest metadata file is used.
+    pub fn open(path: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        let metadata_location = resolve_metadata_location(path, cloud_options)?;
+        let mut json = String::new();
+        open_file(&metadata_location, cloud_options)?
+            .read_to_string(&mut json)
+            .map_err(to_compute_err)?;
+        let value: Value = serde_json::from_str(&json).map_err(to_compute_err)?;
+
+        Self::from_json(metadata_location.into(), &value, cloud_options)
+            .map_err(|e| e.wrap_msg(|msg| format!("invalid iceberg table metadata: {msg}")))
+    }
+
+    fn from_json(
+        metadata_location: PlSmallStr,
+        value: &Value,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let format_version = get_i64(value, "format-version")?;
+        polars_ensure!(
+            format_version == 1 || format_version == 2,
+            ComputeError: "unsupported format version {}", format_version
+        );
+
+        let schemas = match value.get("schemas") {
+            Some(Value::Array(schemas)) => schemas
+                .iter()
+                .map(parse_schema)
+                .collect::<PolarsResult<Vec<_>>>()?,
+            _ => vec![parse_schema(get(value, "schema")?)?],
+        };
+        let current_schema_id = match value.get("current-schema-id") {
+            Some(id) => as_i64(id, "current-schema-id")? as i32,
+            None => schemas[0].schema_id,
+        };
+
+        let specs = match value.get("partition-specs") {
+            Some(Value::Array(specs)) => specs
+                .iter()
+                .map(|spec| {
+                    Ok(PartitionSpec {
+                        spec_id: get_i64(spec, "spec-id")? as i32,
+                        fields: parse_partition_fields(get(spec, "fields")?)?,
+                    })
+                })
+                .collect::<PolarsResult<Vec<_>>>()?,
+            _ => vec![PartitionSpec {
+                spec_id: 0,
+                fields: match value.get("partition-spec") {
+                    Some(fields) => parse_partition_fields(fields)?,
+                    None => vec![],
+                },
+            }],
+        };
+
+        let current_snapshot_id = match value.get("current-snapshot-id") {
+            None | Some(Value::Null) => None,
+            Some(id) => Some(as_i64(id, "current-snapshot-id")?).filter(|id| *id != -1),
+        };
+
+        let snapshots = match value.get("snapshots") {
+            Some(Value::Array(snapshots)) => snapshots
+                .iter()
+                .map(|snapshot| {
+                    Ok(IcebergSnapshot {
+                        snapshot_id: get_i64(snapshot, "snapshot-id")?,
+                        sequence_number: match snapshot.get("sequence-number") {
+                            Some(n) => as_i64(n, "sequence-number")?,
+                            None => 0,
+                        },
+                        timestamp_ms: get_i64(snapshot, "timestamp-ms")?,
+                        manifest_list: get_str(snapshot, "manifest-list")
+                            .map_err(|_| {
+                                polars_err!(
+                                    ComputeError: "snapshots without a manifest list are not supported"
+                                )
+                            })?
+                            .into(),
+                        schema_id: snapshot
+                            .get("schema-id")
+                            .and_then(Value::as_i64)
+                            .map(|id| id as i32),
+                    })
+                })
+                .collect::<PolarsResult<Vec<_>>>()?,
+            _ => vec![],
+        };
+
+        let snapshot_log = match value.get("snapshot-log") {
+            Some(Value::Array(log)) => log
+                .iter()
+                .map(|entry| {
+                    Ok((
+                        get_i64(entry, "timestamp-ms")?,
+                        get_i64(entry, "snapshot-id")?,
+                    ))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?,
+            _ => vec![],
+        };
+
+        Ok(Self {
+            metadata_location,
+            format_version,
+            current_schema_id,
+            schemas,
+            specs,
+            current_snapshot_id,
+            snapshots,
+            snapshot_log,
+            cloud_options: cloud_options.cloned(),
+        })
+    }
+
+    /// The location of the metadata file this table was read from.
+    pub fn metadata_location(&self) -> &str {
+        &self.metadata_location
+    }
+
+    pub fn format_version(&self) -> i64 {
+        self.format_version
+    }
+
+    pub fn snapshots(&self) -> &[IcebergSnapshot] {
+        &self.snapshots
+    }
+
+    /// Get the selected snapshot. Returns `None` if the table has no snapshot, which is the case
+    /// for an empty table.
+    pub fn snapshot(
+        &self,
+        selector: IcebergSnapshotSelector,
+    ) -> PolarsResult<Option<&IcebergSnapshot>> {
+        let snapshot_id = match selector {
+            IcebergSnapshotSelector::Current => match self.current_snapshot_id {
+                Some(id) => id,
+                None => return Ok(None),
+            },
+            IcebergSnapshotSelector::Id(id) => id,
+            IcebergSnapshotSelector::AsOfTimestamp(timestamp_ms) => {
+                // The snapshot log records which snapshot was current when, which isn't
+                // necessarily the latest snapshot that was created before the timestamp.
+                let from_log = self
+                    .snapshot_log
+                    .iter()
+                    .filter(|(ts, _)| *ts <= timestamp_ms)
+                    .max_by_key(|(ts, _)| *ts)
+                    .map(|(_, id)| *id);
+                let from_snapshots = || {
+                    self.snapshots
+                        .iter()
+                        .filter(|s| s.timestamp_ms <= timestamp_ms)
+                        .max_by_key(|s| s.timestamp_ms)
+                        .map(|s| s.snapshot_id)
+                };
+
+                match from_log.or_else(from_snapshots) {
+                    Some(id) => id,
+                    None => polars_bail!(
+                        ComputeError: "iceberg table has no snapshot at or before timestamp {}", timestamp_ms
+                    ),
+                }
+            },
+        };
+
+        match self.snapshots.iter().find(|s| s.snapshot_id == snapshot_id) {
+            Some(snapshot) => Ok(Some(snapshot)),
+            None => polars_bail!(ComputeError: "iceberg snapshot {} not found", snapshot_id),
+        }
+    }
+
+    fn iceberg_schema(&self, snapshot: Option<&IcebergSnapshot>) -> PolarsResult<&IcebergSchema> {
+        let schema_id = snapshot
+            .and_then(|s| s.schema_id)
+            .unwrap_or(self.current_schema_id);
+        self.schemas
+            .iter()
+            .find(|s| s.schema_id == schema_id)
+            .ok_or_else(|| polars_err!(ComputeError: "iceberg schema {} not found", schema_id))
+    }
+
+    /// The schema of the table at the given snapshot, or the current schema.
+    pub fn schema(&self, snapshot: Option<&IcebergSnapshot>) -> PolarsResult<Schema> {
+        Ok(self.iceberg_schema(snapshot)?.to_schema())
+    }
+
+    /// Read the manifests of the snapshot and collect the live data and delete files.
+    pub fn scan_files(&self, snapshot: Option<&IcebergSnapshot>) -> PolarsResult<IcebergScanFiles> {
+        let schema = self.iceberg_schema(snapshot)?.clone();
+        let mut data_files = vec![];
+        let mut delete_files = vec![];
+
+        if let Some(snapshot) = snapshot {
+            let cloud_options = self.cloud_options.as_ref();
+            let manifests = read_manifest_list(
+                open_file(&snapshot.manifest_list, cloud_options)?,
+                snapshot.sequence_number,
+            )?;
+            let manifest_files = POOL.install(|| {
+                use rayon::prelude::*;
+                manifests
+                    .par_iter()
+                    .map(|manifest| {
+                        read_manifest(open_file(&manifest.path, cloud_options)?, manifest, &schema)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?;
+
+            for mut file in manifest_files.into_iter().flatten() {
+                // Files are written with the schema of the snapshot that added them.
+                file.schema_id = file
+                    .snapshot_id
+                    .and_then(|id| self.snapshots.iter().find(|s| s.snapshot_id == id))
+                    .and_then(|s| s.schema_id)
+                    .filter(|id| self.schemas.iter().any(|s| s.schema_id == *id));
+                match file.content {
+                    IcebergFileContent::Data => data_files.push(file),
+                    _ => delete_files.push(file),
+                }
+            }
+        }
+
+        Ok(IcebergScanFiles::new(
+            Arc::new(schema.to_schema()),
+            schema,
+            self.schemas.clone(),
+            self.specs.clone(),
+            data_files,
+            delete_files,
+        ))
+    }
+}
+
+/// Iceberg stores absolute locations, local ones can have a `file:` scheme.
+pub(super) fn normalize_location(location: &str) -> &str {
+    location
+        .strip_prefix("file://")
+        .or_else(|| location.strip_prefix("file:"))
+        .unwrap_or(location)
+}
+
+pub(super) fn open_file(
+    location: &str,
+    #[cfg_attr(not(feature = "cloud"), allow(unused_variables))] cloud_options: Option<
+        &CloudOptions,
+    >,
+) -> PolarsResult<std::fs::File> {
+    let location = normalize_location(location);
+    if is_cloud_url(location) {
+        #[cfg(feature = "cloud")]
+        {
+            use crate::file_cache::init_entries_from_uri_list;
+
+            // Metadata files and manifests are never modified once written.
+            let entry = init_entries_from_uri_list(&[location.into()], cloud_options)?;
+            return entry[0].try_open_assume_latest();
+        }
+        #[cfg(not(feature = "cloud"))]
+        polars_bail!(ComputeError: "the 'cloud' feature is required to read {}", location);
+    }
+    polars_utils::open_file(Path::new(location))
+}
+
+fn resolve_metadata_location(
+    path: &str,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<String> {
+    if path.ends_with(".metadata.json") {
+        return Ok(path.to_string());
+    }
+    let metadata_dir = format!("{}/metadata", path.trim_end_matches('/'));
+
+    // Tables maintained by a file system catalog point to the latest version with a hint.
+    let hint_location = format!("{metadata_dir}/version-hint.text");
+    let hint = if is_cloud_url(path) {
+        open_file(&hint_location, cloud_options).ok()
+    } else {
+        std::fs::File::open(normalize_location(&hint_location)).ok()
+    };
+    if let Some(mut hint) = hint {
+        let mut version = String::new();
+        hint.read_to_string(&mut version).map_err(to_compute_err)?;
+        return Ok(format!("{metadata_dir}/v{}.metadata.json", version.trim()));
+    }
+
+    polars_ensure!(
+        !is_cloud_url(path),
+        ComputeError: "iceberg table {} has no version hint, pass the location of a metadata file", path
+    );
+
+    // Metadata files are named `v<version>.metadata.json` or `<version>-<uuid>.metadata.json`.
+    let version_of = |name: &str| -> Option<u64> {
+        let name = name.strip_suffix(".metadata.json")?;
+        let name = name.strip_prefix('v').unwrap_or(name);
+        name.split('-').next()?.parse().ok()
+    };
+    let latest = std::fs::read_dir(normalize_location(&metadata_dir))
+        .map_err(|err| polars_err!(ComputeError: "could not read {}: {}", metadata_dir, err))?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            Some((version_of(&name)?, name))
+        })
+        .max();
+
+    match latest {
+        Some((_, name)) => Ok(format!("{metadata_dir}/{name}")),
+        None => polars_bail!(ComputeError: "no iceberg metadata files found in {}", metadata_dir),
+    }
+}
+
+fn get<'a>(value: &'a Value, key: &str) -> PolarsResult<&'a Value> {
+    value
+        .get(key)
+        .ok_or_else(|| polars_err!(ComputeError: "missing key '{}'", key))
+}
+
+fn as_i64(value: &Value, key: &str) -> PolarsResult<i64> {
+    value
+        .as_i64()
+        .ok_or_else(|| polars_err!(ComputeError: "'{}' must be an integer", key))
+}
+
+fn get_i64(value: &Value, key: &str) -> PolarsResult<i64> {
+    as_i64(get(value, key)?, key)
+}
+
+fn get_str<'a>(value: &'a Value, key: &str) -> PolarsResult<&'a str> {
+    get(value, key)?
+        .as_str()
+        .ok_or_else(|| polars_err!(ComputeError: "'{}' must be a string", key))
+}
+
+fn parse_partition_fields(fields: &Value) -> PolarsResult<Vec<PartitionField>> {
+    let Value::Array(fields) = fields else {
+        polars_bail!(ComputeError: "partition fields must be an array");
+    };
+    fields
+        .iter()
+        .map(|field| {
+            Ok(PartitionField {
+                source_id: get_i64(field, "source-id")? as i32,
+                transform: Transform::parse(get_str(field, "transform")?),
+            })
+        })
+        .collect()
+}
+
+fn parse_schema(schema: &Value) -> PolarsResult<IcebergSchema> {
+    let Value::Array(fields) = get(schema, "fields")? else {
+        polars_bail!(ComputeError: "schema fields must be an array");
+    };
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let ty = get(field, "type")?;
+            Ok(IcebergField {
+                id: get_i64(field, "id")? as i32,
+                name: get_str(field, "name")?.into(),
+                primitive: ty.as_str().map(PlSmallStr::from_str),
+                dtype: iceberg_type_to_dtype(ty)?,
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(IcebergSchema {
+        schema_id: match schema.get("schema-id") {
+            Some(id) => as_i64(id, "schema-id")? as i32,
+            None => 0,
+        },
+        fields,
+    })
+}
+
+fn iceberg_type_to_dtype(ty: &Value) -> PolarsResult<DataType> {
+    if let Some(ty) = ty.as_str() {
+        return Ok(match ty {
+            "boolean" => DataType::Boolean,
+            "int" => DataType::Int32,
+            "long" => DataType::Int64,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "date" => DataType::Date,
+            "time" => DataType::Time,
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, None),
+            "timestamptz" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            "timestamp_ns" => DataType::Datetime(TimeUnit::Nanoseconds, None),
+            "timestamptz_ns" => DataType::Datetime(TimeUnit::Nanoseconds, Some("UTC".into())),
+            "string" => DataType::String,
+            "uuid" | "binary" => DataType::Binary,
+            ty if ty.starts_with("fixed[") => DataType::Binary,
+            ty if ty.starts_with("decimal(") => {
+                let parse = || -> Option<(usize, usize)> {
+                    let (precision, scale) = ty
+                        .strip_prefix("decimal(")?
+                        .strip_suffix(')')?
+                        .split_once(',')?;
+                    Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+                };
+                let Some((precision, scale)) = parse() else {
+                    polars_bail!(ComputeError: "invalid iceberg type '{}'", ty);
+                };
+                DataType::Decimal(Some(precision), Some(scale))
+            },
+            ty => polars_bail!(ComputeError: "unsupported iceberg type '{}'", ty),
+        });
+    }
+
+    Ok(match get_str(ty, "type")? {
+        "struct" => {
+            let Value::Array(fields) = get(ty, "fields")? else {
+                polars_bail!(ComputeError: "struct fields must be an array");
+            };
+            DataType::Struct(
+                fields
+                    .iter()
+                    .map(|field| {
+                        Ok(Field::new(
+                            get_str(field, "name")?.into(),
+                            iceberg_type_to_dtype(get(field, "type")?)?,
+                        ))
+                    })
+                    .collect::<PolarsResult<_>>()?,
+            )
+        },
+        "list" => DataType::List(Box::new(iceberg_type_to_dtype(get(ty, "element")?)?)),
+        // Maps are stored as a list of key-value structs in Parquet.
+        "map" => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("key".into(), iceberg_type_to_dtype(get(ty, "key")?)?),
+            Field::new("value".into(), iceberg_type_to_dtype(get(ty, "value")?)?),
+        ]))),
+        ty => polars_bail!(ComputeError: "unsupported iceberg type '{}'", ty),
+    })
+}
@@ -0,0 +1,162 @@
+//! Reading of [Apache Iceberg](https://iceberg.apache.org) tables.
+//!
+//! The table metadata, manifest lists and manifests are read here. The data and delete files
+//! themselves are Parquet files that are read by the Parquet scan.
+mod manifest;
+mod metadata;
+mod stats;
+
+pub use manifest::{IcebergDataFile, IcebergFileContent};
+use metadata::{normalize_location, IcebergSchema, PartitionSpec};
+pub use metadata::{IcebergSnapshot, IcebergSnapshotSelector, IcebergTable};
+use polars_core::prelude::*;
+use polars_error::{polars_bail, PolarsResult};
+
+use crate::predicates::{BatchStats, PhysicalIoExpr};
+
+/// Field id of the `file_path` column of position delete files.
+const DELETE_FILE_PATH_FIELD_ID: i32 = 2147483546;
+
+/// The live data files of a snapshot of an Iceberg table, and the delete files that apply to
+/// them.
+#[derive(Clone, Debug)]
+pub struct IcebergScanFiles {
+    schema: SchemaRef,
+    iceberg_schema: IcebergSchema,
+    /// All schemas of the table, the data files may have been written with an older one.
+    schemas: Vec<IcebergSchema>,
+    specs: Vec<PartitionSpec>,
+    data_files: Vec<IcebergDataFile>,
+    delete_files: Vec<IcebergDataFile>,
+}
+
+impl IcebergScanFiles {
+    fn new(
+        schema: SchemaRef,
+        iceberg_schema: IcebergSchema,
+        schemas: Vec<IcebergSchema>,
+        specs: Vec<PartitionSpec>,
+        data_files: Vec<IcebergDataFile>,
+        delete_files: Vec<IcebergDataFile>,
+    ) -> Self {
+        Self {
+            schema,
+            iceberg_schema,
+            schemas,
+            specs,
+            data_files,
+            delete_files,
+        }
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    pub fn data_files(&self) -> &[IcebergDataFile] {
+        &self.data_files
+    }
+
+    /// The location of a data or delete file, as it can be passed to a reader.
+    pub fn location(file: &IcebergDataFile) -> &str {
+        normalize_location(&file.path)
+    }
+
+    /// The name of a column of the table in a data or delete file. Columns are matched by field
+    /// id, as they may have been renamed after the file was written. Returns `None` for columns
+    /// that were added after the file was written. If the schema of the file is unknown, the
+    /// name in the table is returned.
+    pub fn file_column_name<'a>(
+        &'a self,
+        file: &IcebergDataFile,
+        name: &'a PlSmallStr,
+    ) -> PolarsResult<Option<&'a PlSmallStr>> {
+        let Some(file_schema) = file
+            .schema_id
+            .and_then(|id| self.schemas.iter().find(|s| s.schema_id == id))
+        else {
+            return Ok(Some(name));
+        };
+        let Some(field) = self.iceberg_schema.fields.iter().find(|f| f.name == name) else {
+            polars_bail!(ColumnNotFound: "{}", name);
+        };
+        Ok(file_schema.field_by_id(field.id).map(|f| &f.name))
+    }
+
+    fn spec(&self, spec_id: i32) -> Option<&PartitionSpec> {
+        self.specs.iter().find(|spec| spec.spec_id == spec_id)
+    }
+
+    /// Statistics of a data file, from its column bounds and partition values.
+    pub fn statistics(&self, file: &IcebergDataFile) -> BatchStats {
+        stats::collect_file_statistics(
+            file,
+            &self.iceberg_schema,
+            self.spec(file.spec_id),
+            &self.schema,
+        )
+    }
+
+    /// Whether the data file may contain rows that match the predicate.
+    pub fn should_read(
+        &self,
+        file: &IcebergDataFile,
+        predicate: &dyn PhysicalIoExpr,
+    ) -> PolarsResult<bool> {
+        let Some(evaluator) = predicate.as_stats_evaluator() else {
+            return Ok(true);
+        };
+        match evaluator.should_read(&self.statistics(file)) {
+            // The predicate may refer to columns that are not in the table.
+            Err(PolarsError::ColumnNotFound(_)) => Ok(true),
+            result => result,
+        }
+    }
+
+    /// The position delete files that apply to the data file. These are only the files that
+    /// were committed at the same time or after the data file.
+    pub fn position_deletes<'a>(
+        &'a self,
+        file: &'a IcebergDataFile,
+    ) -> impl Iterator<Item = &'a IcebergDataFile> + 'a {
+        self.delete_files.iter().filter(move |delete| {
+            if delete.content != IcebergFileContent::PositionDeletes
+                || delete.sequence_number < file.sequence_number
+            {
+                return false;
+            }
+            // Delete files that only refer to a single data file have equal bounds on its path.
+            let path_bound = |bounds: &'a [(i32, Vec<u8>)]| {
+                bounds
+                    .iter()
+                    .find(|(id, _)| *id == DELETE_FILE_PATH_FIELD_ID)
+                    .map(|(_, path)| path.as_slice())
+            };
+            match (
+                path_bound(&delete.lower_bounds),
+                path_bound(&delete.upper_bounds),
+            ) {
+                (Some(lower), Some(upper)) => {
+                    lower <= file.path.as_bytes() && file.path.as_bytes() <= upper
+                },
+                _ => true,
+            }
+        })
+    }
+
+    /// The equality delete files that apply to the data file. These are the files that were
+    /// committed after the data file, and that are either global or in the same partition.
+    pub fn equality_deletes<'a>(
+        &'a self,
+        file: &'a IcebergDataFile,
+    ) -> impl Iterator<Item = &'a IcebergDataFile> + 'a {
+        self.delete_files.iter().filter(move |delete| {
+            delete.content == IcebergFileContent::EqualityDeletes
+                && delete.sequence_number > file.sequence_number
+                && (self
+                    .spec(delete.spec_id)
+                    .map_or(true, PartitionSpec::is_unpartitioned)
+                    || (delete.spec_id == file.spec_id && delete.partition == file.partition))
+        })
+    }
+}
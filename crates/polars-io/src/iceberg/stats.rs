@@ -0,0 +1,186 @@
+use polars_core::prelude::*;
+
+use super::manifest::IcebergDataFile;
+use super::metadata::{IcebergField, IcebergSchema, PartitionSpec, Transform};
+use crate::predicates::{BatchStats, ColumnStats};
+
+const MICROS_PER_HOUR: i64 = 3_600_000_000;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+
+/// Decode a lower or upper bound that is serialized in Iceberg's single-value binary format.
+/// Returns `None` for types that are not used for pruning.
+fn decode_bound(primitive: &str, bytes: &[u8]) -> Option<AnyValue<'static>> {
+    fn le<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+        bytes.try_into().ok()
+    }
+
+    Some(match primitive {
+        "boolean" => AnyValue::Boolean(*bytes.first()? != 0),
+        "int" | "date" => AnyValue::Int32(i32::from_le_bytes(le(bytes)?)),
+        "long" | "time" | "timestamp" | "timestamptz" | "timestamp_ns" | "timestamptz_ns" => {
+            AnyValue::Int64(i64::from_le_bytes(le(bytes)?))
+        },
+        "float" => AnyValue::Float32(f32::from_le_bytes(le(bytes)?)),
+        "double" => AnyValue::Float64(f64::from_le_bytes(le(bytes)?)),
+        // Truncated bounds of strings are still valid bounds.
+        "string" => AnyValue::StringOwned(std::str::from_utf8(bytes).ok()?.into()),
+        _ => return None,
+    })
+}
+
+/// Days since the epoch of the first day of the given month.
+fn days_from_civil(year: i64, month: i64) -> i64 {
+    // See http://howardhinnant.github.io/date_algorithms.html#days_from_civil.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The bounds, in microseconds since the epoch, of a time based partition value.
+fn transform_bounds_micros(transform: Transform, value: i64) -> Option<(i64, i64)> {
+    let month_start = |months: i64| {
+        days_from_civil(1970 + months.div_euclid(12), months.rem_euclid(12) + 1) * MICROS_PER_DAY
+    };
+    let (start, end) = match transform {
+        Transform::Year => (month_start(value * 12), month_start((value + 1) * 12)),
+        Transform::Month => (month_start(value), month_start(value + 1)),
+        Transform::Day => (value * MICROS_PER_DAY, (value + 1) * MICROS_PER_DAY),
+        Transform::Hour => (value * MICROS_PER_HOUR, (value + 1) * MICROS_PER_HOUR),
+        _ => return None,
+    };
+    Some((start, end - 1))
+}
+
+/// Derive bounds of the source column from a partition value.
+fn partition_bounds(
+    field: &IcebergField,
+    transform: Transform,
+    value: &AnyValue,
+) -> Option<(AnyValue<'static>, AnyValue<'static>)> {
+    let primitive = field.primitive.as_deref()?;
+    // Bounds are built in the physical type of the source column.
+    let value = match value {
+        AnyValue::Null | AnyValue::Time(_) => return None,
+        AnyValue::Date(days) => AnyValue::Int32(*days),
+        AnyValue::Datetime(v, _, _) => AnyValue::Int64(*v),
+        value => value.clone().into_static(),
+    };
+
+    match transform {
+        Transform::Identity => Some((value.clone(), value)),
+        Transform::Truncate(width) if matches!(primitive, "int" | "long") => {
+            let value = value.extract::<i64>()?;
+            Some((AnyValue::Int64(value), AnyValue::Int64(value + width - 1)))
+        },
+        Transform::Year | Transform::Month | Transform::Day | Transform::Hour => {
+            let (start, end) = transform_bounds_micros(transform, value.extract::<i64>()?)?;
+            let (start, end) = match primitive {
+                "date" => (
+                    start.div_euclid(MICROS_PER_DAY),
+                    end.div_euclid(MICROS_PER_DAY),
+                ),
+                "timestamp" | "timestamptz" => (start, end),
+                "timestamp_ns" | "timestamptz_ns" => (start * 1000, end * 1000 + 999),
+                _ => return None,
+            };
+            Some((AnyValue::Int64(start), AnyValue::Int64(end)))
+        },
+        _ => None,
+    }
+}
+
+fn bound_to_series(field: &IcebergField, value: AnyValue) -> Option<Series> {
+    let physical = field.dtype.to_physical();
+    let s =
+        Series::from_any_values_and_dtype(field.name.clone(), &[value], &physical, false).ok()?;
+    // The serialized `time` is in microseconds, polars uses nanoseconds.
+    let s = if field.primitive.as_deref() == Some("time") {
+        &s * 1000
+    } else {
+        s
+    };
+    s.cast(&field.dtype).ok()
+}
+
+/// Collect the statistics of a data file from its column bounds and partition values.
+pub(super) fn collect_file_statistics(
+    file: &IcebergDataFile,
+    schema: &IcebergSchema,
+    spec: Option<&PartitionSpec>,
+    polars_schema: &SchemaRef,
+) -> BatchStats {
+    let stats = schema
+        .fields
+        .iter()
+        .map(|field| {
+            let polars_field = Field::new(field.name.clone(), field.dtype.clone());
+            let Some(primitive) = field.primitive.as_deref() else {
+                return ColumnStats::from_field(polars_field);
+            };
+            let bound = |bounds: &[(i32, Vec<u8>)]| {
+                bounds
+                    .iter()
+                    .find(|(id, _)| *id == field.id)
+                    .and_then(|(_, bytes)| decode_bound(primitive, bytes))
+                    .and_then(|v| bound_to_series(field, v))
+            };
+            let mut min = bound(&file.lower_bounds);
+            let mut max = bound(&file.upper_bounds);
+
+            // Partition values bound columns that don't have statistics of their own.
+            if min.is_none() || max.is_none() {
+                let from_partition = spec.into_iter().flat_map(|spec| {
+                    spec.fields
+                        .iter()
+                        .zip(&file.partition)
+                        .filter(|(pf, _)| pf.source_id == field.id)
+                        .filter_map(|(pf, value)| partition_bounds(field, pf.transform, value))
+                });
+                if let Some((lower, upper)) = from_partition.into_iter().next() {
+                    min = min.or_else(|| bound_to_series(field, lower));
+                    max = max.or_else(|| bound_to_series(field, upper));
+                }
+            }
+
+            let null_count = file
+                .null_counts
+                .iter()
+                .find(|(id, _)| *id == field.id)
+                .map(|(_, n)| Series::new(field.name.clone(), [*n]));
+
+            ColumnStats::new(polars_field, null_count, min, max)
+        })
+        .collect();
+
+    BatchStats::new(
+        polars_schema.clone(),
+        stats,
+        Some(file.record_count as usize),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_bounds() {
+        assert_eq!(days_from_civil(1970, 1), 0);
+        assert_eq!(days_from_civil(2000, 3), 11017);
+        assert_eq!(days_from_civil(1969, 12), -31);
+
+        // Month 1 is February 1970.
+        assert_eq!(
+            transform_bounds_micros(Transform::Month, 1),
+            Some((31 * MICROS_PER_DAY, 59 * MICROS_PER_DAY - 1))
+        );
+        assert_eq!(
+            transform_bounds_micros(Transform::Year, -1),
+            Some((-365 * MICROS_PER_DAY, -1))
+        );
+    }
+}
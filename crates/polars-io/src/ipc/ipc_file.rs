@@ -42,6 +42,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics::should_read_batch;
 use crate::hive::materialize_hive_partitions;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
@@ -85,6 +86,7 @@ pub struct IpcReader<R: MmapBytesReader> {
     pub(super) memory_map: Option<PathBuf>,
     metadata: Option<read::FileMetadata>,
     schema: Option<ArrowSchemaRef>,
+    statistics_predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 
 fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
@@ -100,6 +102,41 @@ fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
     Err(err)
 }
 
+/// Remove the record batches from `metadata` that can't contain rows matching the predicate,
+/// according to the statistics written by [`IpcWriter::with_batch_statistics`].
+pub(super) fn prune_batches<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &mut read::FileMetadata,
+    predicate: &dyn PhysicalIoExpr,
+) -> PolarsResult<()> {
+    if predicate.as_stats_evaluator().is_none() {
+        return Ok(());
+    }
+    let schema = Arc::new(Schema::from_arrow_schema(&metadata.schema));
+    let mut scratch = vec![];
+    let mut keep = Vec::with_capacity(metadata.blocks.len());
+    for i in 0..metadata.blocks.len() {
+        let (num_rows, custom_metadata) =
+            read::read_batch_custom_metadata(reader, metadata, i, &mut scratch)?;
+        keep.push(should_read_batch(
+            &schema,
+            num_rows as usize,
+            &custom_metadata,
+            predicate,
+        )?);
+    }
+    if polars_core::config::verbose() {
+        eprintln!(
+            "ipc record batches skipped with statistics: {} of {}",
+            keep.iter().filter(|keep| !**keep).count(),
+            keep.len()
+        );
+    }
+    let mut keep = keep.into_iter();
+    metadata.blocks.retain(|_| keep.next().unwrap());
+    Ok(())
+}
+
 impl<R: MmapBytesReader> IpcReader<R> {
     fn get_metadata(&mut self) -> PolarsResult<&read::FileMetadata> {
         if self.metadata.is_none() {
@@ -153,6 +190,26 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Set the predicate that is used to skip record batches, with the statistics written by
+    /// [`IpcWriter::with_batch_statistics`]. The rows of the batches that are read are not
+    /// filtered. Batches are not skipped if a row index is added or the number of rows is limited.
+    pub fn with_statistics_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.statistics_predicate = predicate;
+        self
+    }
+
+    /// The predicate to skip record batches with, if batches can be skipped.
+    pub(super) fn batch_predicate(
+        &self,
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    ) -> Option<Arc<dyn PhysicalIoExpr>> {
+        // Skipped batches would change the row index and which rows are in the limit.
+        if self.row_index.is_some() || self.n_rows.is_some() {
+            return None;
+        }
+        predicate.or(self.statistics_predicate.as_ref()).cloned()
+    }
+
     /// Set if the file is to be memory_mapped. Only works with uncompressed files.
     /// The file name must be passed to register the memory mapped file.
     pub fn memory_mapped(mut self, path_buf: Option<PathBuf>) -> Self {
@@ -177,7 +234,10 @@ impl<R: MmapBytesReader> IpcReader<R> {
             }
         }
         let rechunk = self.rechunk;
-        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let mut metadata = read::read_file_metadata(&mut self.reader)?;
+        if let Some(predicate) = self.batch_predicate(predicate.as_ref()) {
+            prune_batches(&mut self.reader, &mut metadata, predicate.as_ref())?;
+        }
 
         // NOTE: For some code paths this already happened. See
         // https://github.com/pola-rs/polars/pull/14984#discussion_r1520125000
@@ -221,6 +281,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             memory_map: None,
             metadata: None,
             schema: None,
+            statistics_predicate: None,
         }
     }
 
@@ -278,7 +339,10 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
                 schema
             };
 
-            let metadata = self.get_metadata()?.clone();
+            let mut metadata = self.get_metadata()?.clone();
+            if let Some(predicate) = self.batch_predicate(None) {
+                prune_batches(&mut self.reader, &mut metadata, predicate.as_ref())?;
+            }
 
             let ipc_reader =
                 read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
@@ -12,7 +12,7 @@ use polars_utils::pl_str::PlSmallStr;
 use crate::cloud::{
     build_object_store, object_path_from_str, CloudLocation, CloudOptions, PolarsObjectStore,
 };
-use crate::file_cache::{init_entries_from_uri_list, FileCacheEntry};
+use crate::file_cache::{init_entries_from_uri_list, object_version, FileCacheEntry};
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::{materialize_projection, IpcReader};
 use crate::shared::SerReader;
@@ -84,18 +84,17 @@ impl IpcReaderAsync {
         self.store.head(&self.path).await
     }
 
-    async fn file_size(&self) -> PolarsResult<usize> {
-        Ok(self.object_metadata().await?.size)
-    }
-
     pub async fn metadata(&self) -> PolarsResult<FileMetadata> {
-        let file_size = self.file_size().await?;
+        let object_metadata = self.object_metadata().await?;
+        let file_size = object_metadata.size;
+        let version = object_version(&object_metadata);
 
         // TODO: Do a larger request and hope that the entire footer is contained within it to save one round-trip.
         let footer_metadata =
             self.store
-                .get_range(
+                .get_range_cached(
                     &self.path,
+                    Some(&version),
                     file_size.checked_sub(FOOTER_METADATA_SIZE).ok_or_else(|| {
                         to_compute_err("ipc file size is smaller than the minimum")
                     })?..file_size,
@@ -111,8 +110,9 @@ impl IpcReaderAsync {
 
         let footer = self
             .store
-            .get_range(
+            .get_range_cached(
                 &self.path,
+                Some(&version),
                 file_size
                     .checked_sub(FOOTER_METADATA_SIZE + footer_size)
                     .ok_or_else(|| {
@@ -4,6 +4,8 @@
 //! It used for sending an arbitrary length sequence of record batches.
 //! The format must be processed from start to end, and does not support random access.
 //! It is different than IPC, if you can't deserialize a file with `IpcReader::new`, it's probably an IPC Stream File.
+//! Streams that are written one after another into the same file are read as a single stream, if
+//! their schemas are equal.
 //!
 //! ## Example
 //!
@@ -33,17 +35,23 @@
 //! let df_read = IpcStreamReader::new(buf).finish().unwrap();
 //! assert!(df.equals(&df_read));
 //! ```
-use std::io::{Read, Write};
+use std::io::{BufRead, BufReader, Read, Write};
 use std::path::PathBuf;
 
 use arrow::io::ipc::read::{StreamMetadata, StreamState};
 use arrow::io::ipc::write::WriteOptions;
 use arrow::io::ipc::{read, write};
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::{finish_reader, ArrowReader};
 
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IpcStreamScanOptions;
+
 /// Read Arrows Stream IPC format into a DataFrame
 ///
 /// # Example
@@ -135,6 +143,47 @@ where
     }
 }
 
+/// Reads the record batches of streams that are concatenated in the same reader.
+///
+/// A new stream starts when the end of stream marker of the previous one is followed by more
+/// data. The dictionaries of a stream don't carry over to the next one.
+struct ConcatenatedStreamReader<R: BufRead> {
+    reader: Option<read::StreamReader<R>>,
+    schema: ArrowSchema,
+    projection: Option<Vec<usize>>,
+}
+
+impl<R: BufRead> ArrowReader for ConcatenatedStreamReader<R> {
+    fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>> {
+        loop {
+            let Some(reader) = self.reader.as_mut() else {
+                return Ok(None);
+            };
+            if let Some(batch) = reader.next_record_batch()? {
+                return Ok(Some(batch));
+            }
+            if !reader.is_finished() {
+                return Ok(None);
+            }
+
+            let mut reader = self.reader.take().unwrap().into_inner();
+            if reader.fill_buf()?.is_empty() {
+                return Ok(None);
+            }
+            let metadata = read::read_stream_metadata(&mut reader)?;
+            polars_ensure!(
+                metadata.schema == self.schema,
+                ComputeError: "concatenated IPC streams must have the same schema"
+            );
+            self.reader = Some(read::StreamReader::new(
+                reader,
+                metadata,
+                self.projection.clone(),
+            ));
+        }
+    }
+}
+
 impl<R> SerReader<R> for IpcStreamReader<R>
 where
     R: Read,
@@ -172,8 +221,15 @@ where
             metadata.schema.clone()
         };
 
-        let ipc_reader =
-            read::StreamReader::new(&mut self.reader, metadata.clone(), self.projection);
+        let ipc_reader = ConcatenatedStreamReader {
+            schema: metadata.schema.clone(),
+            reader: Some(read::StreamReader::new(
+                BufReader::new(&mut self.reader),
+                metadata,
+                self.projection.clone(),
+            )),
+            projection: self.projection,
+        };
         finish_reader(
             ipc_reader,
             rechunk,
@@ -5,7 +5,7 @@ use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
 use polars_utils::mmap::MMapSemaphore;
 
-use super::ipc_file::IpcReader;
+use super::ipc_file::{prune_batches, IpcReader};
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::shared::{finish_reader, ArrowReader};
@@ -19,8 +19,11 @@ impl<R: MmapBytesReader> IpcReader<R> {
         match self.reader.to_file() {
             Some(file) => {
                 let semaphore = MMapSemaphore::new_from_file(file)?;
-                let metadata =
-                    read::read_file_metadata(&mut std::io::Cursor::new(semaphore.as_ref()))?;
+                let mut cursor = std::io::Cursor::new(semaphore.as_ref());
+                let mut metadata = read::read_file_metadata(&mut cursor)?;
+                if let Some(predicate) = self.batch_predicate(predicate.as_ref()) {
+                    prune_batches(&mut cursor, &mut metadata, predicate.as_ref())?;
+                }
 
                 if let Some(columns) = &self.columns {
                     let schema = &metadata.schema;
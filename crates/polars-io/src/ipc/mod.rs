@@ -6,6 +6,7 @@ mod ipc_reader_async;
 mod ipc_stream;
 #[cfg(feature = "ipc")]
 mod mmap;
+mod statistics;
 mod write;
 #[cfg(all(feature = "async", feature = "ipc"))]
 mod write_async;
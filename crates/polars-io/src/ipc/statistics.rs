@@ -0,0 +1,160 @@
+//! Statistics of the record batches of an IPC file.
+//!
+//! The null count, minimum and maximum of the columns of a record batch are written to the
+//! custom metadata of the message of the batch, with the keys `polars:null_count:{i}`,
+//! `polars:min:{i}` and `polars:max:{i}`, where `i` is the index of the column in the schema of
+//! the file. Values are written as text, temporal values as their physical integers.
+use arrow::datatypes::{ArrowSchema, Metadata};
+use arrow::record_batch::RecordBatch;
+use polars_core::prelude::*;
+
+#[cfg(feature = "ipc")]
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+
+const NULL_COUNT_KEY: &str = "polars:null_count:";
+const MIN_KEY: &str = "polars:min:";
+const MAX_KEY: &str = "polars:max:";
+
+/// Longer strings are not written as bounds, to keep the messages small.
+const MAX_STRING_STATISTIC_LEN: usize = 64;
+
+fn has_bounds(dtype: &DataType) -> bool {
+    dtype.is_numeric()
+        || dtype.is_temporal()
+        || matches!(dtype, DataType::Boolean | DataType::String)
+}
+
+fn bound_to_string(value: AnyValue) -> Option<String> {
+    use AnyValue::*;
+    Some(match value {
+        Boolean(v) => v.to_string(),
+        String(v) if v.len() <= MAX_STRING_STATISTIC_LEN => v.to_string(),
+        StringOwned(v) if v.len() <= MAX_STRING_STATISTIC_LEN => v.to_string(),
+        UInt8(v) => v.to_string(),
+        UInt16(v) => v.to_string(),
+        UInt32(v) => v.to_string(),
+        UInt64(v) => v.to_string(),
+        Int8(v) => v.to_string(),
+        Int16(v) => v.to_string(),
+        Int32(v) => v.to_string(),
+        Int64(v) => v.to_string(),
+        Float32(v) if v.is_finite() => v.to_string(),
+        Float64(v) if v.is_finite() => v.to_string(),
+        _ => return None,
+    })
+}
+
+/// Compute the statistics of a record batch, as the custom metadata of its message.
+pub(super) fn batch_statistics_metadata(
+    batch: &RecordBatch,
+    schema: &ArrowSchema,
+) -> PolarsResult<Metadata> {
+    let mut metadata = Metadata::new();
+    for (i, (field, array)) in schema.iter_values().zip(batch.arrays()).enumerate() {
+        metadata.insert(
+            format!("{NULL_COUNT_KEY}{i}").into(),
+            array.null_count().to_string().into(),
+        );
+
+        let s = Series::try_from((field.name.clone(), array.clone()))?;
+        // NaN is larger than any other value, the bounds of the non-NaN values would be wrong.
+        if !has_bounds(s.dtype()) || (s.dtype().is_float() && s.is_nan()?.any()) {
+            continue;
+        }
+        let s = s.to_physical_repr();
+        let bounds = [(MIN_KEY, s.min_reduce()?), (MAX_KEY, s.max_reduce()?)];
+        for (key, bound) in bounds {
+            if let Some(value) = bound_to_string(bound.as_any_value()) {
+                metadata.insert(format!("{key}{i}").into(), value.into());
+            }
+        }
+    }
+    Ok(metadata)
+}
+
+#[cfg(feature = "ipc")]
+fn parse_bound(name: &PlSmallStr, dtype: &DataType, value: &str) -> Option<Series> {
+    let s = match dtype {
+        DataType::Boolean => Series::new(name.clone(), [value.parse::<bool>().ok()?]),
+        DataType::String => Series::new(name.clone(), [value]),
+        dtype if has_bounds(dtype) => Series::new(name.clone(), [value])
+            .strict_cast(&dtype.to_physical())
+            .ok()?,
+        _ => return None,
+    };
+    s.cast(dtype).ok()
+}
+
+/// Collect the statistics of a record batch from the custom metadata of its message.
+#[cfg(feature = "ipc")]
+fn collect_batch_statistics(
+    schema: &SchemaRef,
+    num_rows: usize,
+    metadata: &Metadata,
+) -> BatchStats {
+    let column_stats = schema
+        .iter_fields()
+        .enumerate()
+        .map(|(i, field)| {
+            let name = field.name();
+            let get = |key: &str| metadata.get(format!("{key}{i}").as_str());
+            let null_count = get(NULL_COUNT_KEY)
+                .and_then(|v| v.parse::<IdxSize>().ok())
+                .map(|n| Series::new(name.clone(), [n]));
+            let min = get(MIN_KEY).and_then(|v| parse_bound(name, field.dtype(), v));
+            let max = get(MAX_KEY).and_then(|v| parse_bound(name, field.dtype(), v));
+            ColumnStats::new(field, null_count, min, max)
+        })
+        .collect();
+    BatchStats::new(schema.clone(), column_stats, Some(num_rows))
+}
+
+/// Whether a record batch may contain rows that match the predicate, according to the
+/// statistics in the custom metadata of its message.
+#[cfg(feature = "ipc")]
+pub(super) fn should_read_batch(
+    schema: &SchemaRef,
+    num_rows: usize,
+    metadata: &Metadata,
+    predicate: &dyn PhysicalIoExpr,
+) -> PolarsResult<bool> {
+    let Some(evaluator) = predicate.as_stats_evaluator() else {
+        return Ok(true);
+    };
+    if !metadata.keys().any(|k| k.starts_with(NULL_COUNT_KEY)) {
+        return Ok(true);
+    }
+    match evaluator.should_read(&collect_batch_statistics(schema, num_rows, metadata)) {
+        // The predicate may refer to columns that are not in the file, e.g. hive columns.
+        Err(PolarsError::ColumnNotFound(_)) => Ok(true),
+        result => result,
+    }
+}
+
+#[cfg(all(test, feature = "ipc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_statistics_roundtrip() {
+        let df = df![
+            "a" => [Some(3i32), None, Some(-1)],
+            "b" => ["x", "zz", "a"],
+            "c" => [f64::NAN, 1.0, 2.0],
+        ]
+        .unwrap();
+        let schema = df.schema().to_arrow(CompatLevel::newest());
+        let batch = df.iter_chunks(CompatLevel::newest(), true).next().unwrap();
+
+        let metadata = batch_statistics_metadata(&batch, &schema).unwrap();
+        assert_eq!(metadata.get("polars:null_count:0").unwrap(), "1");
+        assert_eq!(metadata.get("polars:min:0").unwrap(), "-1");
+        assert_eq!(metadata.get("polars:max:1").unwrap(), "zz");
+        assert!(!metadata.contains_key("polars:min:2"));
+
+        let stats = collect_batch_statistics(&Arc::new(df.schema()), 3, &metadata);
+        let a = stats.get_stats("a").unwrap();
+        assert_eq!(a.to_min().unwrap().i32().unwrap().get(0), Some(-1));
+        assert_eq!(a.to_max().unwrap().i32().unwrap().get(0), Some(3));
+    }
+}
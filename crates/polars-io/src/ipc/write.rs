@@ -1,11 +1,13 @@
 use std::io::Write;
 
+use arrow::datatypes::ArrowSchemaRef;
 use arrow::io::ipc::write;
 use arrow::io::ipc::write::WriteOptions;
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics::batch_statistics_metadata;
 use crate::prelude::*;
 use crate::shared::schema_to_arrow_checked;
 
@@ -16,11 +18,15 @@ pub struct IpcWriterOptions {
     pub compression: Option<IpcCompression>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Write the statistics of the record batches, so that scans can skip batches.
+    pub batch_statistics: bool,
 }
 
 impl IpcWriterOptions {
     pub fn to_writer<W: Write>(&self, writer: W) -> IpcWriter<W> {
-        IpcWriter::new(writer).with_compression(self.compression)
+        IpcWriter::new(writer)
+            .with_compression(self.compression)
+            .with_batch_statistics(self.batch_statistics)
     }
 }
 
@@ -48,6 +54,7 @@ pub struct IpcWriter<W> {
     pub(super) compression: Option<IpcCompression>,
     /// Polars' flavor of arrow. This might be temporary.
     pub(super) compat_level: CompatLevel,
+    pub(super) batch_statistics: bool,
 }
 
 impl<W: Write> IpcWriter<W> {
@@ -62,11 +69,19 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Write the null count, minimum and maximum of the columns of every record batch to the
+    /// custom metadata of its message. Scans with a predicate use them to skip record batches.
+    /// Defaults to false.
+    pub fn with_batch_statistics(mut self, batch_statistics: bool) -> Self {
+        self.batch_statistics = batch_statistics;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
+        let schema = Arc::new(schema_to_arrow_checked(schema, self.compat_level, "ipc")?);
         let mut writer = write::FileWriter::new(
             self.writer,
-            Arc::new(schema),
+            schema.clone(),
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
@@ -77,6 +92,7 @@ impl<W: Write> IpcWriter<W> {
         Ok(BatchedWriter {
             writer,
             compat_level: self.compat_level,
+            statistics_schema: self.batch_statistics.then_some(schema),
         })
     }
 }
@@ -90,14 +106,19 @@ where
             writer,
             compression: None,
             compat_level: CompatLevel::newest(),
+            batch_statistics: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(&df.schema(), self.compat_level, "ipc")?;
+        let schema = Arc::new(schema_to_arrow_checked(
+            &df.schema(),
+            self.compat_level,
+            "ipc",
+        )?);
         let mut ipc_writer = write::FileWriter::try_new(
             &mut self.writer,
-            Arc::new(schema),
+            schema.clone(),
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
@@ -107,7 +128,11 @@ where
         let iter = df.iter_chunks(self.compat_level, true);
 
         for batch in iter {
-            ipc_writer.write(&batch, None)?
+            let custom_metadata = self
+                .batch_statistics
+                .then(|| batch_statistics_metadata(&batch, &schema))
+                .transpose()?;
+            ipc_writer.write_with_custom_metadata(&batch, None, custom_metadata.as_ref())?
         }
         ipc_writer.finish()?;
         Ok(())
@@ -117,6 +142,8 @@ where
 pub struct BatchedWriter<W: Write> {
     writer: write::FileWriter<W>,
     compat_level: CompatLevel,
+    /// The schema of the file, if batch statistics are written.
+    statistics_schema: Option<ArrowSchemaRef>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -127,7 +154,13 @@ impl<W: Write> BatchedWriter<W> {
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         let iter = df.iter_chunks(self.compat_level, true);
         for batch in iter {
-            self.writer.write(&batch, None)?
+            let custom_metadata = self
+                .statistics_schema
+                .as_ref()
+                .map(|schema| batch_statistics_metadata(&batch, schema))
+                .transpose()?;
+            self.writer
+                .write_with_custom_metadata(&batch, None, custom_metadata.as_ref())?
         }
         Ok(())
     }
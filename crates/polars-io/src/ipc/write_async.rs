@@ -11,6 +11,7 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
             writer,
             compression: None,
             compat_level: CompatLevel::oldest(),
+            batch_statistics: false,
         }
     }
 
@@ -79,12 +79,15 @@ use simd_json::BorrowedValue;
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
+use crate::utils::compression::{MaybeCompressedWriter, TextCompression};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JsonWriterOptions {
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// compression of the output
+    pub compression: Option<TextCompression>,
 }
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array)
@@ -120,6 +123,7 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    compression: Option<TextCompression>,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -127,6 +131,12 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Set the compression of the output. Defaults to `None`.
+    pub fn with_compression(mut self, compression: Option<TextCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -139,6 +149,7 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            compression: None,
         }
     }
 
@@ -156,25 +167,25 @@ where
             .iter_chunks(CompatLevel::newest(), false)
             .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
 
+        let mut buffer = MaybeCompressedWriter::new(&mut self.buffer, self.compression)?;
         match self.json_format {
             JsonFormat::JsonLines => {
                 let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
-                let writer =
-                    polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
+                let writer = polars_json::ndjson::write::FileWriter::new(&mut buffer, serializer);
                 writer.collect::<PolarsResult<()>>()?;
             },
             JsonFormat::Json => {
                 let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
+                polars_json::json::write::write(&mut buffer, serializer)?;
             },
         }
 
-        Ok(())
+        buffer.finish()
     }
 }
 
 pub struct BatchedWriter<W: Write> {
-    writer: W,
+    writer: MaybeCompressedWriter<W>,
 }
 
 impl<W> BatchedWriter<W>
@@ -182,7 +193,15 @@ where
     W: Write,
 {
     pub fn new(writer: W) -> Self {
-        BatchedWriter { writer }
+        BatchedWriter {
+            writer: MaybeCompressedWriter::Uncompressed(writer),
+        }
+    }
+
+    pub fn with_compression(writer: W, compression: Option<TextCompression>) -> PolarsResult<Self> {
+        Ok(BatchedWriter {
+            writer: MaybeCompressedWriter::new(writer, compression)?,
+        })
     }
     /// Write a batch to the json writer.
     ///
@@ -206,6 +225,11 @@ where
         }
         Ok(())
     }
+
+    /// Finishes the (compressed) output.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.finish()
+    }
 }
 
 /// Reads JSON in one of the formats in [`JsonFormat`] into a DataFrame.
@@ -356,6 +380,8 @@ where
                     false,
                     self.infer_schema_len,
                     self.ignore_errors,
+                    false,
+                    None,
                     None,
                     None,
                     None,
@@ -34,6 +34,8 @@ where
     path: Option<PathBuf>,
     low_memory: bool,
     ignore_errors: bool,
+    strict: bool,
+    required_fields: Option<Arc<[PlSmallStr]>>,
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
@@ -111,6 +113,23 @@ where
         self
     }
 
+    /// Validate every row against the schema and raise an error that lists all unknown
+    /// fields, missing required fields (see [`Self::with_required_fields`]) and type mismatches
+    /// with their line numbers, instead of ignoring unknown fields and turning mismatching values
+    /// into nulls.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Fields of the schema that every row must have with a non-null value. In strict mode
+    /// (see [`Self::with_strict`]) rows where they are missing or `null` are reported, by
+    /// default every field is optional.
+    pub fn with_required_fields(mut self, required_fields: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.required_fields = required_fields;
+        self
+    }
+
     pub fn count(mut self) -> PolarsResult<usize> {
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
         let json_reader = CoreJsonReader::new(
@@ -124,6 +143,8 @@ where
             self.low_memory,
             self.infer_schema_len,
             self.ignore_errors,
+            self.strict,
+            self.required_fields,
             self.row_index,
             self.predicate,
             self.projection,
@@ -159,6 +180,8 @@ where
             chunk_size: NonZeroUsize::new(1 << 18).unwrap(),
             low_memory: false,
             ignore_errors: false,
+            strict: false,
+            required_fields: None,
             row_index: None,
             predicate: None,
             projection: None,
@@ -178,6 +201,8 @@ where
             self.low_memory,
             self.infer_schema_len,
             self.ignore_errors,
+            self.strict,
+            self.required_fields,
             self.row_index,
             self.predicate,
             self.projection,
@@ -200,6 +225,8 @@ pub(crate) struct CoreJsonReader<'a> {
     chunk_size: NonZeroUsize,
     low_memory: bool,
     ignore_errors: bool,
+    strict: bool,
+    required_fields: Option<Arc<[PlSmallStr]>>,
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
@@ -217,6 +244,8 @@ impl<'a> CoreJsonReader<'a> {
         low_memory: bool,
         infer_schema_len: Option<NonZeroUsize>,
         ignore_errors: bool,
+        strict: bool,
+        required_fields: Option<Arc<[PlSmallStr]>>,
         row_index: Option<&'a mut RowIndex>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         projection: Option<Arc<[PlSmallStr]>>,
@@ -235,6 +264,12 @@ impl<'a> CoreJsonReader<'a> {
             let schema = Arc::make_mut(&mut schema);
             overwrite_schema(schema, overwriting_schema)?;
         }
+        for name in required_fields.iter().flat_map(|fields| fields.iter()) {
+            polars_ensure!(
+                schema.contains(name),
+                ColumnNotFound: "required field '{}' is not in the schema", name
+            );
+        }
 
         Ok(CoreJsonReader {
             reader_bytes: Some(reader_bytes),
@@ -245,6 +280,8 @@ impl<'a> CoreJsonReader<'a> {
             chunk_size,
             low_memory,
             ignore_errors,
+            strict,
+            required_fields,
             row_index,
             predicate,
             projection,
@@ -299,13 +336,54 @@ impl<'a> CoreJsonReader<'a> {
         };
         let file_chunks = get_file_chunks_json(bytes, n_threads);
 
+        // In strict mode every row is validated, which needs the line number at which each
+        // chunk starts.
+        let validation = self.strict.then(|| {
+            let is_required = |name: &PlSmallStr| {
+                self.required_fields
+                    .as_ref()
+                    .is_some_and(|fields| fields.contains(name))
+            };
+            let dtype = ArrowDataType::Struct(
+                self.schema
+                    .iter_fields()
+                    .map(|fld| {
+                        let mut fld = fld.to_arrow(CompatLevel::newest());
+                        fld.is_nullable = !is_required(&fld.name);
+                        fld
+                    })
+                    .collect(),
+            );
+            let mut first_line = 1;
+            let first_lines = file_chunks
+                .iter()
+                .map(|(start_pos, stop_at_nbytes)| {
+                    let line = first_line;
+                    first_line +=
+                        memchr::memchr_iter(NEWLINE, &bytes[*start_pos..*stop_at_nbytes]).count();
+                    line
+                })
+                .collect::<Vec<_>>();
+            (dtype, first_lines)
+        });
+
         let row_index = self.row_index.as_ref().map(|ri| ri as &RowIndex);
-        let (mut dfs, prepredicate_heights) = POOL.install(|| {
+        let (mut dfs, prepredicate_heights, violations) = POOL.install(|| {
             file_chunks
                 .into_par_iter()
-                .map(|(start_pos, stop_at_nbytes)| {
+                .enumerate()
+                .map(|(i, (start_pos, stop_at_nbytes))| {
+                    let bytes = &bytes[start_pos..stop_at_nbytes];
                     let mut buffers = init_buffers(&self.schema, capacity, self.ignore_errors)?;
-                    parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers)?;
+                    let violations = match &validation {
+                        Some((dtype, first_lines)) => {
+                            parse_lines_strict(bytes, &mut buffers, dtype, first_lines[i])?
+                        },
+                        None => {
+                            parse_lines(bytes, &mut buffers)?;
+                            vec![]
+                        },
+                    };
                     let mut local_df = DataFrame::new(
                         buffers
                             .into_values()
@@ -329,10 +407,22 @@ impl<'a> CoreJsonReader<'a> {
                         local_df = local_df.filter(mask)?;
                     }
 
-                    Ok((local_df, prepredicate_height))
+                    Ok((local_df, prepredicate_height, violations))
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+                .map(|results| {
+                    let mut dfs = Vec::with_capacity(results.len());
+                    let mut heights = Vec::with_capacity(results.len());
+                    let mut violations = vec![];
+                    for (df, height, chunk_violations) in results {
+                        dfs.push(df);
+                        heights.push(height);
+                        violations.extend(chunk_violations);
+                    }
+                    (dfs, heights, violations)
                 })
-                .collect::<PolarsResult<(Vec<_>, Vec<_>)>>()
         })?;
+        polars_json::ndjson::deserialize::check_violations(&violations)?;
 
         if let Some(ref mut row_index) = self.row_index {
             update_row_counts3(&mut dfs, &prepredicate_heights, 0);
@@ -371,10 +461,19 @@ fn parse_impl(
     let n = scratch.json.len();
     let value = simd_json::to_borrowed_value_with_buffers(&mut scratch.json, &mut scratch.buffers)
         .map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
+    add_value(&value, buffers)?;
+    Ok(n)
+}
+
+#[inline(always)]
+fn add_value(
+    value: &simd_json::BorrowedValue,
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+) -> PolarsResult<()> {
     match value {
         simd_json::BorrowedValue::Object(value) => {
             buffers.iter_mut().try_for_each(|(s, inner)| {
-                match s.0.map_lookup(&value) {
+                match s.0.map_lookup(value) {
                     Some(v) => inner.add(v)?,
                     None => inner.add_null(),
                 }
@@ -385,7 +484,7 @@ fn parse_impl(
             buffers.iter_mut().for_each(|(_, inner)| inner.add_null());
         },
     };
-    Ok(n)
+    Ok(())
 }
 
 #[derive(Default)]
@@ -417,6 +516,37 @@ fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> Pol
     Ok(())
 }
 
+/// Parses the lines like [`parse_lines`] and validates every row against `dtype`.
+///
+/// `first_line` is the 1-based line number of the first line in `bytes`.
+fn parse_lines_strict(
+    bytes: &[u8],
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    dtype: &ArrowDataType,
+    first_line: usize,
+) -> PolarsResult<Vec<polars_json::ndjson::deserialize::SchemaViolation>> {
+    let mut scratch = Scratch::default();
+    let mut violations = vec![];
+
+    for (i, bytes) in bytes.split(|&byte| byte == NEWLINE).enumerate() {
+        if bytes
+            .iter()
+            .all(|&byte| matches!(byte, b' ' | b'\t' | b'\r'))
+        {
+            continue;
+        }
+        let line = first_line + i;
+        scratch.json.clear();
+        scratch.json.extend_from_slice(bytes);
+        let value =
+            simd_json::to_borrowed_value_with_buffers(&mut scratch.json, &mut scratch.buffers)
+                .map_err(|e| polars_err!(ComputeError: "error parsing line {}: {}", line, e))?;
+        polars_json::ndjson::deserialize::validate_value(&value, dtype, line, &mut violations);
+        add_value(&value, buffers)?;
+    }
+    Ok(violations)
+}
+
 /// Find the nearest next line position.
 /// Does not check for new line characters embedded in String fields.
 /// This just looks for `}\n`
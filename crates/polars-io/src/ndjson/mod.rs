@@ -10,11 +10,31 @@ pub fn infer_schema<R: std::io::BufRead>(
     reader: &mut R,
     infer_schema_len: Option<NonZeroUsize>,
 ) -> PolarsResult<Schema> {
+    let dtype = infer_row_dtype(reader, infer_schema_len)?;
+    Ok(row_dtype_to_schema(&dtype))
+}
+
+/// Infer the supertype of the rows in `reader`, optionally only using `infer_schema_len` rows.
+///
+/// The row types of multiple files can be merged with [`merge_row_dtypes`] before they are
+/// converted to a schema with [`row_dtype_to_schema`].
+pub fn infer_row_dtype<R: std::io::BufRead>(
+    reader: &mut R,
+    infer_schema_len: Option<NonZeroUsize>,
+) -> PolarsResult<DataType> {
     let dtypes = polars_json::ndjson::iter_unique_dtypes(reader, infer_schema_len)?;
-    let dtype = crate::json::infer::dtypes_to_supertype(dtypes.map(|dt| DataType::from(&dt)))?;
-    let schema = StructArray::get_fields(&dtype.to_arrow(CompatLevel::newest()))
+    crate::json::infer::dtypes_to_supertype(dtypes.map(|dt| DataType::from(&dt)))
+}
+
+/// Merge two row types into their supertype. Struct fields that only exist in one of them
+/// are added to the result.
+pub fn merge_row_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType> {
+    polars_core::utils::try_get_supertype(left, right)
+}
+
+pub fn row_dtype_to_schema(dtype: &DataType) -> Schema {
+    StructArray::get_fields(&dtype.to_arrow(CompatLevel::newest()))
         .iter()
         .map(Into::<Field>::into)
-        .collect();
-    Ok(schema)
+        .collect()
 }
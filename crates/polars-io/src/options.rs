@@ -1,9 +1,13 @@
+use std::path::PathBuf;
+
 use polars_core::schema::SchemaRef;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::IdxSize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::path_utils::ListedFile;
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RowIndex {
@@ -34,3 +38,102 @@ impl Default for HiveOptions {
         }
     }
 }
+
+/// Filters on the files that are found when paths are expanded, e.g. for incremental ingestion.
+///
+/// Modification times are in milliseconds since the Unix epoch. Filters on the modification time
+/// or size need the metadata of the files, which is taken from the listing of directories and
+/// globs, or requested for every file otherwise.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FileFilterOptions {
+    /// Glob patterns of files that are not read. They are matched against the whole path with
+    /// the same semantics as the glob of the scan: `*` does not match `/`, and `**` matches any
+    /// number of directories, e.g. `**/*.crc` or `**/_temporary/**`.
+    pub exclude: Vec<PlSmallStr>,
+    /// Only read files that were modified at or after this time.
+    pub modified_after: Option<i64>,
+    /// Only read files that were modified before this time.
+    pub modified_before: Option<i64>,
+    /// Only read files of at least this number of bytes.
+    pub min_size: Option<u64>,
+    /// Only read files of at most this number of bytes.
+    pub max_size: Option<u64>,
+    /// Only read files that are newer than the checkpoint.
+    pub checkpoint: Option<FileCheckpoint>,
+}
+
+impl FileFilterOptions {
+    pub fn is_empty(&self) -> bool {
+        self.exclude.is_empty() && !self.needs_metadata()
+    }
+
+    /// Whether the filter needs the modification time or size of the files.
+    pub fn needs_metadata(&self) -> bool {
+        self.modified_after.is_some()
+            || self.modified_before.is_some()
+            || self.min_size.is_some()
+            || self.max_size.is_some()
+            || self.checkpoint.is_some()
+    }
+
+    /// Whether a file passes the filters on the modification time and size.
+    pub fn matches_metadata(&self, file: &ListedFile) -> bool {
+        self.modified_after
+            .map_or(true, |t| file.last_modified >= t)
+            && self
+                .modified_before
+                .map_or(true, |t| file.last_modified < t)
+            && self.min_size.map_or(true, |n| file.size >= n)
+            && self.max_size.map_or(true, |n| file.size <= n)
+            && self.checkpoint.as_ref().map_or(true, |c| c.is_new(file))
+    }
+}
+
+/// The files that were read by an incremental ingestion job.
+///
+/// Files are new if they were modified after the last modification time of the read files. Files
+/// with the same modification time are new if they were not read, as modification times are not
+/// unique. The default checkpoint considers all files as new.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FileCheckpoint {
+    /// The last modification time of the read files, in milliseconds since the Unix epoch.
+    pub last_modified: i64,
+    /// The read files that were modified at `last_modified`.
+    pub paths: Vec<PathBuf>,
+}
+
+impl Default for FileCheckpoint {
+    fn default() -> Self {
+        Self {
+            last_modified: i64::MIN,
+            paths: vec![],
+        }
+    }
+}
+
+impl FileCheckpoint {
+    /// Whether a file was not read before this checkpoint.
+    pub fn is_new(&self, file: &ListedFile) -> bool {
+        file.last_modified > self.last_modified
+            || (file.last_modified == self.last_modified && !self.paths.contains(&file.path))
+    }
+
+    /// The checkpoint after `files` are read.
+    pub fn advance(&self, files: &[ListedFile]) -> Self {
+        let mut checkpoint = self.clone();
+        for file in files {
+            if file.last_modified > checkpoint.last_modified {
+                checkpoint.last_modified = file.last_modified;
+                checkpoint.paths.clear();
+            }
+            if file.last_modified == checkpoint.last_modified
+                && !checkpoint.paths.contains(&file.path)
+            {
+                checkpoint.paths.push(file.path.clone());
+            }
+        }
+        checkpoint
+    }
+}
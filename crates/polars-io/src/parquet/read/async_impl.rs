@@ -17,6 +17,7 @@ use super::predicates::read_this_row_group;
 use crate::cloud::{
     build_object_store, object_path_from_str, CloudLocation, CloudOptions, PolarsObjectStore,
 };
+use crate::file_cache::{object_version, BLOCK_CACHE};
 use crate::parquet::metadata::FileMetadataRef;
 use crate::pl_async::get_runtime;
 use crate::predicates::PhysicalIoExpr;
@@ -29,6 +30,8 @@ pub struct ParquetObjectStore {
     store: PolarsObjectStore,
     path: ObjectPath,
     length: Option<usize>,
+    /// Version of the object for the block cache, only fetched if the block cache is enabled.
+    version: Option<PlSmallStr>,
     metadata: Option<FileMetadataRef>,
 }
 
@@ -40,23 +43,37 @@ impl ParquetObjectStore {
     ) -> PolarsResult<Self> {
         let (CloudLocation { prefix, .. }, store) = build_object_store(uri, options, false).await?;
         let path = object_path_from_str(&prefix)?;
+        let store = PolarsObjectStore::new(store);
+
+        let (length, version) = if BLOCK_CACHE.is_some() {
+            let object_metadata = store.head(&path).await?;
+            (
+                Some(object_metadata.size),
+                Some(object_version(&object_metadata)),
+            )
+        } else {
+            (None, None)
+        };
 
         Ok(ParquetObjectStore {
-            store: PolarsObjectStore::new(store),
+            store,
             path,
-            length: None,
+            length,
+            version,
             metadata,
         })
     }
 
     async fn get_range(&self, start: usize, length: usize) -> PolarsResult<Bytes> {
         self.store
-            .get_range(&self.path, start..start + length)
+            .get_range_cached(&self.path, self.version.as_deref(), start..start + length)
             .await
     }
 
     async fn get_ranges(&self, ranges: &[Range<usize>]) -> PolarsResult<Vec<Bytes>> {
-        self.store.get_ranges(&self.path, ranges).await
+        self.store
+            .get_ranges_cached(&self.path, self.version.as_deref(), ranges)
+            .await
     }
 
     /// Initialize the length property of the object, unless it has already been fetched.
@@ -76,7 +93,7 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, self.version.as_deref(), length).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -102,15 +119,18 @@ fn read_i32le(reader: &mut &[u8]) -> Option<i32> {
     read_n(reader).map(i32::from_le_bytes)
 }
 
-/// Asynchronously reads the files' metadata
+/// Asynchronously reads the files' metadata. The footer is read through the block cache if a
+/// `version` of the object is given.
 pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
+    version: Option<&str>,
     file_byte_length: usize,
 ) -> PolarsResult<FileMetadata> {
     let footer_header_bytes = store
-        .get_range(
+        .get_range_cached(
             path,
+            version,
             file_byte_length
                 .checked_sub(polars_parquet::parquet::FOOTER_SIZE as usize)
                 .ok_or_else(|| {
@@ -140,8 +160,9 @@ pub async fn fetch_metadata(
     };
 
     let footer_bytes = store
-        .get_range(
+        .get_range_cached(
             path,
+            version,
             file_byte_length
                 .checked_sub(polars_parquet::parquet::FOOTER_SIZE as usize + footer_byte_length)
                 .ok_or_else(|| {
@@ -36,7 +36,7 @@ use polars_error::{ErrString, PolarsError};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
 pub use reader::{BatchedParquetReader, ParquetReader};
-pub use utils::materialize_empty_df;
+pub use utils::{materialize_empty_df, unify_schemas_to_supertypes};
 
 pub mod _internal {
     pub use super::mmap::to_deserializer;
@@ -9,6 +9,9 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Unify the schemas of the files to the supertypes of their dtypes, and cast every file to
+    /// the unified schema while it is read.
+    pub schema_evolution: bool,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
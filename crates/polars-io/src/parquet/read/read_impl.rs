@@ -22,7 +22,7 @@ use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
 use super::predicates::read_this_row_group;
 use super::to_metadata::ToMetadata;
-use super::utils::materialize_empty_df;
+use super::utils::{cast_to_evolved_schema, materialize_empty_df};
 use super::{mmap, ParallelStrategy};
 use crate::hive::materialize_hive_partitions;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
@@ -976,6 +976,10 @@ pub struct BatchedParquetReader {
     include_file_path: Option<StringChunked>,
     /// Has returned at least one materialized frame.
     has_returned: bool,
+    /// The schema that the batches are cast to after they are read, with schema evolution.
+    evolved_schema: Option<ArrowSchemaRef>,
+    /// The predicate that is applied after the batches are cast.
+    post_predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 
 impl BatchedParquetReader {
@@ -1033,9 +1037,30 @@ impl BatchedParquetReader {
             include_file_path: include_file_path
                 .map(|(col, path)| StringChunked::full(col, &path, 1)),
             has_returned: false,
+            evolved_schema: None,
+            post_predicate: None,
         })
     }
 
+    /// Cast the batches to `schema` after they are read, and apply `post_predicate` to the cast
+    /// batches.
+    pub(super) fn with_evolved_schema(
+        mut self,
+        schema: Option<ArrowSchemaRef>,
+        post_predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> Self {
+        self.evolved_schema = schema;
+        self.post_predicate = post_predicate;
+        self
+    }
+
+    fn cast_to_evolved_schema(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        match &self.evolved_schema {
+            Some(schema) => cast_to_evolved_schema(df, schema, self.post_predicate.as_deref()),
+            None => Ok(df),
+        }
+    }
+
     pub fn schema(&self) -> &ArrowSchemaRef {
         &self.schema
     }
@@ -1151,6 +1176,10 @@ impl BatchedParquetReader {
                     dfs
                 },
             }?;
+            let mut dfs = dfs
+                .into_iter()
+                .map(|df| self.cast_to_evolved_schema(df))
+                .collect::<PolarsResult<Vec<_>>>()?;
 
             if let Some(ca) = self.include_file_path.as_mut() {
                 let mut max_len = 0;
@@ -1190,12 +1219,12 @@ impl BatchedParquetReader {
             // case where there is no data in the file
             // the streaming engine needs at least a single chunk
             if self.rows_read == 0 && dfs.is_empty() {
-                let mut df = materialize_empty_df(
+                let mut df = self.cast_to_evolved_schema(materialize_empty_df(
                     Some(self.projection.as_ref()),
                     &self.schema,
                     self.hive_partition_columns.as_deref(),
                     self.row_index.as_ref(),
-                );
+                ))?;
 
                 if let Some(ca) = &self.include_file_path {
                     unsafe {
@@ -1228,12 +1257,12 @@ impl BatchedParquetReader {
         if self.chunks_fifo.is_empty() {
             if skipped_all_rgs {
                 self.has_returned = true;
-                let mut df = materialize_empty_df(
+                let mut df = self.cast_to_evolved_schema(materialize_empty_df(
                     Some(self.projection.as_ref()),
                     &self.schema,
                     self.hive_partition_columns.as_deref(),
                     self.row_index.as_ref(),
-                );
+                ))?;
 
                 if let Some(ca) = &self.include_file_path {
                     unsafe {
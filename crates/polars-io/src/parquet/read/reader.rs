@@ -15,7 +15,10 @@ pub use super::read_impl::BatchedParquetReader;
 use super::read_impl::{compute_row_group_range, read_parquet, FetchRowGroupsFromMmapReader};
 #[cfg(feature = "cloud")]
 use super::utils::materialize_empty_df;
-use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices};
+use super::utils::{
+    cast_to_evolved_schema, ensure_matching_dtypes_if_found, evolved_read_schema,
+    projected_arrow_schema_to_projection_indices,
+};
 #[cfg(feature = "cloud")]
 use crate::cloud::CloudOptions;
 use crate::mmap::MmapBytesReader;
@@ -41,6 +44,8 @@ pub struct ParquetReader<R: Read + Seek> {
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
     use_statistics: bool,
+    /// The schema that the file is cast to after it is read, with schema evolution.
+    evolved_schema: Option<ArrowSchemaRef>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -83,17 +88,38 @@ impl<R: MmapBytesReader> ParquetReader<R> {
 
     /// Checks that the file contains all the columns in `projected_arrow_schema` with the same
     /// dtype, and sets the projection indices.
+    ///
+    /// With `schema_evolution`, columns may have a dtype that can be cast to the dtype in
+    /// `first_schema` instead, and the file is cast to `first_schema` after it is read.
     pub fn with_arrow_schema_projection(
         mut self,
         first_schema: &Arc<ArrowSchema>,
         projected_arrow_schema: Option<&ArrowSchema>,
         allow_missing_columns: bool,
+        schema_evolution: bool,
     ) -> PolarsResult<Self> {
-        if allow_missing_columns {
-            // Must check the dtypes
-            ensure_matching_dtypes_if_found(first_schema, self.schema()?.as_ref())?;
-            self.schema.replace(first_schema.clone());
-        }
+        let evolved_projection;
+        let (first_schema, projected_arrow_schema) = if schema_evolution {
+            let (read_schema, needs_cast) =
+                evolved_read_schema(first_schema, self.schema()?.as_ref(), allow_missing_columns)?;
+            let read_schema = Arc::new(read_schema);
+            if needs_cast {
+                self.evolved_schema = Some(first_schema.clone());
+            }
+            self.schema.replace(read_schema.clone());
+            evolved_projection = projected_arrow_schema.map(|s| with_dtypes_of(s, &read_schema));
+            (
+                &Arc::new(with_dtypes_of(first_schema, &read_schema)),
+                evolved_projection.as_ref(),
+            )
+        } else {
+            if allow_missing_columns {
+                // Must check the dtypes
+                ensure_matching_dtypes_if_found(first_schema, self.schema()?.as_ref())?;
+                self.schema.replace(first_schema.clone());
+            }
+            (first_schema, projected_arrow_schema)
+        };
 
         let schema = self.schema()?;
 
@@ -193,6 +219,11 @@ impl<R: MmapBytesReader + 'static> ParquetReader<R> {
     pub fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.get_metadata()?.clone();
         let schema = self.schema()?;
+        // The predicate is applied after the columns are cast to their evolved dtypes.
+        let post_predicate = self
+            .evolved_schema
+            .as_ref()
+            .and_then(|_| self.predicate.take());
 
         // XXX: Can a parquet file starts at an offset?
         self.reader.seek(SeekFrom::Start(0))?;
@@ -211,6 +242,7 @@ impl<R: MmapBytesReader + 'static> ParquetReader<R> {
             self.include_file_path,
             self.parallel,
         )
+        .map(|reader| reader.with_evolved_schema(self.evolved_schema, post_predicate))
     }
 }
 
@@ -232,6 +264,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             use_statistics: true,
             hive_partition_columns: None,
             include_file_path: None,
+            evolved_schema: None,
         }
     }
 
@@ -249,6 +282,10 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.projection = Some(columns_to_projection(cols, schema.as_ref())?);
         }
 
+        // The predicate is applied after the columns are cast to their evolved dtypes.
+        let evolved_schema = self.evolved_schema.take();
+        let post_predicate = evolved_schema.as_ref().and_then(|_| self.predicate.take());
+
         let mut df = read_parquet(
             self.reader,
             self.slice,
@@ -262,6 +299,10 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.hive_partition_columns.as_deref(),
         )?;
 
+        if let Some(evolved_schema) = &evolved_schema {
+            df = cast_to_evolved_schema(df, evolved_schema, post_predicate.as_deref())?;
+        }
+
         if self.rechunk {
             df.as_single_chunk_par();
         };
@@ -298,6 +339,8 @@ pub struct ParquetAsyncReader {
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
     schema: Option<ArrowSchemaRef>,
     parallel: ParallelStrategy,
+    /// The schema that the file is cast to after it is read, with schema evolution.
+    evolved_schema: Option<ArrowSchemaRef>,
 }
 
 #[cfg(feature = "cloud")]
@@ -319,6 +362,7 @@ impl ParquetAsyncReader {
             include_file_path: None,
             schema: None,
             parallel: Default::default(),
+            evolved_schema: None,
         })
     }
 
@@ -327,12 +371,33 @@ impl ParquetAsyncReader {
         first_schema: &Arc<ArrowSchema>,
         projected_arrow_schema: Option<&ArrowSchema>,
         allow_missing_columns: bool,
+        schema_evolution: bool,
     ) -> PolarsResult<Self> {
-        if allow_missing_columns {
-            // Must check the dtypes
-            ensure_matching_dtypes_if_found(first_schema, self.schema().await?.as_ref())?;
-            self.schema.replace(first_schema.clone());
-        }
+        let evolved_projection;
+        let (first_schema, projected_arrow_schema) = if schema_evolution {
+            let (read_schema, needs_cast) = evolved_read_schema(
+                first_schema,
+                self.schema().await?.as_ref(),
+                allow_missing_columns,
+            )?;
+            let read_schema = Arc::new(read_schema);
+            if needs_cast {
+                self.evolved_schema = Some(first_schema.clone());
+            }
+            self.schema.replace(read_schema.clone());
+            evolved_projection = projected_arrow_schema.map(|s| with_dtypes_of(s, &read_schema));
+            (
+                &Arc::new(with_dtypes_of(first_schema, &read_schema)),
+                evolved_projection.as_ref(),
+            )
+        } else {
+            if allow_missing_columns {
+                // Must check the dtypes
+                ensure_matching_dtypes_if_found(first_schema, self.schema().await?.as_ref())?;
+                self.schema.replace(first_schema.clone());
+            }
+            (first_schema, projected_arrow_schema)
+        };
 
         let schema = self.schema().await?;
 
@@ -443,6 +508,11 @@ impl ParquetAsyncReader {
 
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.reader.get_metadata().await?.clone();
+        // The predicate is applied after the columns are cast to their evolved dtypes.
+        let post_predicate = self
+            .evolved_schema
+            .as_ref()
+            .and_then(|_| self.predicate.take());
         let schema = match self.schema {
             Some(schema) => schema,
             None => self.schema().await?,
@@ -476,6 +546,7 @@ impl ParquetAsyncReader {
             self.include_file_path,
             self.parallel,
         )
+        .map(|reader| reader.with_evolved_schema(self.evolved_schema, post_predicate))
     }
 
     pub async fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
@@ -484,6 +555,7 @@ impl ParquetAsyncReader {
 
     pub async fn finish(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
+        let evolved_schema = self.evolved_schema.clone();
         let metadata = self.get_metadata().await?.clone();
         let reader_schema = self.schema().await?;
         let row_index = self.row_index.clone();
@@ -500,12 +572,16 @@ impl ParquetAsyncReader {
             chunks.push(result?)
         }
         if chunks.is_empty() {
-            return Ok(materialize_empty_df(
+            let df = materialize_empty_df(
                 projection.as_deref(),
                 reader_schema.as_ref(),
                 hive_partition_columns.as_deref(),
                 row_index.as_ref(),
-            ));
+            );
+            return match &evolved_schema {
+                Some(evolved_schema) => cast_to_evolved_schema(df, evolved_schema, None),
+                None => Ok(df),
+            };
         }
         let mut df = accumulate_dataframes_vertical_unchecked(chunks);
 
@@ -515,3 +591,11 @@ impl ParquetAsyncReader {
         Ok(df)
     }
 }
+
+/// `schema` with the dtypes of the columns that are in `read_schema`.
+fn with_dtypes_of(schema: &ArrowSchema, read_schema: &ArrowSchema) -> ArrowSchema {
+    schema
+        .iter_values()
+        .map(|field| read_schema.get(&field.name).unwrap_or(field).clone())
+        .collect()
+}
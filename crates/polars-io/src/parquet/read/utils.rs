@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 
-use polars_core::prelude::{ArrowSchema, DataFrame, DataType, Series, IDX_DTYPE};
+use polars_core::prelude::{ArrowSchema, CompatLevel, DataFrame, DataType, Series, IDX_DTYPE};
 use polars_core::schema::SchemaNamesAndDtypes;
-use polars_error::{polars_bail, PolarsResult};
+use polars_core::utils::try_get_supertype;
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsResult};
 
 use crate::hive::materialize_hive_partitions;
+use crate::predicates::PhysicalIoExpr;
 use crate::utils::apply_projection;
 use crate::RowIndex;
 
@@ -89,3 +91,118 @@ pub fn ensure_matching_dtypes_if_found(
             Ok(())
         })
 }
+
+/// Unifies the schemas of the files of a scan with schema evolution.
+///
+/// Columns whose dtype changed between files get the supertype of their dtypes, e.g. `Int64` for
+/// `Int32` and `Int64`, or a struct with the fields of both structs. Columns that are not in the
+/// first schema are appended if `allow_missing_columns` is set.
+pub fn unify_schemas_to_supertypes<'a>(
+    schemas: impl IntoIterator<Item = &'a ArrowSchema>,
+    allow_missing_columns: bool,
+) -> PolarsResult<ArrowSchema> {
+    let mut schemas = schemas.into_iter();
+    let Some(first) = schemas.next() else {
+        polars_bail!(ComputeError: "expected at least 1 schema");
+    };
+    let mut out = first.clone();
+
+    for schema in schemas {
+        for (name, field) in schema.iter() {
+            let Ok(out_field) = out.try_get_mut(name) else {
+                if allow_missing_columns {
+                    out.insert(name.clone(), field.clone());
+                }
+                continue;
+            };
+            if out_field.dtype == field.dtype {
+                continue;
+            }
+            let lhs = DataType::from_arrow(&out_field.dtype, true);
+            let rhs = DataType::from_arrow(&field.dtype, true);
+            if lhs != rhs {
+                let supertype = try_get_supertype(&lhs, &rhs).map_err(|_| {
+                    polars_err!(
+                        SchemaMismatch:
+                        "cannot unify dtypes of column {} for schema evolution: {} and {}",
+                        name, lhs, rhs
+                    )
+                })?;
+                *out_field = supertype.to_arrow_field(name.clone(), CompatLevel::newest());
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+/// The schema to decode a file with for schema evolution: the columns of `schema` that are in
+/// the file keep the dtype of the file, so that they can be cast to `schema` after they are read.
+/// Also returns whether any of the dtypes differ from `schema`.
+pub(super) fn evolved_read_schema(
+    schema: &ArrowSchema,
+    file_schema: &ArrowSchema,
+    allow_missing_columns: bool,
+) -> PolarsResult<(ArrowSchema, bool)> {
+    let mut needs_cast = false;
+    let mut check_castable = |field: &polars_core::prelude::ArrowField| {
+        let Some(target) = schema.get(&field.name) else {
+            return Ok(());
+        };
+        let from = DataType::from_arrow(&field.dtype, true);
+        let to = DataType::from_arrow(&target.dtype, true);
+        if from != to {
+            polars_ensure!(
+                try_get_supertype(&from, &to).ok().as_ref() == Some(&to),
+                SchemaMismatch: "cannot cast column {} from {} to {} for schema evolution",
+                field.name, from, to
+            );
+            needs_cast = true;
+        }
+        PolarsResult::Ok(())
+    };
+
+    let read_schema = if allow_missing_columns {
+        schema
+            .iter_values()
+            .map(|field| match file_schema.get(&field.name) {
+                Some(file_field) => {
+                    check_castable(file_field)?;
+                    Ok(file_field.clone())
+                },
+                None => Ok(field.clone()),
+            })
+            .collect::<PolarsResult<ArrowSchema>>()?
+    } else {
+        file_schema
+            .iter_values()
+            .try_for_each(&mut check_castable)?;
+        file_schema.clone()
+    };
+
+    Ok((read_schema, needs_cast))
+}
+
+/// Casts the columns of a file that was read with an [`evolved_read_schema`] to the dtypes of
+/// `schema`, and applies the predicate that could not be applied to the uncast columns.
+pub(super) fn cast_to_evolved_schema(
+    mut df: DataFrame,
+    schema: &ArrowSchema,
+    predicate: Option<&dyn PhysicalIoExpr>,
+) -> PolarsResult<DataFrame> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|c| match schema.get(c.name()) {
+            Some(field) => c.cast(&DataType::from_arrow(&field.dtype, true)),
+            None => Ok(c.clone()),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    df = unsafe { DataFrame::new_no_checks(columns) };
+
+    if let Some(predicate) = predicate {
+        let mask = predicate.evaluate_io(&df)?;
+        df = df.filter(mask.bool()?)?;
+    }
+    Ok(df)
+}
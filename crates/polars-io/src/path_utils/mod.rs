@@ -4,7 +4,8 @@ use std::sync::Arc;
 
 use once_cell::sync::Lazy;
 use polars_core::config;
-use polars_core::error::{polars_bail, to_compute_err, PolarsError, PolarsResult};
+use polars_core::error::{polars_bail, polars_err, to_compute_err, PolarsError, PolarsResult};
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
 use polars_utils::pl_str::PlSmallStr;
 use regex::Regex;
 
@@ -12,6 +13,7 @@ use regex::Regex;
 mod hugging_face;
 
 use crate::cloud::CloudOptions;
+use crate::options::FileFilterOptions;
 
 pub static POLARS_TEMP_DIR_BASE_PATH: Lazy<Box<Path>> = Lazy::new(|| {
     let path = std::env::var("POLARS_TEMP_DIR")
@@ -84,13 +86,147 @@ pub fn expanded_from_single_directory<P: AsRef<std::path::Path>>(
     }
 }
 
-/// Recursively traverses directories and expands globs if `glob` is `true`.
+/// A file that was found when expanding paths, with its metadata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListedFile {
+    pub path: PathBuf,
+    /// The size in bytes.
+    pub size: u64,
+    /// The modification time in milliseconds since the Unix epoch.
+    pub last_modified: i64,
+}
+
+impl ListedFile {
+    fn from_local(path: PathBuf, metadata: &std::fs::Metadata) -> PolarsResult<Self> {
+        let last_modified = match metadata.modified()?.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => duration.as_millis() as i64,
+            Err(err) => -(err.duration().as_millis() as i64),
+        };
+        Ok(Self {
+            path,
+            size: metadata.len(),
+            last_modified,
+        })
+    }
+}
+
+/// Recursively traverses directories and expands globs if `glob` is `true`. Files that don't
+/// pass `file_filter` are left out.
 pub fn expand_paths(
     paths: &[PathBuf],
     glob: bool,
     #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
+    file_filter: &FileFilterOptions,
 ) -> PolarsResult<Arc<[PathBuf]>> {
-    expand_paths_hive(paths, glob, cloud_options, false).map(|x| x.0)
+    expand_paths_hive(paths, glob, cloud_options, false, file_filter).map(|x| x.0)
+}
+
+/// Expands the paths like [`expand_paths`], and returns the files with their metadata. This can
+/// be used to advance a [`FileCheckpoint`](crate::FileCheckpoint) after the files are read.
+pub fn list_files(
+    paths: &[PathBuf],
+    glob: bool,
+    cloud_options: Option<&CloudOptions>,
+    file_filter: &FileFilterOptions,
+) -> PolarsResult<Vec<ListedFile>> {
+    let mut listed = PlHashMap::new();
+    let (paths, _) = expand_paths_impl(
+        paths,
+        glob,
+        cloud_options,
+        false,
+        file_filter,
+        &mut listed,
+        true,
+    )?;
+    resolve_metadata(&paths, &listed, cloud_options)
+}
+
+/// Get the metadata of `paths`, from the listing or with a request per file.
+fn resolve_metadata(
+    paths: &[PathBuf],
+    listed: &PlHashMap<PathBuf, ListedFile>,
+    #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Vec<ListedFile>> {
+    let mut files = Vec::with_capacity(paths.len());
+    let mut cloud_paths = vec![];
+    for (i, path) in paths.iter().enumerate() {
+        if let Some(file) = listed.get(path).cloned() {
+            files.push(Some(file));
+        } else if is_cloud_url(path) {
+            files.push(None);
+            cloud_paths.push(i);
+        } else {
+            let metadata = std::fs::metadata(path)
+                .map_err(|err| polars_utils::_limit_path_len_io_err(path, err))?;
+            files.push(Some(ListedFile::from_local(path.clone(), &metadata)?));
+        }
+    }
+
+    if !cloud_paths.is_empty() {
+        #[cfg(feature = "cloud")]
+        {
+            let requested = crate::pl_async::get_runtime().block_on_potential_spawn(
+                futures::future::try_join_all(cloud_paths.iter().map(|&i| async move {
+                    let path = paths[i].to_str().unwrap();
+                    let (cloud_location, store) =
+                        crate::cloud::build_object_store(path, cloud_options, false).await?;
+                    let location = crate::cloud::object_path_from_str(&cloud_location.prefix)?;
+                    let meta = store.head(&location).await.map_err(to_compute_err)?;
+                    PolarsResult::Ok(ListedFile {
+                        path: paths[i].clone(),
+                        size: meta.size as u64,
+                        last_modified: meta.last_modified.timestamp_millis(),
+                    })
+                })),
+            )?;
+            for (i, file) in cloud_paths.into_iter().zip(requested) {
+                files[i] = Some(file);
+            }
+        }
+        #[cfg(not(feature = "cloud"))]
+        panic!("Feature `cloud` must be enabled to filter files of cloud urls.")
+    }
+
+    Ok(files.into_iter().map(Option::unwrap).collect())
+}
+
+/// Leave out the expanded paths that don't pass `file_filter`.
+fn filter_paths(
+    paths: Vec<PathBuf>,
+    listed: &PlHashMap<PathBuf, ListedFile>,
+    file_filter: &FileFilterOptions,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Vec<PathBuf>> {
+    let exclude = file_filter
+        .exclude
+        .iter()
+        .map(|pattern| {
+            glob::Pattern::new(pattern).map_err(
+                |err| polars_err!(ComputeError: "invalid exclude pattern '{}': {}", pattern, err),
+            )
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..Default::default()
+    };
+    let mut paths = paths;
+    paths.retain(|path| {
+        let path = path.to_string_lossy();
+        !exclude
+            .iter()
+            .any(|pattern| pattern.matches_with(&path, options))
+    });
+
+    if !file_filter.needs_metadata() {
+        return Ok(paths);
+    }
+    Ok(resolve_metadata(&paths, listed, cloud_options)?
+        .into_iter()
+        .filter(|file| file_filter.matches_metadata(file))
+        .map(|file| file.path)
+        .collect())
 }
 
 struct HiveIdxTracker<'a> {
@@ -123,6 +259,8 @@ impl<'a> HiveIdxTracker<'a> {
 }
 
 /// Recursively traverses directories and expands globs if `glob` is `true`.
+///
+/// Files that don't pass `file_filter` are left out.
 /// Returns the expanded paths and the index at which to start parsing hive
 /// partitions from the path.
 pub fn expand_paths_hive(
@@ -130,6 +268,29 @@ pub fn expand_paths_hive(
     glob: bool,
     #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
     check_directory_level: bool,
+    file_filter: &FileFilterOptions,
+) -> PolarsResult<(Arc<[PathBuf]>, usize)> {
+    expand_paths_impl(
+        paths,
+        glob,
+        cloud_options,
+        check_directory_level,
+        file_filter,
+        &mut PlHashMap::new(),
+        file_filter.needs_metadata(),
+    )
+}
+
+/// Expands the paths, and collects the metadata of listed files into `listed` if
+/// `collect_metadata` is `true`.
+fn expand_paths_impl(
+    paths: &[PathBuf],
+    glob: bool,
+    #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
+    check_directory_level: bool,
+    file_filter: &FileFilterOptions,
+    listed: &mut PlHashMap<PathBuf, ListedFile>,
+    collect_metadata: bool,
 ) -> PolarsResult<(Arc<[PathBuf]>, usize)> {
     let Some(first_path) = paths.first() else {
         return Ok((vec![].into(), 0));
@@ -216,6 +377,7 @@ pub fn expand_paths_hive(
                         glob,
                     ))?;
 
+                let paths = filter_paths(paths, listed, file_filter, cloud_options)?;
                 return Ok((Arc::from(paths), expand_start_idx));
             }
 
@@ -227,81 +389,87 @@ pub fn expand_paths_hive(
                 }
             };
 
-            let expand_path_cloud = |path: &str,
-                                     cloud_options: Option<&CloudOptions>|
-             -> PolarsResult<(usize, Vec<PathBuf>)> {
-                crate::pl_async::get_runtime().block_on_potential_spawn(async {
-                    let (cloud_location, store) =
-                        crate::cloud::build_object_store(path, cloud_options, glob).await?;
-                    let prefix = object_path_from_str(&cloud_location.prefix)?;
-
-                    let out = if !path.ends_with("/")
-                        && (!glob || cloud_location.expansion.is_none())
-                        && {
-                            // We need to check if it is a directory for local paths (we can be here due
-                            // to FORCE_ASYNC). For cloud paths the convention is that the user must add
-                            // a trailing slash `/` to scan directories. We don't infer it as that would
-                            // mean sending one network request per path serially (very slow).
-                            is_cloud || PathBuf::from(path).is_file()
-                        } {
-                        (
-                            0,
-                            vec![PathBuf::from(format_path(
-                                &cloud_location.scheme,
-                                &cloud_location.bucket,
-                                prefix.as_ref(),
-                            ))],
-                        )
-                    } else {
-                        use futures::TryStreamExt;
-
-                        if !is_cloud {
-                            // FORCE_ASYNC in the test suite wants us to raise a proper error message
-                            // for non-existent file paths. Note we can't do this for cloud paths as
-                            // there is no concept of a "directory" - a non-existent path is
-                            // indistinguishable from an empty directory.
-                            let path = PathBuf::from(path);
-                            if !path.is_dir() {
-                                path.metadata()
-                                    .map_err(|err| _limit_path_len_io_err(&path, err))?;
+            let expand_path_cloud =
+                |path: &str,
+                 cloud_options: Option<&CloudOptions>|
+                 -> PolarsResult<(usize, Vec<PathBuf>, Vec<ListedFile>)> {
+                    crate::pl_async::get_runtime().block_on_potential_spawn(async {
+                        let (cloud_location, store) =
+                            crate::cloud::build_object_store(path, cloud_options, glob).await?;
+                        let prefix = object_path_from_str(&cloud_location.prefix)?;
+
+                        let out = if !path.ends_with("/")
+                            && (!glob || cloud_location.expansion.is_none())
+                            && {
+                                // We need to check if it is a directory for local paths (we can be here due
+                                // to FORCE_ASYNC). For cloud paths the convention is that the user must add
+                                // a trailing slash `/` to scan directories. We don't infer it as that would
+                                // mean sending one network request per path serially (very slow).
+                                is_cloud || PathBuf::from(path).is_file()
+                            } {
+                            (
+                                0,
+                                vec![PathBuf::from(format_path(
+                                    &cloud_location.scheme,
+                                    &cloud_location.bucket,
+                                    prefix.as_ref(),
+                                ))],
+                                vec![],
+                            )
+                        } else {
+                            use futures::TryStreamExt;
+
+                            if !is_cloud {
+                                // FORCE_ASYNC in the test suite wants us to raise a proper error message
+                                // for non-existent file paths. Note we can't do this for cloud paths as
+                                // there is no concept of a "directory" - a non-existent path is
+                                // indistinguishable from an empty directory.
+                                let path = PathBuf::from(path);
+                                if !path.is_dir() {
+                                    path.metadata()
+                                        .map_err(|err| _limit_path_len_io_err(&path, err))?;
+                                }
                             }
-                        }
 
-                        let cloud_location = &cloud_location;
-
-                        let mut paths = store
-                            .list(Some(&prefix))
-                            .try_filter_map(|x| async move {
-                                let out = (x.size > 0).then(|| {
-                                    PathBuf::from({
-                                        format_path(
-                                            &cloud_location.scheme,
-                                            &cloud_location.bucket,
-                                            x.location.as_ref(),
-                                        )
-                                    })
-                                });
-                                Ok(out)
-                            })
-                            .try_collect::<Vec<_>>()
-                            .await
-                            .map_err(to_compute_err)?;
-
-                        paths.sort_unstable();
-                        (
-                            format_path(
-                                &cloud_location.scheme,
-                                &cloud_location.bucket,
-                                &cloud_location.prefix,
+                            let cloud_location = &cloud_location;
+
+                            let mut files = store
+                                .list(Some(&prefix))
+                                .try_filter_map(|x| async move {
+                                    let out = (x.size > 0).then(|| ListedFile {
+                                        path: PathBuf::from({
+                                            format_path(
+                                                &cloud_location.scheme,
+                                                &cloud_location.bucket,
+                                                x.location.as_ref(),
+                                            )
+                                        }),
+                                        size: x.size as u64,
+                                        last_modified: x.last_modified.timestamp_millis(),
+                                    });
+                                    Ok(out)
+                                })
+                                .try_collect::<Vec<_>>()
+                                .await
+                                .map_err(to_compute_err)?;
+
+                            files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+                            let paths = files.iter().map(|file| file.path.clone()).collect();
+                            (
+                                format_path(
+                                    &cloud_location.scheme,
+                                    &cloud_location.bucket,
+                                    &cloud_location.prefix,
+                                )
+                                .len(),
+                                paths,
+                                files,
                             )
-                            .len(),
-                            paths,
-                        )
-                    };
+                        };
 
-                    PolarsResult::Ok(out)
-                })
-            };
+                        PolarsResult::Ok(out)
+                    })
+                };
 
             for (path_idx, path) in paths.iter().enumerate() {
                 if path.to_str().unwrap().starts_with("http") {
@@ -315,24 +483,31 @@ pub fn expand_paths_hive(
                 let path = if glob && glob_start_idx.is_some() {
                     path.clone()
                 } else {
-                    let (expand_start_idx, paths) =
+                    let (expand_start_idx, paths, files) =
                         expand_path_cloud(path.to_str().unwrap(), cloud_options)?;
                     out_paths.extend_from_slice(&paths);
+                    if collect_metadata {
+                        listed.extend(files.into_iter().map(|file| (file.path.clone(), file)));
+                    }
                     hive_idx_tracker.update(expand_start_idx, path_idx)?;
                     continue;
                 };
 
                 hive_idx_tracker.update(0, path_idx)?;
 
-                let iter = crate::pl_async::get_runtime().block_on_potential_spawn(
-                    crate::async_glob(path.to_str().unwrap(), cloud_options),
+                let files = crate::pl_async::get_runtime().block_on_potential_spawn(
+                    crate::cloud::glob_listed(path.to_str().unwrap(), cloud_options),
                 )?;
 
-                if is_cloud {
-                    out_paths.extend(iter.into_iter().map(PathBuf::from));
-                } else {
-                    // FORCE_ASYNC, remove leading file:// as not all readers support it.
-                    out_paths.extend(iter.iter().map(|x| &x[7..]).map(PathBuf::from))
+                for mut file in files {
+                    if !is_cloud {
+                        // FORCE_ASYNC, remove leading file:// as not all readers support it.
+                        file.path = PathBuf::from(&file.path.to_str().unwrap()[7..]);
+                    }
+                    out_paths.push(file.path.clone());
+                    if collect_metadata {
+                        listed.insert(file.path.clone(), file);
+                    }
                 }
             }
         }
@@ -363,7 +538,14 @@ pub fn expand_paths_hive(
                     for path in paths {
                         if path.is_dir() {
                             stack.push_back(path);
-                        } else if path.metadata()?.len() > 0 {
+                            continue;
+                        }
+                        let metadata = path.metadata()?;
+                        if metadata.len() > 0 {
+                            if collect_metadata {
+                                let file = ListedFile::from_local(path.clone(), &metadata)?;
+                                listed.insert(path.clone(), file);
+                            }
                             out_paths.push(path);
                         }
                     }
@@ -383,7 +565,15 @@ pub fn expand_paths_hive(
 
                 for path in paths {
                     let path = path.map_err(to_compute_err)?;
-                    if !path.is_dir() && path.metadata()?.len() > 0 {
+                    if path.is_dir() {
+                        continue;
+                    }
+                    let metadata = path.metadata()?;
+                    if metadata.len() > 0 {
+                        if collect_metadata {
+                            let file = ListedFile::from_local(path.clone(), &metadata)?;
+                            listed.insert(path.clone(), file);
+                        }
                         out_paths.push(path);
                     }
                 }
@@ -394,6 +584,21 @@ pub fn expand_paths_hive(
         }
     }
 
+    if !file_filter.is_empty() {
+        let paths = filter_paths(
+            std::mem::take(&mut out_paths.paths),
+            listed,
+            file_filter,
+            cloud_options,
+        )?;
+        out_paths = OutPaths {
+            paths: Vec::with_capacity(paths.len()),
+            exts: [None, None],
+            current_idx: 0,
+        };
+        out_paths.extend(paths);
+    }
+
     assert_eq!(out_paths.current_idx, out_paths.paths.len());
 
     if expanded_from_single_directory(paths, out_paths.paths.as_slice()) {
@@ -424,9 +629,12 @@ pub(crate) fn ensure_directory_init(path: &Path) -> std::io::Result<()> {
 
 #[cfg(test)]
 mod tests {
+    use std::fs::File;
     use std::path::PathBuf;
+    use std::time::{Duration, UNIX_EPOCH};
 
-    use super::resolve_homedir;
+    use super::{expand_paths, list_files, resolve_homedir};
+    use crate::options::{FileCheckpoint, FileFilterOptions};
 
     #[cfg(not(target_os = "windows"))]
     #[test]
@@ -476,7 +684,73 @@ mod tests {
 
         let path = "https://pola.rs/test.csv?token=bear";
         let paths = &[PathBuf::from(path)];
-        let out = expand_paths(paths, true, None).unwrap();
+        let out = expand_paths(paths, true, None, &Default::default()).unwrap();
         assert_eq!(out.as_ref(), paths);
     }
+
+    #[test]
+    fn test_expand_paths_file_filter() {
+        let dir = std::env::temp_dir().join("polars_expand_paths_file_filter");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let write = |name: &str, len: usize, modified: u64| {
+            let path = dir.join(name);
+            std::fs::write(&path, vec![b'a'; len]).unwrap();
+            File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(UNIX_EPOCH + Duration::from_secs(modified))
+                .unwrap();
+            path
+        };
+        let a = write("a.csv", 10, 100);
+        let b = write("b.csv", 20, 200);
+        let c = write("c.csv", 30, 200);
+        write("c.csv.crc", 1, 300);
+
+        let expand = |file_filter: FileFilterOptions| {
+            expand_paths(&[dir.clone()], true, None, &file_filter)
+                .unwrap()
+                .to_vec()
+        };
+        let all = FileFilterOptions {
+            exclude: vec!["**/*.crc".into()],
+            ..Default::default()
+        };
+        assert_eq!(expand(all.clone()), [a.clone(), b.clone(), c.clone()]);
+        // `*` does not match `/`, like in the glob of the scan, so the `.crc` file isn't excluded.
+        let star = FileFilterOptions {
+            exclude: vec!["*.crc".into()],
+            ..Default::default()
+        };
+        assert!(expand_paths(&[dir.clone()], true, None, &star).is_err());
+        assert_eq!(
+            expand(FileFilterOptions {
+                modified_after: Some(200_000),
+                max_size: Some(20),
+                ..all.clone()
+            }),
+            [b.clone()]
+        );
+
+        // Files with the same modification time as the checkpoint are new if they weren't read.
+        let checkpoint = FileCheckpoint::default().advance(
+            &list_files(&[a.clone(), b.clone()], true, None, &Default::default()).unwrap(),
+        );
+        assert_eq!(checkpoint.last_modified, 200_000);
+        let file_filter = FileFilterOptions {
+            checkpoint: Some(checkpoint),
+            ..all
+        };
+        assert_eq!(expand(file_filter.clone()), [c.clone()]);
+        let glob = dir.join("*.csv");
+        assert_eq!(
+            expand_paths(&[glob], true, None, &file_filter)
+                .unwrap()
+                .to_vec(),
+            [c]
+        );
+    }
 }
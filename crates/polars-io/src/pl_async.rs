@@ -231,6 +231,52 @@ where
     callable().await
 }
 
+/// Limits the rate of requests with a token bucket that holds at most one second worth of
+/// requests.
+#[cfg(feature = "cloud")]
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    requests_per_second: u32,
+    /// Available tokens and the time they were last refilled.
+    state: std::sync::Mutex<(f64, std::time::Instant)>,
+}
+
+#[cfg(feature = "cloud")]
+impl RateLimiter {
+    pub(crate) fn new(requests_per_second: u32) -> Self {
+        assert!(requests_per_second > 0);
+        Self {
+            requests_per_second,
+            state: std::sync::Mutex::new((requests_per_second as f64, std::time::Instant::now())),
+        }
+    }
+
+    pub(crate) fn requests_per_second(&self) -> u32 {
+        self.requests_per_second
+    }
+
+    /// Wait until a request may be sent.
+    pub(crate) async fn acquire(&self) {
+        let rate = self.requests_per_second as f64;
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let (tokens, last_refill) = &mut *state;
+                let now = std::time::Instant::now();
+                *tokens = (*tokens + (now - *last_refill).as_secs_f64() * rate).min(rate);
+                *last_refill = now;
+
+                if *tokens >= 1.0 {
+                    *tokens -= 1.0;
+                    return;
+                }
+                std::time::Duration::from_secs_f64((1.0 - *tokens) / rate)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
 pub struct RuntimeManager {
     rt: Runtime,
 }
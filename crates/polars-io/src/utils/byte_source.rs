@@ -4,11 +4,13 @@ use std::sync::Arc;
 use polars_error::PolarsResult;
 use polars_utils::_limit_path_len_io_err;
 use polars_utils::mmap::MemSlice;
+use polars_utils::pl_str::PlSmallStr;
 
 use crate::cloud::{
     build_object_store, object_path_from_str, CloudLocation, CloudOptions, ObjectStorePath,
     PolarsObjectStore,
 };
+use crate::file_cache::{object_version, BLOCK_CACHE};
 
 #[allow(async_fn_in_trait)]
 pub trait ByteSource: Send + Sync {
@@ -60,6 +62,8 @@ impl ByteSource for MemSliceByteSource {
 pub struct ObjectStoreByteSource {
     store: PolarsObjectStore,
     path: ObjectStorePath,
+    /// Size and version of the object, only fetched up front if the block cache is enabled.
+    size_and_version: Option<(usize, PlSmallStr)>,
 }
 
 impl ObjectStoreByteSource {
@@ -72,24 +76,48 @@ impl ObjectStoreByteSource {
         let path = object_path_from_str(&prefix)?;
         let store = PolarsObjectStore::new(store);
 
-        Ok(Self { store, path })
+        let size_and_version = if BLOCK_CACHE.is_some() {
+            let meta = store.head(&path).await?;
+            Some((meta.size, object_version(&meta)))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            store,
+            path,
+            size_and_version,
+        })
+    }
+
+    fn version(&self) -> Option<&str> {
+        self.size_and_version.as_ref().map(|(_, v)| v.as_str())
     }
 }
 
 impl ByteSource for ObjectStoreByteSource {
     async fn get_size(&self) -> PolarsResult<usize> {
+        if let Some((size, _)) = &self.size_and_version {
+            return Ok(*size);
+        }
         Ok(self.store.head(&self.path).await?.size)
     }
 
     async fn get_range(&self, range: Range<usize>) -> PolarsResult<MemSlice> {
-        let bytes = self.store.get_range(&self.path, range).await?;
+        let bytes = self
+            .store
+            .get_range_cached(&self.path, self.version(), range)
+            .await?;
         let mem_slice = MemSlice::from_bytes(bytes);
 
         Ok(mem_slice)
     }
 
     async fn get_ranges(&self, ranges: &[Range<usize>]) -> PolarsResult<Vec<MemSlice>> {
-        let ranges = self.store.get_ranges(&self.path, ranges).await?;
+        let ranges = self
+            .store
+            .get_ranges_cached(&self.path, self.version(), ranges)
+            .await?;
         Ok(ranges.into_iter().map(MemSlice::from_bytes).collect())
     }
 }
@@ -1,13 +1,17 @@
-use std::io::Read;
+use std::io::{Read, Write};
 
 use polars_core::prelude::*;
 use polars_error::to_compute_err;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents the compression algorithms that we have decoders for
 pub enum SupportedCompression {
     GZIP,
     ZLIB,
     ZSTD,
+    BZIP2,
+    XZ,
 }
 
 impl SupportedCompression {
@@ -25,11 +29,28 @@ impl SupportedCompression {
             [0x78, 0xDA, _, _]   // ZLIB2
                                      => Some(Self::ZLIB),
             [0x28, 0xB5, 0x2F, 0xFD] => Some(Self::ZSTD),
+            [b'B', b'Z', b'h', b'1'..=b'9'] => Some(Self::BZIP2),
+            [0xFD, b'7', b'z', b'X'] => Some(Self::XZ),
             _ => None,
         }
     }
 }
 
+/// Returns a reader that lazily decompresses `bytes` with the given algorithm.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+pub fn decompressing_reader<'a>(
+    algo: SupportedCompression,
+    bytes: &'a [u8],
+) -> PolarsResult<Box<dyn Read + Send + 'a>> {
+    Ok(match algo {
+        SupportedCompression::GZIP => Box::new(flate2::read::MultiGzDecoder::new(bytes)),
+        SupportedCompression::ZLIB => Box::new(flate2::read::ZlibDecoder::new(bytes)),
+        SupportedCompression::ZSTD => Box::new(zstd::Decoder::with_buffer(bytes)?),
+        SupportedCompression::BZIP2 => Box::new(bzip2::read::MultiBzDecoder::new(bytes)),
+        SupportedCompression::XZ => Box::new(xz2::read::XzDecoder::new_multi_decoder(bytes)),
+    })
+}
+
 /// Decompress `bytes` if compression is detected, otherwise simply return it.
 /// An `out` vec must be given for ownership of the decompressed data.
 pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> PolarsResult<&'a [u8]> {
@@ -38,29 +59,466 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
     if let Some(algo) = SupportedCompression::check(bytes) {
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
         {
-            match algo {
-                SupportedCompression::GZIP => {
-                    flate2::read::MultiGzDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZLIB => {
-                    flate2::read::ZlibDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZSTD => {
-                    zstd::Decoder::new(bytes)?.read_to_end(out)?;
-                },
-            }
+            decompressing_reader(algo, bytes)?
+                .read_to_end(out)
+                .map_err(to_compute_err)?;
 
             Ok(out)
         }
         #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
         {
+            let _ = algo;
             panic!("cannot decompress without 'decompress' or 'decompress-fast' feature")
         }
     } else {
         Ok(bytes)
     }
 }
+
+/// Returns a reader that decompresses `bytes` on the fly if compression is detected, otherwise a
+/// reader over `bytes` itself.
+pub fn maybe_decompressing_reader<'a>(bytes: &'a [u8]) -> PolarsResult<Box<dyn Read + Send + 'a>> {
+    if let Some(algo) = SupportedCompression::check(bytes) {
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        {
+            decompressing_reader(algo, bytes)
+        }
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        {
+            let _ = algo;
+            panic!("cannot decompress without 'decompress' or 'decompress-fast' feature")
+        }
+    } else {
+        Ok(Box::new(bytes))
+    }
+}
+
+/// Default number of decompressed bytes that are handed out per chunk by [`DecompressedChunks`].
+const DEFAULT_DECOMPRESS_CHUNK_SIZE: usize = 1 << 26;
+
+/// Number of decompressed bytes per chunk when streaming compressed files in lazy scans.
+///
+/// Can be set with the `POLARS_DECOMPRESS_CHUNK_SIZE` environment variable.
+pub fn decompress_chunk_size() -> usize {
+    std::env::var("POLARS_DECOMPRESS_CHUNK_SIZE")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_DECOMPRESS_CHUNK_SIZE)
+}
+
+#[cfg_attr(
+    not(any(feature = "decompress", feature = "decompress-fast")),
+    allow(dead_code)
+)]
+enum ChunkSource<'a> {
+    Reader(Box<dyn Read + Send + 'a>),
+    /// Zstd data that consists of multiple independent frames (e.g. the seekable format or
+    /// the output of `pzstd`). These frames are decoded in parallel.
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    ZstdFrames {
+        bytes: &'a [u8],
+        offset: usize,
+    },
+}
+
+/// Streaming decompression that hands out line-aligned chunks of roughly `chunk_size` bytes.
+///
+/// This allows the lazy scans (and their row counts) to parse compressed text files in bounded
+/// memory instead of decompressing the whole file up front. The sources of the streaming engines
+/// don't use this, they still decompress whole files.
+pub struct DecompressedChunks<'a> {
+    source: ChunkSource<'a>,
+    chunk_size: usize,
+    eol_char: u8,
+    quote_char: Option<u8>,
+    buf: Vec<u8>,
+    exhausted: bool,
+    /// Number of bytes of `buf` that were scanned for a split position. The scan resumes from
+    /// here after a refill, so that a long (quoted) line is not rescanned from the start.
+    scanned: usize,
+    /// Whether `buf[scanned]` is inside a quoted field.
+    in_quotes: bool,
+    /// Position of the last `eol_char` outside of quotes in `buf[..scanned]`.
+    last_split: Option<usize>,
+}
+
+impl<'a> DecompressedChunks<'a> {
+    /// Returns `None` if `bytes` are not compressed.
+    pub fn try_new(
+        bytes: &'a [u8],
+        chunk_size: usize,
+        eol_char: u8,
+        quote_char: Option<u8>,
+    ) -> PolarsResult<Option<Self>> {
+        let Some(algo) = SupportedCompression::check(bytes) else {
+            return Ok(None);
+        };
+
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        {
+            let source = match algo {
+                SupportedCompression::ZSTD if zstd_frame_size(bytes)? < bytes.len() => {
+                    ChunkSource::ZstdFrames { bytes, offset: 0 }
+                },
+                algo => ChunkSource::Reader(decompressing_reader(algo, bytes)?),
+            };
+
+            Ok(Some(Self {
+                source,
+                chunk_size: chunk_size.max(1),
+                eol_char,
+                quote_char,
+                buf: vec![],
+                exhausted: false,
+                scanned: 0,
+                in_quotes: false,
+                last_split: None,
+            }))
+        }
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        {
+            let _ = (algo, chunk_size, eol_char, quote_char);
+            polars_bail!(
+                ComputeError: "cannot decompress without 'decompress' or 'decompress-fast' feature"
+            )
+        }
+    }
+
+    /// Returns the next chunk of decompressed data. Every chunk but the last one ends with
+    /// `eol_char` that is not inside a quoted field.
+    pub fn next_chunk(&mut self) -> PolarsResult<Option<Vec<u8>>> {
+        loop {
+            if !self.exhausted && self.buf.len() < self.chunk_size {
+                self.fill()?;
+                continue;
+            }
+
+            if self.buf.is_empty() {
+                return Ok(None);
+            }
+            if self.exhausted {
+                self.scanned = 0;
+                self.last_split = None;
+                return Ok(Some(std::mem::take(&mut self.buf)));
+            }
+
+            match self.last_split_position() {
+                Some(pos) => {
+                    let rest = self.buf.split_off(pos + 1);
+                    // The split is outside of quotes, so the scan state of the rest still holds.
+                    self.scanned -= pos + 1;
+                    self.last_split = None;
+                    return Ok(Some(std::mem::replace(&mut self.buf, rest)));
+                },
+                // A single line is longer than the chunk size, keep reading.
+                None => self.fill()?,
+            }
+        }
+    }
+
+    fn fill(&mut self) -> PolarsResult<()> {
+        match &mut self.source {
+            ChunkSource::Reader(reader) => {
+                let n = reader
+                    .by_ref()
+                    .take(self.chunk_size as u64)
+                    .read_to_end(&mut self.buf)
+                    .map_err(to_compute_err)?;
+                self.exhausted = n == 0;
+            },
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            ChunkSource::ZstdFrames { bytes, offset } => {
+                let n_frames = polars_core::POOL.current_num_threads();
+                let mut frames = Vec::with_capacity(n_frames);
+                while frames.len() < n_frames && *offset < bytes.len() {
+                    let size = zstd_frame_size(&bytes[*offset..])?;
+                    frames.push(&bytes[*offset..*offset + size]);
+                    *offset += size;
+                }
+                self.exhausted = frames.is_empty();
+
+                use rayon::prelude::*;
+                let decoded = polars_core::POOL.install(|| {
+                    frames
+                        .into_par_iter()
+                        .map(|frame| zstd::stream::decode_all(frame).map_err(to_compute_err))
+                        .collect::<PolarsResult<Vec<_>>>()
+                })?;
+                for d in decoded {
+                    self.buf.extend_from_slice(&d);
+                }
+            },
+        }
+        Ok(())
+    }
+
+    fn last_split_position(&mut self) -> Option<usize> {
+        let offset = self.scanned;
+        let new = &self.buf[offset..];
+        match self.quote_char {
+            None => {
+                if let Some(i) = memchr::memrchr(self.eol_char, new) {
+                    self.last_split = Some(offset + i);
+                }
+            },
+            Some(quote_char) => {
+                for (i, &b) in new.iter().enumerate() {
+                    if b == quote_char {
+                        self.in_quotes = !self.in_quotes;
+                    } else if b == self.eol_char && !self.in_quotes {
+                        self.last_split = Some(offset + i);
+                    }
+                }
+            },
+        }
+        self.scanned = self.buf.len();
+        self.last_split
+    }
+}
+
+/// Size of the (possibly skippable) zstd frame at the start of `bytes`.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+fn zstd_frame_size(bytes: &[u8]) -> PolarsResult<usize> {
+    zstd::zstd_safe::find_frame_compressed_size(bytes).map_err(|code| {
+        polars_err!(
+            ComputeError: "invalid zstd frame: {}",
+            zstd::zstd_safe::get_error_name(code)
+        )
+    })
+}
+
+/// Compression codec for text based output formats (CSV, JSON).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextCompression {
+    /// Gzip with an optional compression level in `0..=9`, defaults to 6.
+    Gzip(Option<u32>),
+    /// Zstd with an optional compression level in `1..=22`, defaults to 3.
+    Zstd(Option<i32>),
+}
+
+impl TextCompression {
+    fn check_level(&self) -> PolarsResult<()> {
+        match *self {
+            Self::Gzip(Some(level)) => polars_ensure!(
+                level <= 9,
+                InvalidOperation: "invalid gzip compression level {}, expected a value in 0..=9", level
+            ),
+            Self::Zstd(Some(level)) => polars_ensure!(
+                (1..=22).contains(&level),
+                InvalidOperation: "invalid zstd compression level {}, expected a value in 1..=22", level
+            ),
+            _ => {},
+        }
+        Ok(())
+    }
+}
+
+/// A writer that compresses everything that is written to it if a [`TextCompression`] is given.
+///
+/// [`MaybeCompressedWriter::finish`] must be called to write the trailer of the compressed stream.
+pub enum MaybeCompressedWriter<W: Write> {
+    Uncompressed(W),
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> MaybeCompressedWriter<W> {
+    pub fn new(writer: W, compression: Option<TextCompression>) -> PolarsResult<Self> {
+        let Some(compression) = compression else {
+            return Ok(Self::Uncompressed(writer));
+        };
+        compression.check_level()?;
+
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        {
+            Ok(match compression {
+                TextCompression::Gzip(level) => Self::Gzip(flate2::write::GzEncoder::new(
+                    writer,
+                    level.map_or_else(flate2::Compression::default, flate2::Compression::new),
+                )),
+                TextCompression::Zstd(level) => Self::Zstd(zstd::Encoder::new(
+                    writer,
+                    level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL),
+                )?),
+            })
+        }
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        {
+            polars_bail!(
+                ComputeError: "cannot compress without 'decompress' or 'decompress-fast' feature"
+            )
+        }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        match self {
+            Self::Uncompressed(w) => w,
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Gzip(w) => w.get_ref(),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Zstd(w) => w.get_ref(),
+        }
+    }
+
+    /// Write the trailer of the compressed stream and flush the inner writer.
+    ///
+    /// Nothing may be written after this has been called.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        match self {
+            Self::Uncompressed(w) => w.flush()?,
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Gzip(w) => {
+                w.try_finish()?;
+                w.get_mut().flush()?;
+            },
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Zstd(w) => {
+                w.do_finish()?;
+                w.get_mut().flush()?;
+            },
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for MaybeCompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Uncompressed(w) => w.write(buf),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Gzip(w) => w.write(buf),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Zstd(w) => w.write(buf),
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        match self {
+            Self::Uncompressed(w) => w.write_all(buf),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Gzip(w) => w.write_all(buf),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Zstd(w) => w.write_all(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Uncompressed(w) => w.flush(),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Gzip(w) => w.flush(),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Zstd(w) => w.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn collect_chunks(bytes: &[u8], chunk_size: usize, quote_char: Option<u8>) -> Vec<Vec<u8>> {
+        let mut chunks = DecompressedChunks::try_new(bytes, chunk_size, b'\n', quote_char)
+            .unwrap()
+            .unwrap();
+        let mut out = vec![];
+        while let Some(chunk) = chunks.next_chunk().unwrap() {
+            out.push(chunk);
+        }
+        out
+    }
+
+    #[test]
+    fn test_decompressed_chunks_are_line_aligned() {
+        let mut data = String::new();
+        for i in 0..1000 {
+            data += &format!("{i},\"a\nb\",{}\n", i * 2);
+        }
+
+        let mut gz = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        gz.write_all(data.as_bytes()).unwrap();
+        let gz = gz.finish().unwrap();
+
+        let chunks = collect_chunks(&gz, 100, Some(b'"'));
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert_eq!(*chunk.last().unwrap(), b'\n');
+            assert_eq!(chunk.iter().filter(|b| **b == b'"').count() % 2, 0);
+        }
+        assert_eq!(chunks.concat(), data.as_bytes());
+    }
+
+    #[test]
+    fn test_decompressed_chunks_long_quoted_field() {
+        // The quoted field spans many refills and contains line breaks.
+        let long = "x\n".repeat(5000);
+        let data = format!("a,b\n1,\"{long}\"\n2,c\n");
+
+        let mut gz = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        gz.write_all(data.as_bytes()).unwrap();
+        let gz = gz.finish().unwrap();
+
+        let chunks = collect_chunks(&gz, 64, Some(b'"'));
+        for chunk in &chunks {
+            assert_eq!(chunk.iter().filter(|b| **b == b'"').count() % 2, 0);
+        }
+        assert!(chunks.iter().any(|chunk| chunk.len() > long.len()));
+        assert_eq!(chunks.concat(), data.as_bytes());
+    }
+
+    #[test]
+    fn test_decompressed_chunks_zstd_multi_frame() {
+        let mut data = String::new();
+        for i in 0..1000 {
+            data += &format!("{i}\n");
+        }
+        let (a, b) = data.as_bytes().split_at(1234);
+
+        let mut zst = zstd::encode_all(a, 0).unwrap();
+        zst.extend(zstd::encode_all(b, 0).unwrap());
+
+        assert_eq!(collect_chunks(&zst, 64, None).concat(), data.as_bytes());
+    }
+
+    #[test]
+    fn test_check_bzip2_xz() {
+        let mut bz = bzip2::write::BzEncoder::new(vec![], bzip2::Compression::default());
+        bz.write_all(b"a,b\n1,2\n").unwrap();
+        let bz = bz.finish().unwrap();
+
+        let mut xz = xz2::write::XzEncoder::new(vec![], 6);
+        xz.write_all(b"a,b\n1,2\n").unwrap();
+        let xz = xz.finish().unwrap();
+
+        for bytes in [bz, xz] {
+            let mut out = vec![];
+            assert_eq!(
+                maybe_decompress_bytes(&bytes, &mut out).unwrap(),
+                b"a,b\n1,2\n"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compressed_writer_roundtrip() {
+        for compression in [TextCompression::Gzip(Some(9)), TextCompression::Zstd(None)] {
+            let mut writer = MaybeCompressedWriter::new(vec![], Some(compression)).unwrap();
+            writer.write_all(b"a,b\n").unwrap();
+            writer.write_all(b"1,2\n").unwrap();
+            writer.finish().unwrap();
+
+            let mut out = vec![];
+            assert_eq!(
+                maybe_decompress_bytes(writer.get_ref(), &mut out).unwrap(),
+                b"a,b\n1,2\n"
+            );
+        }
+    }
+}
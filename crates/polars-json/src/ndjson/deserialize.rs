@@ -59,3 +59,253 @@ pub fn deserialize_iter<'a>(
         concatenate_owned_unchecked(&arr)
     }
 }
+
+/// The ways in which a row of an NDJSON file can violate a schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaViolationKind {
+    /// The row has a field that is not in the schema.
+    UnknownField,
+    /// A non-nullable field is missing or `null`.
+    MissingField,
+    /// The JSON value cannot be represented by the data type of the field.
+    TypeMismatch {
+        expected: ArrowDataType,
+        found: &'static str,
+    },
+}
+
+/// A violation of a schema by a row of an NDJSON file, as found by [`validate_iter`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// The 1-based line number of the row.
+    pub line: usize,
+    /// The path of the offending field, e.g. `a.b[2]`. Empty for the row itself.
+    pub path: String,
+    pub kind: SchemaViolationKind,
+}
+
+impl std::fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = if self.path.is_empty() {
+            "row"
+        } else {
+            self.path.as_str()
+        };
+        match &self.kind {
+            SchemaViolationKind::UnknownField => {
+                write!(f, "line {}: unknown field '{}'", self.line, path)
+            },
+            SchemaViolationKind::MissingField => {
+                write!(f, "line {}: missing required field '{}'", self.line, path)
+            },
+            SchemaViolationKind::TypeMismatch { expected, found } => write!(
+                f,
+                "line {}: '{}' has type {:?}, got {}",
+                self.line, path, expected, found
+            ),
+        }
+    }
+}
+
+/// The maximum number of violations that are listed in the error of [`check_violations`].
+const MAX_REPORTED_VIOLATIONS: usize = 10;
+
+/// Returns an error that lists the `violations`, if there are any.
+pub fn check_violations(violations: &[SchemaViolation]) -> PolarsResult<()> {
+    if violations.is_empty() {
+        return Ok(());
+    }
+    let mut msg = String::from("NDJSON does not match the schema:");
+    for v in violations.iter().take(MAX_REPORTED_VIOLATIONS) {
+        msg.push_str(&format!("\n  {v}"));
+    }
+    if violations.len() > MAX_REPORTED_VIOLATIONS {
+        msg.push_str(&format!(
+            "\n  ... and {} more violations",
+            violations.len() - MAX_REPORTED_VIOLATIONS
+        ));
+    }
+    polars_bail!(SchemaMismatch: "{}", msg)
+}
+
+/// Validates the rows of an NDJSON file against `dtype`, which usually is a
+/// [`ArrowDataType::Struct`] describing the fields of a row.
+///
+/// Unlike [`deserialize_iter`], which ignores unknown fields and turns values of the wrong
+/// type into nulls, this reports every unknown field, missing non-nullable field and type
+/// mismatch together with the line number of the row.
+///
+/// # Errors
+/// This function errors iff any of the rows is not a valid JSON.
+pub fn validate_iter<'a>(
+    rows: impl Iterator<Item = &'a str>,
+    dtype: &ArrowDataType,
+) -> PolarsResult<Vec<SchemaViolation>> {
+    let mut violations = vec![];
+    let mut scratch = vec![];
+    for (i, row) in rows.enumerate() {
+        if row.trim().is_empty() {
+            continue;
+        }
+        scratch.clear();
+        scratch.extend_from_slice(row.as_bytes());
+        let value = simd_json::to_borrowed_value(&mut scratch).map_err(
+            |e| polars_err!(ComputeError: "json parsing error at line {}: '{}'", i + 1, e),
+        )?;
+        validate_value(&value, dtype, i + 1, &mut violations);
+    }
+    Ok(violations)
+}
+
+/// Validates a single parsed row against `dtype` and appends the violations found to
+/// `violations`.
+pub fn validate_value(
+    value: &BorrowedValue,
+    dtype: &ArrowDataType,
+    line: usize,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    let mut path = String::new();
+    validate_impl(value, dtype, line, &mut path, violations)
+}
+
+fn json_type_name(value: &BorrowedValue) -> &'static str {
+    use simd_json::StaticNode;
+    match value {
+        BorrowedValue::Static(StaticNode::Null) => "null",
+        BorrowedValue::Static(StaticNode::Bool(_)) => "boolean",
+        BorrowedValue::Static(StaticNode::I64(_) | StaticNode::U64(_)) => "integer",
+        BorrowedValue::Static(StaticNode::F64(_)) => "float",
+        BorrowedValue::String(_) => "string",
+        BorrowedValue::Array(_) => "array",
+        BorrowedValue::Object(_) => "object",
+    }
+}
+
+/// Whether a JSON integer fits in an integer of `dtype`.
+fn int_in_range(dtype: &ArrowDataType, v: i128) -> bool {
+    use ArrowDataType::*;
+    let (min, max) = match dtype {
+        Int8 => (i8::MIN as i128, i8::MAX as i128),
+        Int16 => (i16::MIN as i128, i16::MAX as i128),
+        Int32 => (i32::MIN as i128, i32::MAX as i128),
+        UInt8 => (0, u8::MAX as i128),
+        UInt16 => (0, u16::MAX as i128),
+        UInt32 => (0, u32::MAX as i128),
+        UInt64 => (0, u64::MAX as i128),
+        _ => (i64::MIN as i128, i64::MAX as i128),
+    };
+    (min..=max).contains(&v)
+}
+
+fn validate_impl(
+    value: &BorrowedValue,
+    dtype: &ArrowDataType,
+    line: usize,
+    path: &mut String,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    use simd_json::StaticNode;
+    use ArrowDataType::*;
+
+    let dtype = dtype.to_logical_type();
+    let matches = match (dtype, value) {
+        // Nullability is checked by the parent.
+        (_, BorrowedValue::Static(StaticNode::Null)) => true,
+        (Boolean, BorrowedValue::Static(StaticNode::Bool(_))) => true,
+        (
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64,
+            BorrowedValue::Static(StaticNode::I64(v)),
+        ) => int_in_range(dtype, *v as i128),
+        (
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64,
+            BorrowedValue::Static(StaticNode::U64(v)),
+        ) => int_in_range(dtype, *v as i128),
+        (
+            Float16 | Float32 | Float64 | Decimal(_, _) | Decimal256(_, _),
+            BorrowedValue::Static(StaticNode::I64(_) | StaticNode::U64(_) | StaticNode::F64(_)),
+        ) => true,
+        (Decimal(_, _) | Decimal256(_, _), BorrowedValue::String(_)) => true,
+        // Temporal values are either strings to parse or their physical integer.
+        (
+            Date32 | Date64 | Time32(_) | Time64(_) | Timestamp(_, _) | Duration(_),
+            BorrowedValue::String(_)
+            | BorrowedValue::Static(StaticNode::I64(_) | StaticNode::U64(_)),
+        ) => true,
+        (
+            Utf8 | LargeUtf8 | Utf8View | Binary | LargeBinary | BinaryView | Dictionary(_, _, _),
+            BorrowedValue::String(_),
+        ) => true,
+        (List(inner) | LargeList(inner), BorrowedValue::Array(items)) => {
+            validate_items(items, &inner.dtype, line, path, violations);
+            true
+        },
+        (FixedSizeList(inner, size), BorrowedValue::Array(items)) => {
+            validate_items(items, &inner.dtype, line, path, violations);
+            items.len() == *size
+        },
+        (Struct(fields), BorrowedValue::Object(object)) => {
+            let len = path.len();
+            for field in fields {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(&field.name);
+                match object.get(field.name.as_str()) {
+                    None | Some(BorrowedValue::Static(StaticNode::Null)) if !field.is_nullable => {
+                        violations.push(SchemaViolation {
+                            line,
+                            path: path.clone(),
+                            kind: SchemaViolationKind::MissingField,
+                        })
+                    },
+                    Some(value) => validate_impl(value, &field.dtype, line, path, violations),
+                    None => {},
+                }
+                path.truncate(len);
+            }
+            for key in object.keys() {
+                if !fields.iter().any(|f| f.name.as_str() == key.as_ref()) {
+                    let path = if path.is_empty() {
+                        key.to_string()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    violations.push(SchemaViolation {
+                        line,
+                        path,
+                        kind: SchemaViolationKind::UnknownField,
+                    })
+                }
+            }
+            true
+        },
+        _ => false,
+    };
+
+    if !matches {
+        violations.push(SchemaViolation {
+            line,
+            path: path.clone(),
+            kind: SchemaViolationKind::TypeMismatch {
+                expected: dtype.clone(),
+                found: json_type_name(value),
+            },
+        })
+    }
+}
+
+fn validate_items(
+    items: &[BorrowedValue],
+    inner: &ArrowDataType,
+    line: usize,
+    path: &mut String,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    let len = path.len();
+    for (i, item) in items.iter().enumerate() {
+        path.push_str(&format!("[{i}]"));
+        validate_impl(item, inner, line, path, violations);
+        path.truncate(len);
+    }
+}
@@ -1,6 +1,8 @@
 pub(crate) use polars_expr::prelude::*;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
+#[cfg(feature = "flight")]
+pub use polars_io::flight::{FlightServer, FlightSource};
 #[cfg(feature = "ipc")]
 pub use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
@@ -8,7 +8,7 @@ use polars_io::csv::read::{
 use polars_io::mmap::ReaderBytes;
 use polars_io::path_utils::expand_paths;
 use polars_io::utils::get_reader_bytes;
-use polars_io::RowIndex;
+use polars_io::{FileFilterOptions, RowIndex};
 
 use crate::prelude::*;
 
@@ -21,6 +21,7 @@ pub struct LazyCsvReader {
     read_options: CsvReadOptions,
     cloud_options: Option<CloudOptions>,
     include_file_paths: Option<PlSmallStr>,
+    file_filter: FileFilterOptions,
 }
 
 #[cfg(feature = "csv")]
@@ -43,6 +44,7 @@ impl LazyCsvReader {
             read_options: Default::default(),
             cloud_options: Default::default(),
             include_file_paths: None,
+            file_filter: Default::default(),
         }
     }
 
@@ -257,7 +259,12 @@ impl LazyCsvReader {
             ScanSources::Paths(paths) => {
                 // TODO: Path expansion should happen when converting to the IR
                 // https://github.com/pola-rs/polars/issues/17634
-                let paths = expand_paths(&paths[..], self.glob(), self.cloud_options())?;
+                let paths = expand_paths(
+                    &paths[..],
+                    self.glob(),
+                    self.cloud_options(),
+                    &self.file_filter,
+                )?;
 
                 let Some(path) = paths.first() else {
                     polars_bail!(ComputeError: "no paths specified for this reader");
@@ -305,6 +312,12 @@ impl LazyCsvReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Filter the files that are found when the paths are expanded.
+    pub fn with_file_filter(mut self, file_filter: FileFilterOptions) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyCsvReader {
@@ -317,6 +330,7 @@ impl LazyFileListReader for LazyCsvReader {
             self.cloud_options,
             self.glob,
             self.include_file_paths,
+            self.file_filter,
         )?
         .build()
         .into();
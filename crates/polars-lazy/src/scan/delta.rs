@@ -0,0 +1,189 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::delta::{DeltaAddFile, DeltaTable};
+use polars_io::{HiveOptions, RowIndex};
+use polars_plan::utils::expr_to_leaf_column_names;
+
+use super::io_expr::to_io_expr;
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsDelta {
+    /// The version of the table that is read, the latest version if `None`.
+    pub version: Option<i64>,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub rechunk: bool,
+}
+
+/// Scans the data files of a version of a Delta table.
+///
+/// Data files are pruned with the pushed down predicate, using the statistics and partition
+/// values in the log. The remaining files are read with the Parquet scan, the partition columns
+/// are added from the partition values of the file.
+struct DeltaScan {
+    table: DeltaTable,
+    cloud_options: Option<CloudOptions>,
+    rechunk: bool,
+}
+
+impl DeltaScan {
+    /// Read a single data file, with the columns of the table.
+    fn scan_data_file(
+        &self,
+        file: &DeltaAddFile,
+        columns: &[PlSmallStr],
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.table.schema();
+        let args = ScanArgsParquet {
+            cloud_options: self.cloud_options.clone(),
+            hive_options: HiveOptions {
+                enabled: Some(false),
+                ..Default::default()
+            },
+            glob: false,
+            ..Default::default()
+        };
+        let mut lf = LazyFrame::scan_parquet(self.table.location(file), args)?;
+        let partition_values = self.table.partition_values(file)?;
+
+        // Columns that were added to the table after the file was written are null.
+        let file_schema = lf.collect_schema()?;
+        let mut exprs = columns
+            .iter()
+            .filter(|name| !self.table.partition_columns().contains(name))
+            .map(|name| {
+                let dtype = schema.try_get(name)?.clone();
+                Ok(if file_schema.contains(name) {
+                    col(name.clone()).cast(dtype)
+                } else {
+                    lit(Null {}).cast(dtype).alias(name.clone())
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        // A column of the file is needed for the number of rows if only partition columns are
+        // selected.
+        if exprs.is_empty() {
+            if let Some(name) = file_schema.iter_names().next() {
+                exprs.push(col(name.clone()));
+            }
+        }
+        let partition_exprs = partition_values
+            .into_iter()
+            .filter(|s| columns.contains(s.name()))
+            .map(|s| lit(s).first())
+            .collect::<Vec<_>>();
+
+        Ok(lf.select(exprs).with_columns(partition_exprs).select(
+            columns
+                .iter()
+                .map(|name| col(name.clone()))
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+impl AnonymousScan for DeltaScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let schema = self.table.schema();
+        let output_schema = scan_opts.output_schema.unwrap_or_else(|| schema.clone());
+
+        let predicate = scan_opts
+            .predicate
+            .as_ref()
+            .map(|predicate| to_io_expr(predicate, schema))
+            .transpose()?;
+
+        // Columns that the predicate needs are read as well, they are dropped after filtering.
+        let mut columns = output_schema.iter_names().cloned().collect::<Vec<_>>();
+        if let Some(predicate) = &scan_opts.predicate {
+            for name in expr_to_leaf_column_names(predicate) {
+                if !columns.contains(&name) && schema.contains(&name) {
+                    columns.push(name);
+                }
+            }
+        }
+
+        let mut frames = vec![];
+        for file in self.table.files() {
+            if let Some(predicate) = &predicate {
+                if !self.table.should_read(file, predicate.as_ref())? {
+                    continue;
+                }
+            }
+            let mut lf = self.scan_data_file(file, &columns)?;
+            if let Some(predicate) = &scan_opts.predicate {
+                lf = lf.filter(predicate.clone());
+            }
+            frames.push(
+                lf.select(
+                    output_schema
+                        .iter_names()
+                        .map(|name| col(name.clone()))
+                        .collect::<Vec<_>>(),
+                ),
+            );
+        }
+
+        if frames.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&output_schema));
+        }
+        let mut lf = concat(
+            frames,
+            UnionArgs {
+                rechunk: self.rechunk,
+                ..Default::default()
+            },
+        )?;
+        if let Some(n_rows) = scan_opts.n_rows {
+            lf = lf.limit(n_rows as IdxSize);
+        }
+        lf.collect()
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a version of a Delta Lake table.
+    ///
+    /// `path` is the root directory of the table, which contains the `_delta_log` directory.
+    /// Use [`DeltaWriter`](polars_io::delta::DeltaWriter) to append to a table.
+    pub fn scan_delta(path: impl AsRef<str>, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let table = DeltaTable::open(path.as_ref(), args.version, args.cloud_options.as_ref())?;
+        let schema = table.schema().clone();
+
+        let scan = DeltaScan {
+            table,
+            cloud_options: args.cloud_options,
+            rechunk: args.rechunk,
+        };
+        LazyFrame::anonymous_scan(
+            Arc::new(scan),
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                n_rows: args.n_rows,
+                row_index: args.row_index,
+                name: "DELTA SCAN",
+                ..Default::default()
+            },
+        )
+    }
+}
@@ -0,0 +1,137 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::csv::read::{FixedWidthColumn, FixedWidthReadOptions, NullValues};
+use polars_io::path_utils::resolve_homedir;
+use polars_io::{RowIndex, SerReader};
+
+use crate::prelude::*;
+
+/// Lazily read a fixed-width file.
+///
+/// Projections and slices are pushed down into the reader.
+#[derive(Clone)]
+#[must_use]
+pub struct LazyFixedWidthReader {
+    path: PathBuf,
+    options: FixedWidthReadOptions,
+    row_index: Option<RowIndex>,
+}
+
+impl LazyFixedWidthReader {
+    pub fn new(path: impl AsRef<Path>, columns: impl Into<Arc<[FixedWidthColumn]>>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            options: FixedWidthReadOptions::new(columns),
+            row_index: None,
+        }
+    }
+
+    /// Set the options of the underlying reader. The columns in `options` replace the columns
+    /// given in [`LazyFixedWidthReader::new`].
+    pub fn with_options(mut self, options: FixedWidthReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set whether the file has a header line that should be skipped.
+    pub fn with_has_header(mut self, has_header: bool) -> Self {
+        self.options.has_header = has_header;
+        self
+    }
+
+    /// Skip the first `n` lines of the file.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.options.skip_rows = skip_rows;
+        self
+    }
+
+    /// Try to stop parsing when `n` rows are parsed.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.options.n_rows = n_rows;
+        self
+    }
+
+    /// Set the number of rows to use when inferring the column types. Pass [None] to use all
+    /// rows.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.options.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null.
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.options.null_values = null_values;
+        self
+    }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Rechunk the memory to contiguous chunks when parsing is done.
+    pub fn with_rechunk(mut self, rechunk: bool) -> Self {
+        self.options.rechunk = rechunk;
+        self
+    }
+
+    pub fn finish(self) -> PolarsResult<LazyFrame> {
+        let n_rows = self.options.n_rows;
+        let scan = FixedWidthScan {
+            path: resolve_homedir(&self.path),
+            options: self.options,
+        };
+        let schema = scan.schema(None)?;
+
+        let args = ScanArgsAnonymous {
+            schema: Some(schema),
+            n_rows,
+            row_index: self.row_index,
+            name: "FIXED WIDTH SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(scan), args)
+    }
+}
+
+struct FixedWidthScan {
+    path: PathBuf,
+    options: FixedWidthReadOptions,
+}
+
+impl AnonymousScan for FixedWidthScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let n_rows = match (scan_opts.n_rows, self.options.n_rows) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.options
+            .clone()
+            .with_projection(scan_opts.with_columns)
+            .with_n_rows(n_rows)
+            .try_into_reader_with_file_path(Some(self.path.clone()))?
+            .finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        self.options
+            .clone()
+            .try_into_reader_with_file_path(Some(self.path.clone()))?
+            .schema()
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
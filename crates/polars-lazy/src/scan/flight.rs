@@ -0,0 +1,133 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_io::flight::{FlightClient, FlightSource};
+use polars_io::pl_async::get_runtime;
+use polars_io::RowIndex;
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsFlight {
+    /// The schema of the dataset, it is requested with `GetSchema` if `None`.
+    pub schema: Option<SchemaRef>,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+}
+
+/// Reads a dataset of an Arrow Flight service with `DoGet`.
+///
+/// The service only knows the ticket of the dataset, so projections and slices are applied
+/// after the whole dataset is received.
+struct FlightScan {
+    uri: String,
+    ticket: Vec<u8>,
+}
+
+impl AnonymousScan for FlightScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut df = get_runtime().block_on_potential_spawn(async {
+            let mut client = FlightClient::connect(&self.uri).await?;
+            client.do_get(&self.ticket).await
+        })?;
+        if let Some(columns) = &scan_opts.with_columns {
+            df = df.select(columns.iter().cloned())?;
+        }
+        if let Some(n_rows) = scan_opts.n_rows {
+            df = df.head(Some(n_rows));
+        }
+        Ok(df)
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from the dataset of `ticket` of the Arrow Flight service at `uri`.
+    ///
+    /// The dataset is read with `DoGet` when the query is executed. Use a
+    /// [`FlightServer`](polars_io::flight::FlightServer) to serve frames to other processes.
+    pub fn scan_flight(
+        uri: impl Into<String>,
+        ticket: impl Into<Vec<u8>>,
+        args: ScanArgsFlight,
+    ) -> PolarsResult<Self> {
+        let uri = uri.into();
+        let ticket = ticket.into();
+        let schema = match args.schema {
+            Some(schema) => schema,
+            None => get_runtime().block_on_potential_spawn(async {
+                let mut client = FlightClient::connect(&uri).await?;
+                client.get_schema(&ticket).await
+            })?,
+        };
+
+        LazyFrame::anonymous_scan(
+            Arc::new(FlightScan { uri, ticket }),
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                n_rows: args.n_rows,
+                row_index: args.row_index,
+                name: "FLIGHT SCAN",
+                ..Default::default()
+            },
+        )
+    }
+}
+
+/// Named [`LazyFrame`]s that are served by a [`FlightServer`](polars_io::flight::FlightServer).
+///
+/// The ticket of a frame is its name. Frames are collected for every `DoGet`, so they are served
+/// with the data of their sources at the time of the request.
+#[derive(Clone, Default)]
+pub struct FlightTables {
+    tables: PlHashMap<String, LazyFrame>,
+}
+
+impl FlightTables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a [`LazyFrame`] with `name` as its ticket.
+    pub fn register(&mut self, name: &str, lf: LazyFrame) {
+        self.tables.insert(name.to_owned(), lf);
+    }
+
+    fn get(&self, name: &str) -> PolarsResult<LazyFrame> {
+        self.tables
+            .get(name)
+            .cloned()
+            .ok_or_else(|| polars_err!(ComputeError: "no flight table named '{}'", name))
+    }
+}
+
+impl FromIterator<(String, LazyFrame)> for FlightTables {
+    fn from_iter<I: IntoIterator<Item = (String, LazyFrame)>>(iter: I) -> Self {
+        Self {
+            tables: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl FlightSource for FlightTables {
+    fn tickets(&self) -> Vec<String> {
+        let mut names = self.tables.keys().cloned().collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    fn schema(&self, ticket: &str) -> PolarsResult<SchemaRef> {
+        self.get(ticket)?.collect_schema()
+    }
+
+    fn collect(&self, ticket: &str) -> PolarsResult<DataFrame> {
+        self.get(ticket)?.collect()
+    }
+}
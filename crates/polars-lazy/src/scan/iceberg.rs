@@ -0,0 +1,252 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::iceberg::{
+    IcebergDataFile, IcebergScanFiles, IcebergSnapshot, IcebergSnapshotSelector, IcebergTable,
+};
+use polars_io::{HiveOptions, RowIndex};
+use polars_plan::utils::expr_to_leaf_column_names;
+
+use super::io_expr::to_io_expr;
+use crate::prelude::*;
+
+/// Name of the row index that position deletes are matched on.
+const POSITION_COLUMN: &str = "__POLARS_ICEBERG_POS";
+
+#[derive(Clone, Default)]
+pub struct ScanArgsIceberg {
+    /// The snapshot of the table that is read.
+    pub snapshot: IcebergSnapshotSelector,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub rechunk: bool,
+}
+
+/// Scans the data files of a snapshot of an Iceberg table.
+///
+/// The manifests are read when the scan is executed. Data files are pruned with the pushed down
+/// predicate, using the column bounds and partition values in the manifests. The remaining files
+/// are read with the Parquet scan, their columns are matched by field id.
+struct IcebergScan {
+    table: IcebergTable,
+    snapshot: Option<IcebergSnapshot>,
+    cloud_options: Option<CloudOptions>,
+    rechunk: bool,
+}
+
+impl IcebergScan {
+    fn scan_parquet(&self, file: &IcebergDataFile) -> PolarsResult<LazyFrame> {
+        let args = ScanArgsParquet {
+            cloud_options: self.cloud_options.clone(),
+            hive_options: HiveOptions {
+                enabled: Some(false),
+                ..Default::default()
+            },
+            glob: false,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(IcebergScanFiles::location(file), args)
+    }
+
+    /// Select columns of the table from a data or delete file, cast to their type in the table.
+    ///
+    /// Columns that were renamed after the file was written are read by their old name, columns
+    /// that were added after the file was written are null.
+    fn select_table_columns<'a>(
+        files: &IcebergScanFiles,
+        file: &IcebergDataFile,
+        mut lf: LazyFrame,
+        names: impl IntoIterator<Item = &'a PlSmallStr>,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = files.schema();
+        let file_schema = lf.collect_schema()?;
+        let exprs = names
+            .into_iter()
+            .map(|name| {
+                let dtype = schema.try_get(name)?.clone();
+                Ok(match files.file_column_name(file, name)? {
+                    Some(file_name) if file_schema.contains(file_name) => {
+                        col(file_name.clone()).cast(dtype).alias(name.clone())
+                    },
+                    // The column is in the schema the file was written with, reading it as null
+                    // would hide that the file doesn't match its schema.
+                    Some(file_name) if file.schema_id.is_some() => polars_bail!(
+                        ColumnNotFound: "column '{}' of the iceberg table is missing in {}, expected it as '{}'",
+                        name, file.path, file_name
+                    ),
+                    _ => lit(Null {}).cast(dtype).alias(name.clone()),
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(lf.select(exprs))
+    }
+
+    /// Read a single data file with the columns of the table, without the rows that are deleted
+    /// by the delete files of the snapshot.
+    fn scan_data_file(
+        &self,
+        files: &IcebergScanFiles,
+        file: &IcebergDataFile,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = files.schema();
+        // Unused columns are dropped by projection pushdown.
+        let mut lf =
+            Self::select_table_columns(files, file, self.scan_parquet(file)?, schema.iter_names())?;
+
+        let position_deletes = files
+            .position_deletes(file)
+            .map(|delete| {
+                Ok(self
+                    .scan_parquet(delete)?
+                    .filter(col("file_path").eq(lit(file.path.as_str())))
+                    .select([col("pos").cast(IDX_DTYPE).alias(POSITION_COLUMN)]))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if !position_deletes.is_empty() {
+            let deletes = concat(position_deletes, UnionArgs::default())?;
+            lf = lf
+                .with_row_index(POSITION_COLUMN, None)
+                .join_builder()
+                .with(deletes)
+                .on([col(POSITION_COLUMN)])
+                .how(JoinType::Anti)
+                .finish();
+        }
+
+        for delete in files.equality_deletes(file) {
+            let on = delete
+                .equality_columns
+                .iter()
+                .map(|name| col(name.clone()))
+                .collect::<Vec<_>>();
+            let deletes = Self::select_table_columns(
+                files,
+                delete,
+                self.scan_parquet(delete)?,
+                &delete.equality_columns,
+            )?;
+            lf = lf
+                .join_builder()
+                .with(deletes)
+                .on(on)
+                .how(JoinType::Anti)
+                .join_nulls(true)
+                .finish();
+        }
+
+        Ok(lf)
+    }
+}
+
+impl AnonymousScan for IcebergScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let files = self.table.scan_files(self.snapshot.as_ref())?;
+        let schema = files.schema();
+        let output_schema = scan_opts.output_schema.unwrap_or_else(|| schema.clone());
+
+        let predicate = scan_opts
+            .predicate
+            .as_ref()
+            .map(|predicate| to_io_expr(predicate, schema))
+            .transpose()?;
+
+        // Columns that the predicate needs are read as well, they are dropped after filtering.
+        let mut columns = output_schema.iter_names().cloned().collect::<Vec<_>>();
+        if let Some(predicate) = &scan_opts.predicate {
+            for name in expr_to_leaf_column_names(predicate) {
+                if !columns.contains(&name) && schema.contains(&name) {
+                    columns.push(name);
+                }
+            }
+        }
+
+        let mut frames = vec![];
+        for file in files.data_files() {
+            if let Some(predicate) = &predicate {
+                if !files.should_read(file, predicate.as_ref())? {
+                    continue;
+                }
+            }
+            let mut lf = self.scan_data_file(&files, file)?.select(
+                columns
+                    .iter()
+                    .map(|name| col(name.clone()))
+                    .collect::<Vec<_>>(),
+            );
+
+            if let Some(predicate) = &scan_opts.predicate {
+                lf = lf.filter(predicate.clone());
+            }
+            frames.push(
+                lf.select(
+                    output_schema
+                        .iter_names()
+                        .map(|name| col(name.clone()))
+                        .collect::<Vec<_>>(),
+                ),
+            );
+        }
+
+        if frames.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&output_schema));
+        }
+        let mut lf = concat(
+            frames,
+            UnionArgs {
+                rechunk: self.rechunk,
+                ..Default::default()
+            },
+        )?;
+        if let Some(n_rows) = scan_opts.n_rows {
+            lf = lf.limit(n_rows as IdxSize);
+        }
+        lf.collect()
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a snapshot of an Apache Iceberg table.
+    ///
+    /// `path` is either the root directory of the table or one of its `*.metadata.json` files.
+    /// Position and equality delete files are applied to the data files they refer to.
+    pub fn scan_iceberg(path: impl AsRef<str>, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let table = IcebergTable::open(path.as_ref(), args.cloud_options.as_ref())?;
+        let snapshot = table.snapshot(args.snapshot)?.cloned();
+        let schema = Arc::new(table.schema(snapshot.as_ref())?);
+
+        let scan = IcebergScan {
+            table,
+            snapshot,
+            cloud_options: args.cloud_options,
+            rechunk: args.rechunk,
+        };
+        LazyFrame::anonymous_scan(
+            Arc::new(scan),
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                n_rows: args.n_rows,
+                row_index: args.row_index,
+                name: "ICEBERG SCAN",
+                ..Default::default()
+            },
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use polars_core::prelude::*;
+use polars_expr::prelude::phys_expr_to_io_expr;
+use polars_expr::{create_physical_expr, ExpressionConversionState};
+use polars_io::predicates::PhysicalIoExpr;
+
+use crate::prelude::*;
+
+/// Convert a pushed down predicate to an expression that can be evaluated on file statistics.
+pub(super) fn to_io_expr(
+    predicate: &Expr,
+    schema: &SchemaRef,
+) -> PolarsResult<Arc<dyn PhysicalIoExpr>> {
+    let mut lp_arena = Arena::with_capacity(8);
+    let mut expr_arena = Arena::with_capacity(10);
+
+    // Run type coercion on a dummy frame with the schema of the table. The predicate is selected
+    // rather than filtered on, as filters on conjunctions are split into multiple nodes.
+    let lf = DataFrame::empty_with_schema(schema)
+        .lazy()
+        .without_optimizations()
+        .with_simplify_expr(true)
+        .select([predicate.clone()]);
+    let optimized = lf.optimize(&mut lp_arena, &mut expr_arena)?;
+    let lp = lp_arena.get(optimized);
+    let aexpr = lp.get_exprs().pop().unwrap();
+
+    let phys_expr = create_physical_expr(
+        &aexpr,
+        Context::Default,
+        &expr_arena,
+        Some(schema),
+        &mut ExpressionConversionState::new(true, 0),
+    )?;
+    Ok(phys_expr_to_io_expr(phys_expr))
+}
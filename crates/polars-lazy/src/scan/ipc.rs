@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::ipc::IpcScanOptions;
-use polars_io::{HiveOptions, RowIndex};
+use polars_io::{FileFilterOptions, HiveOptions, RowIndex};
 
 use crate::prelude::*;
 
@@ -16,6 +16,8 @@ pub struct ScanArgsIpc {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Filters on the files that are found when the paths are expanded.
+    pub file_filter: FileFilterOptions,
 }
 
 impl Default for ScanArgsIpc {
@@ -28,6 +30,7 @@ impl Default for ScanArgsIpc {
             cloud_options: Default::default(),
             hive_options: Default::default(),
             include_file_paths: None,
+            file_filter: Default::default(),
         }
     }
 }
@@ -63,6 +66,7 @@ impl LazyFileListReader for LazyIpcReader {
             args.cloud_options,
             args.hive_options,
             args.include_file_paths,
+            args.file_filter,
         )?
         .build()
         .into();
@@ -0,0 +1,146 @@
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::ipc::IpcStreamScanOptions;
+use polars_io::{FileFilterOptions, RowIndex};
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsIpcStream {
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub include_file_paths: Option<PlSmallStr>,
+    /// Filters on the files that are found when the paths are expanded.
+    pub file_filter: FileFilterOptions,
+}
+
+impl Default for ScanArgsIpcStream {
+    fn default() -> Self {
+        Self {
+            n_rows: None,
+            cache: true,
+            rechunk: false,
+            row_index: None,
+            cloud_options: Default::default(),
+            include_file_paths: None,
+            file_filter: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct LazyIpcStreamReader {
+    args: ScanArgsIpcStream,
+    sources: ScanSources,
+}
+
+impl LazyIpcStreamReader {
+    fn new(args: ScanArgsIpcStream) -> Self {
+        Self {
+            args,
+            sources: ScanSources::default(),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyIpcStreamReader {
+    fn finish(self) -> PolarsResult<LazyFrame> {
+        let args = self.args;
+
+        let mut lf: LazyFrame = DslBuilder::scan_ipc_stream(
+            self.sources,
+            IpcStreamScanOptions,
+            args.n_rows,
+            args.cache,
+            args.row_index,
+            args.rechunk,
+            args.cloud_options,
+            args.include_file_paths,
+            args.file_filter,
+        )?
+        .build()
+        .into();
+        lf.opt_state |= OptFlags::FILE_CACHING;
+
+        Ok(lf)
+    }
+
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        unreachable!()
+    }
+
+    fn sources(&self) -> &ScanSources {
+        &self.sources
+    }
+
+    fn with_sources(mut self, sources: ScanSources) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+
+    /// [CloudOptions] used to list files.
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.args.cloud_options.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from a scan of Arrow IPC stream files.
+    ///
+    /// Streams that are concatenated in a file, e.g. the output of a producer that writes a new
+    /// stream per flush, are read as one stream if their schemas are equal.
+    pub fn scan_ipc_stream(path: impl AsRef<Path>, args: ScanArgsIpcStream) -> PolarsResult<Self> {
+        Self::scan_ipc_stream_sources(
+            ScanSources::Paths([path.as_ref().to_path_buf()].into()),
+            args,
+        )
+    }
+
+    pub fn scan_ipc_stream_files(
+        paths: Arc<[PathBuf]>,
+        args: ScanArgsIpcStream,
+    ) -> PolarsResult<Self> {
+        Self::scan_ipc_stream_sources(ScanSources::Paths(paths), args)
+    }
+
+    pub fn scan_ipc_stream_sources(
+        sources: ScanSources,
+        args: ScanArgsIpcStream,
+    ) -> PolarsResult<Self> {
+        LazyIpcStreamReader::new(args)
+            .with_sources(sources)
+            .finish()
+    }
+}
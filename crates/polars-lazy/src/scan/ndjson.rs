@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
-use polars_io::{HiveOptions, RowIndex};
+use polars_io::{FileFilterOptions, HiveOptions, RowIndex};
 use polars_plan::plans::{DslPlan, FileScan, ScanSources};
 use polars_plan::prelude::{FileScanOptions, NDJsonReadOptions};
 
@@ -21,10 +21,14 @@ pub struct LazyJsonLineReader {
     pub(crate) schema_overwrite: Option<SchemaRef>,
     pub(crate) row_index: Option<RowIndex>,
     pub(crate) infer_schema_length: Option<NonZeroUsize>,
+    pub(crate) infer_schema_all_files: bool,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
+    pub(crate) strict: bool,
+    pub(crate) required_fields: Option<Arc<[PlSmallStr]>>,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) file_filter: FileFilterOptions,
 }
 
 impl LazyJsonLineReader {
@@ -42,10 +46,14 @@ impl LazyJsonLineReader {
             schema_overwrite: None,
             row_index: None,
             infer_schema_length: NonZeroUsize::new(100),
+            infer_schema_all_files: false,
             ignore_errors: false,
+            strict: false,
+            required_fields: None,
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
+            file_filter: Default::default(),
         }
     }
 
@@ -66,6 +74,19 @@ impl LazyJsonLineReader {
         self.ignore_errors = ignore_errors;
         self
     }
+    /// Validate every row against the schema and raise an error that lists all unknown fields,
+    /// missing required fields and type mismatches with their line numbers.
+    #[must_use]
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+    /// Fields that every row must have with a non-null value, checked in strict mode.
+    #[must_use]
+    pub fn with_required_fields(mut self, required_fields: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.required_fields = required_fields;
+        self
+    }
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -82,6 +103,18 @@ impl LazyJsonLineReader {
         self.infer_schema_length = num_rows;
         self
     }
+
+    /// Infer the schema from all rows of all files of the scan instead of the first rows of the
+    /// first file. The inferred types of the files are merged: new struct fields are added and
+    /// conflicting types are widened to their supertype.
+    ///
+    /// This reads every file completely, [`Self::with_infer_schema_length`] is ignored.
+    /// Ignored when the schema is specified explicitly using [`Self::with_schema`].
+    #[must_use]
+    pub fn with_infer_schema_all_files(mut self, toggle: bool) -> Self {
+        self.infer_schema_all_files = toggle;
+        self
+    }
     /// Set the JSON file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -118,6 +151,12 @@ impl LazyJsonLineReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Filter the files that are found when the paths are expanded.
+    pub fn with_file_filter(mut self, file_filter: FileFilterOptions) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
@@ -138,14 +177,18 @@ impl LazyFileListReader for LazyJsonLineReader {
             glob: true,
             include_file_paths: self.include_file_paths,
             allow_missing_columns: false,
+            file_filter: self.file_filter,
         };
 
         let options = NDJsonReadOptions {
             n_threads: None,
             infer_schema_length: self.infer_schema_length,
+            infer_schema_all_files: self.infer_schema_all_files,
             chunk_size: NonZeroUsize::new(1 << 18).unwrap(),
             low_memory: self.low_memory,
             ignore_errors: self.ignore_errors,
+            strict: self.strict,
+            required_fields: self.required_fields,
             schema: self.schema,
             schema_overwrite: self.schema_overwrite,
         };
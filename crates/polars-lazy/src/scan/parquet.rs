@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
-use polars_io::{HiveOptions, RowIndex};
+use polars_io::{FileFilterOptions, HiveOptions, RowIndex};
 
 use crate::prelude::*;
 
@@ -23,6 +23,11 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Cast files whose columns changed dtype, e.g. from `Int32` to `Int64`, to the supertype of
+    /// the dtypes of all files.
+    pub schema_evolution: bool,
+    /// Filters on the files that are found when the paths are expanded.
+    pub file_filter: FileFilterOptions,
 }
 
 impl Default for ScanArgsParquet {
@@ -41,6 +46,8 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            schema_evolution: false,
+            file_filter: Default::default(),
         }
     }
 }
@@ -80,6 +87,8 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.glob,
             self.args.include_file_paths,
             self.args.allow_missing_columns,
+            self.args.schema_evolution,
+            self.args.file_filter,
         )?
         .build()
         .into();
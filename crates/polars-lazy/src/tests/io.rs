@@ -420,6 +420,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             cloud_options: None,
             hive_options: Default::default(),
             include_file_paths: None,
+            file_filter: Default::default(),
         },
     )?
     .collect()?;
@@ -711,3 +712,790 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+fn scan_fixed_width() -> PolarsResult<()> {
+    use polars_io::csv::read::FixedWidthColumn;
+
+    let path = std::env::temp_dir().join("polars_scan_fixed_width.txt");
+    std::fs::write(&path, "a    b\n1    x\n22   y\n333  z\n").unwrap();
+
+    let df = LazyFixedWidthReader::new(&path, FixedWidthColumn::from_widths([("a", 5), ("b", 1)]))
+        .with_has_header(true)
+        .finish()?
+        .select([col("a") * lit(2)])
+        .limit(2)
+        .collect()?;
+
+    assert_eq!(df.shape(), (2, 1));
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(2), Some(44)]);
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn scan_ndjson_strict_required_fields() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_scan_ndjson_strict_required_fields.ndjson");
+    std::fs::write(
+        &path,
+        "{\"a\": 1, \"b\": \"x\"}\n{\"b\": \"y\"}\n{\"a\": 3}\n",
+    )
+    .unwrap();
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]));
+    let scan = |required_fields: &[&str]| {
+        LazyJsonLineReader::new(&path)
+            .with_schema(Some(schema.clone()))
+            .with_strict(true)
+            .with_required_fields(Some(required_fields.iter().map(|&f| f.into()).collect()))
+            .finish()?
+            .collect()
+    };
+
+    // Fields are optional unless they are required.
+    assert_eq!(scan(&[])?.shape(), (3, 2));
+
+    let err = scan(&["a"]).unwrap_err().to_string();
+    assert!(err.contains("line 2: missing required field 'a'"), "{err}");
+    assert!(!err.contains("line 3"), "{err}");
+
+    assert!(scan(&["c"]).is_err());
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn scan_ndjson_infer_schema_all_files() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_scan_ndjson_infer_schema_all_files");
+    std::fs::create_dir_all(&dir).unwrap();
+    let a = dir.join("a.ndjson");
+    let b = dir.join("b.ndjson");
+    std::fs::write(&a, "{\"id\": 1, \"payload\": {\"x\": 1}}\n").unwrap();
+    // The new fields appear after the rows that are inferred by default.
+    let mut lines = "{\"id\": 2, \"payload\": {\"x\": 2}}\n".repeat(150);
+    lines.push_str("{\"id\": 3, \"payload\": {\"x\": 2.5, \"y\": \"new\"}, \"extra\": true}\n");
+    std::fs::write(&b, lines).unwrap();
+
+    let df = LazyJsonLineReader::new_paths([a, b].into())
+        .with_infer_schema_length(std::num::NonZeroUsize::new(100))
+        .with_infer_schema_all_files(true)
+        .finish()?
+        .collect()?;
+
+    let expected = Schema::from_iter([
+        Field::new("id".into(), DataType::Int64),
+        Field::new(
+            "payload".into(),
+            DataType::Struct(vec![
+                Field::new("x".into(), DataType::Float64),
+                Field::new("y".into(), DataType::String),
+            ]),
+        ),
+        Field::new("extra".into(), DataType::Boolean),
+    ]);
+    assert_eq!(df.schema(), expected);
+    assert_eq!(df.column("extra")?.null_count(), 151);
+    std::fs::remove_dir_all(&dir).unwrap();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "iceberg")]
+fn scan_iceberg() -> PolarsResult<()> {
+    use polars_io::avro::AvroWriter;
+    use polars_io::iceberg::IcebergSnapshotSelector;
+    use polars_io::SerWriter;
+
+    let dir = std::env::temp_dir().join("polars_scan_iceberg");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("metadata")).unwrap();
+    std::fs::create_dir_all(dir.join("data")).unwrap();
+    let location = |name: &str| dir.join(name).to_str().unwrap().to_string();
+
+    let write_parquet = |name: &str, mut df: DataFrame| {
+        let f = std::fs::File::create(location(name)).unwrap();
+        ParquetWriter::new(f).finish(&mut df).unwrap();
+    };
+    write_parquet(
+        "data/a.parquet",
+        df!["id" => [1i64, 2, 3], "name" => ["a", "b", "c"]]?,
+    );
+    write_parquet(
+        "data/b.parquet",
+        df!["id" => [10i64, 11, 12], "name" => ["x", "y", "z"]]?,
+    );
+    // Deletes the second row of `a.parquet`.
+    write_parquet(
+        "data/pos-delete.parquet",
+        df!["file_path" => [location("data/a.parquet")], "pos" => [1i64]]?,
+    );
+    // Only applies to `a.parquet`, `b.parquet` is committed together with the deletes.
+    write_parquet("data/eq-delete.parquet", df!["id" => [3i64, 11]]?);
+
+    let write_avro = |name: &str, mut df: DataFrame| {
+        let f = std::fs::File::create(location(name)).unwrap();
+        AvroWriter::new(f).finish(&mut df).unwrap();
+    };
+    // Bounds are keyed by field id and serialized as little endian.
+    let bounds = |bounds: &[Option<i64>]| -> PolarsResult<Series> {
+        let entries = bounds
+            .iter()
+            .map(|bound| {
+                let bound = bound.map(i64::to_le_bytes);
+                let keys =
+                    Series::new("key".into(), bound.iter().map(|_| 1i32).collect::<Vec<_>>());
+                let values = Series::new(
+                    "value".into(),
+                    bound.iter().map(|b| b.as_slice()).collect::<Vec<_>>(),
+                );
+                Ok(
+                    StructChunked::from_series("".into(), [&keys, &values].into_iter())?
+                        .into_series(),
+                )
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Series::new("".into(), entries))
+    };
+    // Entries are `(sequence number, content, path, record count, lower, upper, equality ids)`.
+    type Entry<'a> = (i64, i32, &'a str, i64, Option<i64>, Option<i64>, &'a [i32]);
+    let write_manifest = |name: &str, entries: &[Entry]| -> PolarsResult<()> {
+        let column = |name: &str, s: Series| s.with_name(name.into());
+        let fields = [
+            column("content", entries.iter().map(|e| e.1).collect()),
+            column("file_path", entries.iter().map(|e| location(e.2)).collect()),
+            column("record_count", entries.iter().map(|e| e.3).collect()),
+            column(
+                "lower_bounds",
+                bounds(&entries.iter().map(|e| e.4).collect::<Vec<_>>())?,
+            ),
+            column(
+                "upper_bounds",
+                bounds(&entries.iter().map(|e| e.5).collect::<Vec<_>>())?,
+            ),
+            column(
+                "equality_ids",
+                Series::new(
+                    "".into(),
+                    entries
+                        .iter()
+                        .map(|e| Series::new("".into(), e.6))
+                        .collect::<Vec<_>>(),
+                ),
+            ),
+        ];
+        let data_file = StructChunked::from_series("data_file".into(), fields.iter())?;
+        write_avro(
+            name,
+            DataFrame::new(vec![
+                Column::new("status".into(), vec![1i32; entries.len()]),
+                Column::new(
+                    "sequence_number".into(),
+                    entries.iter().map(|e| e.0).collect::<Vec<_>>(),
+                ),
+                data_file.into_series().into(),
+            ])?,
+        );
+        Ok(())
+    };
+    write_manifest(
+        "metadata/m1.avro",
+        &[(1, 0, "data/a.parquet", 3, Some(1), Some(3), &[])],
+    )?;
+    write_manifest(
+        "metadata/m2.avro",
+        &[
+            (2, 0, "data/b.parquet", 3, Some(10), Some(12), &[]),
+            (2, 1, "data/pos-delete.parquet", 1, None, None, &[]),
+            (2, 2, "data/eq-delete.parquet", 2, None, None, &[1]),
+        ],
+    )?;
+    let write_manifest_list = |name: &str, manifests: &[(&str, i64)]| {
+        write_avro(
+            name,
+            df![
+                "manifest_path" => manifests.iter().map(|m| location(m.0)).collect::<Vec<_>>(),
+                "partition_spec_id" => vec![0i32; manifests.len()],
+                "sequence_number" => manifests.iter().map(|m| m.1).collect::<Vec<_>>(),
+            ]
+            .unwrap(),
+        )
+    };
+    write_manifest_list("metadata/snap-1.avro", &[("metadata/m1.avro", 1)]);
+    write_manifest_list(
+        "metadata/snap-2.avro",
+        &[("metadata/m1.avro", 1), ("metadata/m2.avro", 2)],
+    );
+
+    let metadata = format!(
+        r#"{{
+            "format-version": 2,
+            "location": "file://{}",
+            "current-schema-id": 0,
+            "schemas": [{{"type": "struct", "schema-id": 0, "fields": [
+                {{"id": 1, "name": "id", "required": true, "type": "long"}},
+                {{"id": 2, "name": "name", "required": false, "type": "string"}}
+            ]}}],
+            "default-spec-id": 0,
+            "partition-specs": [{{"spec-id": 0, "fields": []}}],
+            "current-snapshot-id": 2,
+            "snapshots": [
+                {{"snapshot-id": 1, "sequence-number": 1, "timestamp-ms": 1000,
+                  "manifest-list": "file://{}", "schema-id": 0}},
+                {{"snapshot-id": 2, "sequence-number": 2, "timestamp-ms": 2000,
+                  "manifest-list": "file://{}", "schema-id": 0}}
+            ],
+            "snapshot-log": [
+                {{"timestamp-ms": 1000, "snapshot-id": 1}},
+                {{"timestamp-ms": 2000, "snapshot-id": 2}}
+            ]
+        }}"#,
+        location(""),
+        location("metadata/snap-1.avro"),
+        location("metadata/snap-2.avro"),
+    );
+    std::fs::write(dir.join("metadata/v1.metadata.json"), metadata).unwrap();
+    std::fs::write(dir.join("metadata/version-hint.text"), "1").unwrap();
+
+    let scan = |snapshot| {
+        LazyFrame::scan_iceberg(
+            dir.to_str().unwrap(),
+            ScanArgsIceberg {
+                snapshot,
+                ..Default::default()
+            },
+        )
+    };
+    let ids = |lf: LazyFrame| -> PolarsResult<Vec<Option<i64>>> {
+        let df = lf.sort(["id"], Default::default()).collect()?;
+        Ok(Vec::from(df.column("id")?.i64()?))
+    };
+
+    let df = scan(IcebergSnapshotSelector::Current)?.collect()?;
+    assert_eq!(df.get_column_names(), &["id", "name"]);
+    assert_eq!(
+        ids(scan(IcebergSnapshotSelector::Current)?)?,
+        &[Some(1), Some(10), Some(11), Some(12)]
+    );
+    assert_eq!(
+        ids(scan(IcebergSnapshotSelector::Id(1))?)?,
+        &[Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        ids(scan(IcebergSnapshotSelector::AsOfTimestamp(1500))?)?,
+        &[Some(1), Some(2), Some(3)]
+    );
+
+    // `b.parquet` is pruned by its bounds, so it isn't read.
+    std::fs::remove_file(dir.join("data/b.parquet")).unwrap();
+    let df = scan(IcebergSnapshotSelector::Current)?
+        .filter(col("id").lt(lit(5i64)))
+        .select([col("name")])
+        .collect()?;
+    assert_eq!(Vec::from(df.column("name")?.str()?), &[Some("a")]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "iceberg")]
+fn scan_iceberg_renamed_column() -> PolarsResult<()> {
+    use polars_io::avro::AvroWriter;
+    use polars_io::SerWriter;
+
+    let dir = std::env::temp_dir().join("polars_scan_iceberg_renamed_column");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("metadata")).unwrap();
+    std::fs::create_dir_all(dir.join("data")).unwrap();
+    let location = |name: &str| dir.join(name).to_str().unwrap().to_string();
+
+    let write_parquet = |name: &str, mut df: DataFrame| {
+        let f = std::fs::File::create(location(name)).unwrap();
+        ParquetWriter::new(f).finish(&mut df).unwrap();
+    };
+    // `name` is renamed to `label` in the second snapshot.
+    write_parquet(
+        "data/a.parquet",
+        df!["id" => [1i64, 2, 3], "name" => ["a", "b", "c"]]?,
+    );
+    write_parquet("data/b.parquet", df!["id" => [10i64], "label" => ["x"]]?);
+    write_parquet("data/eq-delete.parquet", df!["label" => ["b"]]?);
+
+    let write_avro = |name: &str, mut df: DataFrame| {
+        let f = std::fs::File::create(location(name)).unwrap();
+        AvroWriter::new(f).finish(&mut df).unwrap();
+    };
+    // Entries are `(sequence number, content, path, record count, equality ids)`.
+    let write_manifest = |name: &str, entries: &[(i64, i32, &str, i64, &[i32])]| {
+        let column = |name: &str, s: Series| s.with_name(name.into());
+        let fields = [
+            column("content", entries.iter().map(|e| e.1).collect()),
+            column("file_path", entries.iter().map(|e| location(e.2)).collect()),
+            column("record_count", entries.iter().map(|e| e.3).collect()),
+            column(
+                "equality_ids",
+                Series::new(
+                    "".into(),
+                    entries
+                        .iter()
+                        .map(|e| Series::new("".into(), e.4))
+                        .collect::<Vec<_>>(),
+                ),
+            ),
+        ];
+        let data_file = StructChunked::from_series("data_file".into(), fields.iter()).unwrap();
+        write_avro(
+            name,
+            DataFrame::new(vec![
+                Column::new("status".into(), vec![1i32; entries.len()]),
+                Column::new(
+                    "sequence_number".into(),
+                    entries.iter().map(|e| e.0).collect::<Vec<_>>(),
+                ),
+                data_file.into_series().into(),
+            ])
+            .unwrap(),
+        );
+    };
+    write_manifest("metadata/m1.avro", &[(1, 0, "data/a.parquet", 3, &[])]);
+    write_manifest(
+        "metadata/m2.avro",
+        &[
+            (2, 0, "data/b.parquet", 1, &[]),
+            (2, 2, "data/eq-delete.parquet", 1, &[2]),
+        ],
+    );
+    // The schema of the files is that of the snapshot that added them.
+    write_avro(
+        "metadata/snap-2.avro",
+        df![
+            "manifest_path" => [location("metadata/m1.avro"), location("metadata/m2.avro")],
+            "partition_spec_id" => [0i32, 0],
+            "sequence_number" => [1i64, 2],
+            "added_snapshot_id" => [1i64, 2],
+        ]?,
+    );
+
+    let metadata = format!(
+        r#"{{
+            "format-version": 2,
+            "location": "file://{}",
+            "current-schema-id": 1,
+            "schemas": [
+                {{"type": "struct", "schema-id": 0, "fields": [
+                    {{"id": 1, "name": "id", "required": true, "type": "long"}},
+                    {{"id": 2, "name": "name", "required": false, "type": "string"}}
+                ]}},
+                {{"type": "struct", "schema-id": 1, "fields": [
+                    {{"id": 1, "name": "id", "required": true, "type": "long"}},
+                    {{"id": 2, "name": "label", "required": false, "type": "string"}}
+                ]}}
+            ],
+            "partition-specs": [{{"spec-id": 0, "fields": []}}],
+            "current-snapshot-id": 2,
+            "snapshots": [
+                {{"snapshot-id": 1, "sequence-number": 1, "timestamp-ms": 1000,
+                  "manifest-list": "file://{}", "schema-id": 0}},
+                {{"snapshot-id": 2, "sequence-number": 2, "timestamp-ms": 2000,
+                  "manifest-list": "file://{}", "schema-id": 1}}
+            ]
+        }}"#,
+        location(""),
+        location("metadata/snap-1.avro"),
+        location("metadata/snap-2.avro"),
+    );
+    std::fs::write(dir.join("metadata/v1.metadata.json"), metadata).unwrap();
+    let scan = || {
+        LazyFrame::scan_iceberg(
+            location("metadata/v1.metadata.json"),
+            ScanArgsIceberg::default(),
+        )
+    };
+
+    let df = scan()?.sort(["id"], Default::default()).collect()?;
+    assert_eq!(df.get_column_names(), &["id", "label"]);
+    assert_eq!(
+        Vec::from(df.column("label")?.str()?),
+        &[Some("a"), Some("c"), Some("x")]
+    );
+
+    // A file that doesn't have the column under the name of its schema isn't read as nulls.
+    write_parquet(
+        "data/a.parquet",
+        df!["id" => [1i64, 2, 3], "label" => ["a", "b", "c"]]?,
+    );
+    assert!(matches!(
+        scan()?.collect(),
+        Err(PolarsError::ColumnNotFound(_))
+    ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "delta")]
+fn scan_delta() -> PolarsResult<()> {
+    use polars_io::delta::{DeltaTable, DeltaWriter};
+
+    let dir = std::env::temp_dir().join("polars_scan_delta");
+    let _ = std::fs::remove_dir_all(&dir);
+    let path = dir.to_str().unwrap();
+
+    let writer = DeltaWriter::new(path).with_partition_by(["part"]);
+    let version = writer.finish(&df!["id" => [1i64, 2, 3], "part" => ["a", "b", "a"]]?)?;
+    assert_eq!(version, 0);
+    // The partition columns of the table are used when appending.
+    let version =
+        DeltaWriter::new(path).finish(&df!["part" => ["b", "c d"], "id" => [4i32, 5]]?)?;
+    assert_eq!(version, 1);
+    // Another writer committed version 2 concurrently, the append is retried as version 3.
+    std::fs::write(
+        dir.join("_delta_log/00000000000000000002.json"),
+        r#"{"commitInfo":{"operation":"WRITE"}}"#,
+    )
+    .unwrap();
+    let version = writer.finish(&df!["id" => [6i64], "part" => ["a"]]?)?;
+    assert_eq!(version, 3);
+
+    // Appends must have the columns of the table.
+    assert!(writer.finish(&df!["id" => [7i64]]?).is_err());
+
+    let scan = |version| {
+        LazyFrame::scan_delta(
+            path,
+            ScanArgsDelta {
+                version,
+                ..Default::default()
+            },
+        )
+    };
+    let df = scan(None)?.sort(["id"], Default::default()).collect()?;
+    assert_eq!(
+        df,
+        df!["id" => [1i64, 2, 3, 4, 5, 6], "part" => ["a", "b", "a", "b", "c d", "a"]]?
+    );
+    let df = scan(Some(0))?.sort(["id"], Default::default()).collect()?;
+    assert_eq!(
+        df.column("id")?.i64()?.to_vec(),
+        [Some(1), Some(2), Some(3)]
+    );
+    // Every row has a partition value, also when only partition columns are selected.
+    let df = scan(None)?.select([col("part")]).collect()?;
+    assert_eq!(df.height(), 6);
+
+    // The files of the first commit are pruned with their statistics, and the file of the
+    // second commit in partition `b` with its partition value.
+    let table = DeltaTable::open(path, Some(1), None)?;
+    for file in table.files() {
+        if file.partition_values[0].1.as_deref() != Some("c d") {
+            std::fs::remove_file(table.location(file)).unwrap();
+        }
+    }
+    let df = scan(None)?
+        .filter(col("id").gt(lit(4)).and(col("part").neq(lit("b"))))
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(df, df!["id" => [5i64, 6], "part" => ["c d", "a"]]?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc_streaming")]
+fn scan_ipc_stream() -> PolarsResult<()> {
+    use polars_io::ipc::IpcStreamWriter;
+
+    let dir = std::env::temp_dir().join("polars_scan_ipc_stream");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    // The first file has two streams that are written one after another.
+    let mut buf = vec![];
+    IpcStreamWriter::new(&mut buf)
+        .finish(&mut df!["a" => [1i64, 2, 3], "b" => ["x", "y", "z"]]?)?;
+    IpcStreamWriter::new(&mut buf).finish(&mut df!["a" => [4i64, 5], "b" => ["u", "v"]]?)?;
+    let first = dir.join("0.arrows");
+    std::fs::write(&first, buf).unwrap();
+    let mut buf = vec![];
+    IpcStreamWriter::new(&mut buf).finish(&mut df!["a" => [6i64], "b" => ["w"]]?)?;
+    let second = dir.join("1.arrows");
+    std::fs::write(&second, buf).unwrap();
+
+    let scan =
+        |args| LazyFrame::scan_ipc_stream_files([first.clone(), second.clone()].into(), args);
+
+    let df = scan(Default::default())?.collect()?;
+    assert_eq!(
+        df,
+        df!["a" => [1i64, 2, 3, 4, 5, 6], "b" => ["x", "y", "z", "u", "v", "w"]]?
+    );
+
+    let df = scan(ScanArgsIpcStream {
+        row_index: Some(RowIndex {
+            name: "idx".into(),
+            offset: 0,
+        }),
+        include_file_paths: Some("path".into()),
+        ..Default::default()
+    })?
+    .filter(col("a").gt(lit(3)))
+    .select([col("idx"), col("b"), col("path")])
+    .collect()?;
+    assert_eq!(
+        df.select(["idx", "b"])?,
+        df!["idx" => [3 as IdxSize, 4, 5], "b" => ["u", "v", "w"]]?
+    );
+    let paths = df.column("path")?.str()?;
+    assert!(paths.get(1).unwrap().ends_with("0.arrows"));
+    assert!(paths.get(2).unwrap().ends_with("1.arrows"));
+
+    let df = scan(ScanArgsIpcStream {
+        n_rows: Some(4),
+        ..Default::default()
+    })?
+    .select([col("a")])
+    .collect()?;
+    assert_eq!(df, df!["a" => [1i64, 2, 3, 4]]?);
+
+    let df = scan(Default::default())?.select([len()]).collect()?;
+    assert_eq!(df.column("len")?.idx()?.get(0), Some(6));
+
+    // Concatenated streams must have the same schema.
+    let mut buf = vec![];
+    IpcStreamWriter::new(&mut buf).finish(&mut df!["a" => [1i64]]?)?;
+    IpcStreamWriter::new(&mut buf).finish(&mut df!["a" => [1i32]]?)?;
+    std::fs::write(&first, buf).unwrap();
+    assert!(LazyFrame::scan_ipc_stream(&first, Default::default())?
+        .collect()
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn scan_ipc_batch_statistics() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_scan_ipc_batch_statistics.ipc");
+    let batches = [
+        df!["a" => [1i64, 2, 3], "b" => ["x", "y", "z"]]?,
+        df!["a" => [Some(4i64), None], "b" => ["u", "v"]]?,
+        df!["a" => [6i64], "b" => ["w"]]?,
+    ];
+    let mut writer = IpcWriter::new(std::fs::File::create(&path).unwrap())
+        .with_batch_statistics(true)
+        .batched(&batches[0].schema())?;
+    for df in &batches {
+        writer.write_batch(df)?;
+    }
+    writer.finish()?;
+
+    let scan = || LazyFrame::scan_ipc(&path, Default::default());
+
+    let df = scan()?.filter(col("a").gt(lit(3))).collect()?;
+    assert_eq!(df, df!["a" => [4i64, 6], "b" => ["u", "w"]]?);
+
+    let df = scan()?.filter(col("b").eq(lit("y"))).collect()?;
+    assert_eq!(df, df!["a" => [2i64], "b" => ["y"]]?);
+
+    let df = scan()?
+        .filter(col("a").is_null())
+        .select([col("b")])
+        .collect()?;
+    assert_eq!(df, df!["b" => ["v"]]?);
+
+    // Batches are not skipped with a row index.
+    let df = scan()?
+        .with_row_index("idx", None)
+        .filter(col("a").gt(lit(5)))
+        .collect()?;
+    assert_eq!(df.column("idx")?.idx()?.get(0), Some(5));
+
+    // Dynamic literals are materialized to the dtype of the column.
+    let mut writer = IpcWriter::new(std::fs::File::create(&path).unwrap())
+        .with_batch_statistics(true)
+        .batched(&Schema::from_iter([Field::new(
+            "u".into(),
+            DataType::UInt8,
+        )]))?;
+    writer.write_batch(&df!["u" => [1u8, 2]]?)?;
+    writer.write_batch(&df!["u" => [3u8, 4]]?)?;
+    writer.finish()?;
+    let df = scan()?.filter(col("u").gt(lit(3))).collect()?;
+    assert_eq!(df, df!["u" => [4u8]]?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "flight")]
+fn scan_flight() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    let mut tables = FlightTables::new();
+    tables.register("t", df.clone().lazy());
+    tables.register("t_filtered", df.clone().lazy().filter(col("a").gt(lit(1))));
+    let (addr, _server) = FlightServer::new(tables).spawn("127.0.0.1:0".parse().unwrap())?;
+    let uri = format!("http://{addr}");
+
+    let out = LazyFrame::scan_flight(uri.as_str(), "t", Default::default())?.collect()?;
+    assert_eq!(out, df);
+
+    let out = LazyFrame::scan_flight(uri.as_str(), "t_filtered", Default::default())?
+        .select([col("b")])
+        .collect()?;
+    assert_eq!(out, df!["b" => ["y", "z"]]?);
+
+    let args = ScanArgsFlight {
+        n_rows: Some(1),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_flight(uri.as_str(), "t", args)?.collect()?;
+    assert_eq!(out, df.head(Some(1)));
+
+    assert!(LazyFrame::scan_flight(uri.as_str(), "unknown", Default::default()).is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn scan_parquet_file_filter() -> PolarsResult<()> {
+    use polars_io::path_utils::list_files;
+    use polars_io::{FileCheckpoint, FileFilterOptions};
+
+    let dir = std::env::temp_dir().join("polars_scan_parquet_file_filter");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let write = |name: &str, df: &mut DataFrame| -> PolarsResult<()> {
+        let f = std::fs::File::create(dir.join(name)).unwrap();
+        ParquetWriter::new(f).finish(df)?;
+        Ok(())
+    };
+    write("0.parquet", &mut df!["a" => [1i64, 2]]?)?;
+    write("1.parquet", &mut df!["a" => [3i64]]?)?;
+    std::fs::write(dir.join("_SUCCESS"), "ok").unwrap();
+
+    let exclude = FileFilterOptions {
+        exclude: vec!["**/_SUCCESS".into()],
+        ..Default::default()
+    };
+    let scan = |file_filter: FileFilterOptions| {
+        LazyFrame::scan_parquet(
+            &dir,
+            ScanArgsParquet {
+                file_filter,
+                ..Default::default()
+            },
+        )?
+        .sort(["a"], Default::default())
+        .collect()
+    };
+    assert_eq!(scan(exclude.clone())?, df!["a" => [1i64, 2, 3]]?);
+
+    // An ingestion job only reads the files that were added after its last run.
+    let checkpoint =
+        FileCheckpoint::default().advance(&list_files(&[dir.clone()], true, None, &exclude)?);
+    write("2.parquet", &mut df!["a" => [4i64]]?)?;
+    let file_filter = FileFilterOptions {
+        checkpoint: Some(checkpoint),
+        ..exclude
+    };
+    assert_eq!(scan(file_filter)?, df!["a" => [4i64]]?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "parquet",
+    feature = "dtype-datetime",
+    feature = "dtype-struct"
+))]
+fn scan_parquet_schema_evolution() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_scan_parquet_schema_evolution");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let write = |name: &str, df: &mut DataFrame| -> PolarsResult<()> {
+        let f = std::fs::File::create(dir.join(name)).unwrap();
+        ParquetWriter::new(f).finish(df)?;
+        Ok(())
+    };
+    let frame = |a: Series, b: Series, tu: TimeUnit, fields: &[Series]| -> PolarsResult<_> {
+        // The timestamps are `a` seconds, in the time unit of the file.
+        let t = (&a.cast(&DataType::Int64)? * 1_000)
+            .with_name("t".into())
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+            .cast(&DataType::Datetime(tu, None))?;
+        let s = StructChunked::from_series("s".into(), fields.iter())?.into_series();
+        DataFrame::new(vec![a.into(), b.into(), t.into(), s.into()])
+    };
+    write(
+        "0.parquet",
+        &mut frame(
+            Series::new("a".into(), [1i32, 2]),
+            Series::new("b".into(), [0.5f32, 1.5]),
+            TimeUnit::Milliseconds,
+            &[Series::new("x".into(), [1i32, 2])],
+        )?,
+    )?;
+    write(
+        "1.parquet",
+        &mut frame(
+            Series::new("a".into(), [3i64, 4]),
+            Series::new("b".into(), [2.5f64, 3.5]),
+            TimeUnit::Microseconds,
+            &[
+                Series::new("x".into(), [3i32, 4]),
+                Series::new("y".into(), ["c", "d"]),
+            ],
+        )?,
+    )?;
+
+    let scan = |schema_evolution| {
+        LazyFrame::scan_parquet(
+            dir.join("*.parquet"),
+            ScanArgsParquet {
+                schema_evolution,
+                ..Default::default()
+            },
+        )
+    };
+    assert!(scan(false)?.collect().is_err());
+
+    // The supertype of datetimes has the coarser time unit.
+    let df = scan(true)?.collect()?;
+    let expected = frame(
+        Series::new("a".into(), [1i64, 2, 3, 4]),
+        Series::new("b".into(), [0.5f64, 1.5, 2.5, 3.5]),
+        TimeUnit::Milliseconds,
+        &[
+            Series::new("x".into(), [1i32, 2, 3, 4]),
+            Series::new("y".into(), [None, None, Some("c"), Some("d")]),
+        ],
+    )?;
+    assert_eq!(df.schema(), expected.schema());
+    assert!(df.equals_missing(&expected));
+    #[cfg(feature = "streaming")]
+    assert!(scan(true)?
+        .with_streaming(true)
+        .collect()?
+        .equals_missing(&expected));
+
+    // The predicate is applied to the cast columns.
+    let df = scan(true)?
+        .filter(col("a").gt(lit(1i64)))
+        .select([col("a"), col("b")])
+        .collect()?;
+    assert_eq!(df, df!["a" => [2i64, 3, 4], "b" => [1.5f64, 2.5, 3.5]]?);
+
+    Ok(())
+}
@@ -1321,6 +1321,27 @@ fn test_sort_by() -> PolarsResult<()> {
         &[Some(3), Some(1), Some(2), Some(5), Some(4)]
     );
 
+    // evaluate_on_groups with an input that does not match the groups
+    #[cfg(feature = "range")]
+    {
+        let out = df![
+            "b" => [1, 2, 1, 2, 1],
+            "c" => [2, 2, 3, 1, 1]
+        ]?
+        .lazy()
+        .select([int_range(lit(0), len(), 1, DataType::Int32)
+            .sort_by([col("b"), col("c")], SortMultipleOptions::default())
+            .over([col("b")])
+            .alias("a")])
+        .collect()?;
+
+        let a = out.column("a")?;
+        assert_eq!(
+            Vec::from(a.i32().unwrap()),
+            &[Some(2), Some(1), Some(0), Some(0), Some(1)]
+        );
+    }
+
     Ok(())
 }
 
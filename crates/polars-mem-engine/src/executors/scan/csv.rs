@@ -4,7 +4,7 @@ use polars_core::config;
 use polars_core::utils::{
     accumulate_dataframes_vertical, accumulate_dataframes_vertical_unchecked,
 };
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::{decompress_chunk_size, DecompressedChunks};
 
 use super::*;
 
@@ -65,15 +65,20 @@ impl CsvExec {
         let finish_read =
             |i: usize, options: CsvReadOptions, predicate: Option<Arc<dyn PhysicalIoExpr>>| {
                 let source = self.sources.at(i);
-                let owned = &mut vec![];
-
                 let memslice = source.to_memslice_async_latest(run_async)?;
 
-                let reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
-                let mut df = options
-                    .into_reader_with_file_handle(reader)
-                    ._with_predicate(predicate.clone())
-                    .finish()?;
+                let mut df = match DecompressedChunks::try_new(
+                    &memslice,
+                    decompress_chunk_size(),
+                    options.parse_options.eol_char,
+                    options.parse_options.quote_char,
+                )? {
+                    Some(chunks) => read_decompressed_chunks(chunks, options, predicate)?,
+                    None => options
+                        .into_reader_with_file_handle(std::io::Cursor::new(&memslice[..]))
+                        ._with_predicate(predicate.clone())
+                        .finish()?,
+                };
 
                 if let Some(col) = &self.file_options.include_file_paths {
                     let name = source.to_include_path_name();
@@ -209,6 +214,109 @@ impl CsvExec {
     }
 }
 
+/// Parse a compressed file chunk by chunk, so that the decompressed file never has to be
+/// fully materialized.
+fn read_decompressed_chunks(
+    mut chunks: DecompressedChunks,
+    mut options: CsvReadOptions,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+) -> PolarsResult<DataFrame> {
+    // The predicate is never combined with `n_rows` or a row index, so the height of every
+    // chunk is the number of rows that were read.
+    debug_assert!(predicate.is_none() || (options.n_rows.is_none() && options.row_index.is_none()));
+
+    // The rows to skip can span multiple chunks, so the number that is still to be skipped is
+    // carried over to the next chunk.
+    let mut skip_rows = std::mem::take(&mut options.skip_rows);
+    let mut skip_rows_after_header = std::mem::take(&mut options.skip_rows_after_header);
+
+    let mut out = vec![];
+    while let Some(chunk) = chunks.next_chunk()? {
+        // Chunks end at a line ending, so a chunk with at most `skip_rows` lines is skipped
+        // entirely.
+        if skip_rows > 0 {
+            let n_lines = count_lines(
+                &chunk,
+                options.parse_options.quote_char,
+                options.parse_options.eol_char,
+            );
+            if n_lines <= skip_rows {
+                skip_rows -= n_lines;
+                continue;
+            }
+        }
+
+        let df = if skip_rows_after_header == 0 {
+            options
+                .clone()
+                .with_skip_rows(std::mem::take(&mut skip_rows))
+                .into_reader_with_file_handle(std::io::Cursor::new(chunk))
+                ._with_predicate(predicate.clone())
+                .finish()?
+        } else {
+            // The number of rows that the reader skips is unknown if the chunk has fewer rows,
+            // so the rows are sliced off here and the predicate and row index are applied after.
+            let row_index = options.row_index.clone();
+            let mut df = options
+                .clone()
+                .with_skip_rows(std::mem::take(&mut skip_rows))
+                .with_n_rows(options.n_rows.map(|n| n + skip_rows_after_header))
+                .with_row_index(None)
+                .into_reader_with_file_handle(std::io::Cursor::new(chunk))
+                .finish()?;
+            let skipped = skip_rows_after_header.min(df.height());
+            skip_rows_after_header -= skipped;
+            df = df.slice(skipped as i64, df.height() - skipped);
+            if let Some(row_index) = row_index {
+                df.with_row_index_mut(row_index.name, Some(row_index.offset));
+            }
+            if let Some(predicate) = &predicate {
+                let mask = predicate.evaluate_io(&df)?;
+                df = df.filter(mask.bool()?)?;
+            }
+            df
+        };
+
+        let height = df.height();
+        out.push(df);
+
+        if let Some(n_rows) = options.n_rows.as_mut() {
+            *n_rows -= height;
+            if *n_rows == 0 {
+                break;
+            }
+        }
+        if let Some(row_index) = options.row_index.as_mut() {
+            row_index.offset += height as IdxSize;
+        }
+
+        // The header is only found in the first chunk that is parsed.
+        options.has_header = false;
+        options.raise_if_empty = false;
+    }
+
+    if out.is_empty() {
+        return options
+            .into_reader_with_file_handle(std::io::Cursor::new(&[][..]))
+            .finish();
+    }
+    Ok(accumulate_dataframes_vertical_unchecked(out))
+}
+
+/// Number of lines in `bytes`, not counting line endings in quoted fields.
+fn count_lines(bytes: &[u8], quote_char: Option<u8>, eol_char: u8) -> usize {
+    let mut in_quotes = false;
+    let mut n_lines = 0;
+    for &b in bytes {
+        if Some(b) == quote_char {
+            in_quotes = !in_quotes;
+        } else if b == eol_char && !in_quotes {
+            n_lines += 1;
+        }
+    }
+    n_lines + (bytes.last().is_some_and(|b| *b != eol_char) as usize)
+}
+
 impl Executor for CsvExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
         let profile_name = if state.has_node_timer() {
@@ -71,6 +71,9 @@ impl IpcExec {
             self.file_options.row_index.is_some(),
         );
 
+        // Record batches are skipped with their statistics, the rows are filtered after reading.
+        let statistics_predicate = self.predicate.clone().map(phys_expr_to_io_expr);
+
         let read_path = |index: usize, n_rows: Option<usize>| {
             let source = self.sources.at(index);
 
@@ -91,6 +94,7 @@ impl IpcExec {
                 .with_n_rows(n_rows)
                 .with_row_index(self.file_options.row_index.clone())
                 .with_projection(projection.clone())
+                .with_statistics_predicate(statistics_predicate.clone())
                 .with_hive_partition_columns(
                     self.hive_parts
                         .as_ref()
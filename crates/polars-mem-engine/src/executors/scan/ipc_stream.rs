@@ -0,0 +1,130 @@
+use polars_core::config;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::predicates::apply_predicate;
+
+use super::*;
+
+pub struct IpcStreamExec {
+    sources: ScanSources,
+    file_info: FileInfo,
+    predicate: Option<Arc<dyn PhysicalExpr>>,
+    file_options: FileScanOptions,
+}
+
+impl IpcStreamExec {
+    pub fn new(
+        sources: ScanSources,
+        file_info: FileInfo,
+        predicate: Option<Arc<dyn PhysicalExpr>>,
+        file_options: FileScanOptions,
+    ) -> Self {
+        Self {
+            sources,
+            file_info,
+            predicate,
+            file_options,
+        }
+    }
+
+    fn read(&mut self) -> PolarsResult<DataFrame> {
+        let run_async =
+            self.sources.is_cloud_url() || (self.sources.is_paths() && config::force_async());
+
+        let n_rows = self.file_options.slice.map(|x| {
+            assert_eq!(x.0, 0);
+            x.1
+        });
+
+        if config::verbose() {
+            eprintln!(
+                "executing ipc stream read with row_index = {:?}, n_rows = {:?}, predicate = {:?} for paths {:?}",
+                self.file_options.row_index.as_ref(),
+                n_rows,
+                self.predicate.is_some(),
+                self.sources,
+            );
+        }
+
+        let projection = materialize_projection(
+            self.file_options.with_columns.as_deref(),
+            &self.file_info.schema,
+            None,
+            self.file_options.row_index.is_some(),
+        );
+
+        // Streams have to be read from start to end, so the files are read in order until enough
+        // rows are read. The first file is always read for the schema of the output.
+        let mut remaining = n_rows;
+        let mut dfs = Vec::with_capacity(self.sources.len());
+        for source in self.sources.iter() {
+            if remaining == Some(0) && !dfs.is_empty() {
+                break;
+            }
+
+            let memslice = source.to_memslice_async_latest(run_async)?;
+            let mut df = IpcStreamReader::new(std::io::Cursor::new(memslice))
+                .with_n_rows(remaining)
+                .with_row_index(self.file_options.row_index.clone())
+                .with_projection(projection.clone())
+                .set_rechunk(false)
+                .finish()?;
+
+            if let Some(remaining) = remaining.as_mut() {
+                *remaining -= df.height();
+            }
+
+            if let Some(col) = &self.file_options.include_file_paths {
+                let name = source.to_include_path_name();
+                unsafe {
+                    df.with_column_unchecked(Column::new_scalar(
+                        col.clone(),
+                        Scalar::new(DataType::String, AnyValue::StringOwned(name.into())),
+                        df.height(),
+                    ))
+                };
+            }
+            dfs.push(df);
+        }
+
+        if let Some(ref row_index) = self.file_options.row_index {
+            let mut offset = 0;
+            for df in &mut dfs {
+                df.apply(&row_index.name, |series| series.idx().unwrap() + offset)
+                    .unwrap();
+                offset += df.height();
+            }
+        };
+
+        if let Some(predicate) = self.predicate.clone() {
+            let predicate = phys_expr_to_io_expr(predicate);
+            for df in &mut dfs {
+                apply_predicate(df, Some(predicate.as_ref()), true)?;
+            }
+        }
+
+        let mut out = accumulate_dataframes_vertical(dfs)?;
+
+        if self.file_options.rechunk {
+            out.as_single_chunk_par();
+        }
+
+        Ok(out)
+    }
+}
+
+impl Executor for IpcStreamExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let profile_name = if state.has_node_timer() {
+            let mut ids = vec![self.sources.id()];
+            if self.predicate.is_some() {
+                ids.push("predicate".into())
+            }
+            let name = comma_delimited("ipc_stream".to_string(), &ids);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        };
+
+        state.record(|| self.read(), profile_name)
+    }
+}
@@ -2,6 +2,8 @@
 mod csv;
 #[cfg(feature = "ipc")]
 mod ipc;
+#[cfg(feature = "ipc_streaming")]
+mod ipc_stream;
 #[cfg(feature = "json")]
 mod ndjson;
 #[cfg(feature = "parquet")]
@@ -15,13 +17,20 @@ use std::mem;
 pub(crate) use csv::CsvExec;
 #[cfg(feature = "ipc")]
 pub(crate) use ipc::IpcExec;
+#[cfg(feature = "ipc_streaming")]
+pub(crate) use ipc_stream::IpcStreamExec;
 #[cfg(feature = "json")]
 pub(crate) use ndjson::JsonExec;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::ParquetExec;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use polars_io::predicates::PhysicalIoExpr;
-#[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv",
+    feature = "ipc",
+    feature = "ipc_streaming"
+))]
 use polars_io::prelude::*;
 use polars_plan::global::_set_n_rows_for_scan;
 
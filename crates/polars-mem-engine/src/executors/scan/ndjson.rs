@@ -1,6 +1,6 @@
 use polars_core::config;
 use polars_core::utils::accumulate_dataframes_vertical;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::{decompress_chunk_size, DecompressedChunks};
 
 use super::*;
 
@@ -36,7 +36,8 @@ impl JsonExec {
             .as_ref()
             .unwrap()
             .as_ref()
-            .unwrap_right();
+            .unwrap_right()
+            .clone();
 
         let verbose = config::verbose();
         let force_async = config::force_async();
@@ -53,7 +54,7 @@ impl JsonExec {
 
         // Avoid panicking
         if n_rows == Some(0) {
-            let mut df = DataFrame::empty_with_schema(schema);
+            let mut df = DataFrame::empty_with_schema(&schema);
             if let Some(col) = &self.file_scan_options.include_file_paths {
                 unsafe {
                     df.with_column_unchecked(Column::new_empty(col.clone(), &DataType::String))
@@ -65,39 +66,57 @@ impl JsonExec {
             return Ok(df);
         }
 
-        let dfs = self
-            .sources
+        let sources = self.sources.clone();
+        let dfs = sources
             .iter()
             .map_while(|source| {
                 if n_rows == Some(0) {
                     return None;
                 }
 
-                let row_index = self.file_scan_options.row_index.as_mut();
-
                 let memslice = match source.to_memslice_async_latest(run_async) {
                     Ok(memslice) => memslice,
                     Err(err) => return Some(Err(err)),
                 };
 
-                let owned = &mut vec![];
-                let curs = std::io::Cursor::new(match maybe_decompress_bytes(&memslice, owned) {
-                    Ok(v) => v,
+                let df = match DecompressedChunks::try_new(
+                    &memslice,
+                    decompress_chunk_size(),
+                    b'\n',
+                    None,
+                ) {
+                    Ok(Some(mut chunks)) => {
+                        // Parse compressed files chunk by chunk, so that the decompressed file
+                        // never has to be fully materialized.
+                        let mut out = vec![];
+                        let mut n_rows = n_rows;
+                        loop {
+                            let chunk = match chunks.next_chunk() {
+                                Ok(Some(chunk)) => chunk,
+                                Ok(None) => break,
+                                Err(e) => return Some(Err(e)),
+                            };
+                            let df = match self.read_bytes(&chunk, &schema, n_rows) {
+                                Ok(df) => df,
+                                Err(e) => return Some(Err(e)),
+                            };
+                            if let Some(ref mut n_rows) = n_rows {
+                                *n_rows -= df.height();
+                            }
+                            out.push(df);
+                            if n_rows == Some(0) {
+                                break;
+                            }
+                        }
+                        if out.is_empty() {
+                            self.read_bytes(&[], &schema, n_rows)
+                        } else {
+                            accumulate_dataframes_vertical(out)
+                        }
+                    },
+                    Ok(None) => self.read_bytes(&memslice, &schema, n_rows),
                     Err(e) => return Some(Err(e)),
-                });
-                let reader = JsonLineReader::new(curs);
-
-                let df = reader
-                    .with_schema(schema.clone())
-                    .with_rechunk(self.file_scan_options.rechunk)
-                    .with_chunk_size(Some(self.options.chunk_size))
-                    .with_row_index(row_index)
-                    .with_predicate(self.predicate.clone().map(phys_expr_to_io_expr))
-                    .with_projection(self.file_scan_options.with_columns.clone())
-                    .low_memory(self.options.low_memory)
-                    .with_n_rows(n_rows)
-                    .with_ignore_errors(self.options.ignore_errors)
-                    .finish();
+                };
 
                 let mut df = match df {
                     Ok(df) => df,
@@ -127,6 +146,30 @@ impl JsonExec {
     }
 }
 
+impl JsonExec {
+    fn read_bytes(
+        &mut self,
+        bytes: &[u8],
+        schema: &SchemaRef,
+        n_rows: Option<usize>,
+    ) -> PolarsResult<DataFrame> {
+        let row_index = self.file_scan_options.row_index.as_mut();
+        JsonLineReader::new(std::io::Cursor::new(bytes))
+            .with_schema(schema.clone())
+            .with_rechunk(self.file_scan_options.rechunk)
+            .with_chunk_size(Some(self.options.chunk_size))
+            .with_row_index(row_index)
+            .with_predicate(self.predicate.clone().map(phys_expr_to_io_expr))
+            .with_projection(self.file_scan_options.with_columns.clone())
+            .low_memory(self.options.low_memory)
+            .with_n_rows(n_rows)
+            .with_ignore_errors(self.options.ignore_errors)
+            .with_strict(self.options.strict)
+            .with_required_fields(self.options.required_fields.clone())
+            .finish()
+    }
+}
+
 impl Executor for JsonExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
         let profile_name = if state.has_node_timer() {
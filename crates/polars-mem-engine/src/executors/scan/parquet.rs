@@ -203,6 +203,7 @@ impl ParquetExec {
                 .collect::<Vec<_>>();
 
             let allow_missing_columns = self.file_options.allow_missing_columns;
+            let schema_evolution = self.options.schema_evolution;
 
             let out = POOL.install(|| {
                 readers_and_metadata
@@ -222,6 +223,7 @@ impl ParquetExec {
                                 &first_schema,
                                 projected_arrow_schema.as_deref(),
                                 allow_missing_columns,
+                                schema_evolution,
                             )?
                             .finish()?;
 
@@ -399,6 +401,7 @@ impl ParquetExec {
             let projected_arrow_schema = projected_arrow_schema.clone();
             let predicate = predicate.clone();
             let allow_missing_columns = self.file_options.allow_missing_columns;
+            let schema_evolution = self.options.schema_evolution;
 
             if verbose {
                 eprintln!("reading of {}/{} file...", processed, paths.len());
@@ -429,6 +432,7 @@ impl ParquetExec {
                                 &first_schema,
                                 projected_arrow_schema.as_deref(),
                                 allow_missing_columns,
+                                schema_evolution,
                             )
                             .await?
                             .use_statistics(use_statistics)
@@ -326,6 +326,13 @@ fn create_physical_plan_impl(
                     hive_parts,
                     cloud_options,
                 })),
+                #[cfg(feature = "ipc_streaming")]
+                FileScan::IpcStream { .. } => Ok(Box::new(executors::IpcStreamExec::new(
+                    sources,
+                    file_info,
+                    predicate,
+                    file_options,
+                ))),
                 #[cfg(feature = "parquet")]
                 FileScan::Parquet {
                     options,
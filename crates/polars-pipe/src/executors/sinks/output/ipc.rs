@@ -15,6 +15,7 @@ impl IpcSink {
         let file = std::fs::File::create(path)?;
         let writer = IpcWriter::new(file)
             .with_compression(options.compression)
+            .with_batch_statistics(options.batch_statistics)
             .batched(schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
@@ -52,6 +53,7 @@ impl IpcCloudSink {
             let cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
             let writer = IpcWriter::new(cloud_writer)
                 .with_compression(ipc_options.compression)
+                .with_batch_statistics(ipc_options.batch_statistics)
                 .batched(schema)?;
 
             let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
@@ -135,6 +135,7 @@ impl ParquetSource {
                     &self.first_schema,
                     self.projected_arrow_schema.as_deref(),
                     self.file_options.allow_missing_columns,
+                    options.schema_evolution,
                 )?
                 .with_row_index(file_options.row_index)
                 .with_predicate(predicate.clone())
@@ -201,6 +202,7 @@ impl ParquetSource {
                         &self.first_schema,
                         self.projected_arrow_schema.as_deref(),
                         self.file_options.allow_missing_columns,
+                        options.schema_evolution,
                     )
                     .await?
                     .with_predicate(predicate.clone())
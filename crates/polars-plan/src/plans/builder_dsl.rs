@@ -1,17 +1,30 @@
 use std::sync::Arc;
 
+use either::Either;
 use polars_core::prelude::*;
-#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "ipc_streaming",
+    feature = "csv"
+))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "ipc_streaming")]
+use polars_io::ipc::IpcStreamScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
-use polars_io::HiveOptions;
-#[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv",
+    feature = "ipc",
+    feature = "ipc_streaming"
+))]
 use polars_io::RowIndex;
+use polars_io::{FileFilterOptions, HiveOptions};
 
 #[cfg(feature = "python")]
 use crate::prelude::python_udf::PythonFunction;
@@ -39,7 +52,11 @@ impl DslBuilder {
             None => function.schema(infer_schema_length)?,
         };
 
-        let file_info = FileInfo::new(schema.clone(), None, (n_rows, n_rows.unwrap_or(usize::MAX)));
+        let file_info = FileInfo::new(
+            schema.clone(),
+            Some(Either::Right(schema.clone())),
+            (n_rows, n_rows.unwrap_or(usize::MAX)),
+        );
         let file_options = FileScanOptions {
             slice: n_rows.map(|x| (0, x)),
             with_columns: None,
@@ -55,6 +72,7 @@ impl DslBuilder {
             glob: false,
             include_file_paths: None,
             allow_missing_columns: false,
+            file_filter: Default::default(),
         };
 
         Ok(DslPlan::Scan {
@@ -90,6 +108,8 @@ impl DslBuilder {
         glob: bool,
         include_file_paths: Option<PlSmallStr>,
         allow_missing_columns: bool,
+        schema_evolution: bool,
+        file_filter: FileFilterOptions,
     ) -> PolarsResult<Self> {
         let options = FileScanOptions {
             with_columns: None,
@@ -102,6 +122,7 @@ impl DslBuilder {
             glob,
             include_file_paths,
             allow_missing_columns,
+            file_filter,
         };
         Ok(DslPlan::Scan {
             sources,
@@ -113,6 +134,7 @@ impl DslBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    schema_evolution,
                 },
                 cloud_options,
                 metadata: None,
@@ -134,6 +156,7 @@ impl DslBuilder {
         cloud_options: Option<CloudOptions>,
         hive_options: HiveOptions,
         include_file_paths: Option<PlSmallStr>,
+        file_filter: FileFilterOptions,
     ) -> PolarsResult<Self> {
         Ok(DslPlan::Scan {
             sources,
@@ -149,6 +172,7 @@ impl DslBuilder {
                 glob: true,
                 include_file_paths,
                 allow_missing_columns: false,
+                file_filter,
             },
             scan_type: FileScan::Ipc {
                 options,
@@ -160,6 +184,47 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "ipc_streaming")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn scan_ipc_stream(
+        sources: ScanSources,
+        options: IpcStreamScanOptions,
+        n_rows: Option<usize>,
+        cache: bool,
+        row_index: Option<RowIndex>,
+        rechunk: bool,
+        cloud_options: Option<CloudOptions>,
+        include_file_paths: Option<PlSmallStr>,
+        file_filter: FileFilterOptions,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            file_options: FileScanOptions {
+                with_columns: None,
+                cache,
+                slice: n_rows.map(|x| (0, x)),
+                rechunk,
+                row_index,
+                file_counter: Default::default(),
+                hive_options: HiveOptions {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+                glob: true,
+                include_file_paths,
+                allow_missing_columns: false,
+                file_filter,
+            },
+            scan_type: FileScan::IpcStream {
+                options,
+                cloud_options,
+            },
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv(
@@ -169,6 +234,7 @@ impl DslBuilder {
         cloud_options: Option<CloudOptions>,
         glob: bool,
         include_file_paths: Option<PlSmallStr>,
+        file_filter: FileFilterOptions,
     ) -> PolarsResult<Self> {
         // This gets partially moved by FileScanOptions
         let read_options_clone = read_options.clone();
@@ -188,6 +254,7 @@ impl DslBuilder {
             glob,
             include_file_paths,
             allow_missing_columns: false,
+            file_filter,
         };
         Ok(DslPlan::Scan {
             sources,
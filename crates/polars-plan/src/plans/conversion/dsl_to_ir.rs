@@ -161,6 +161,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     FileScan::Csv {
                         ref cloud_options, ..
                     } => sources.expand_paths(&file_options, cloud_options.as_ref())?,
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream {
+                        ref cloud_options, ..
+                    } => sources.expand_paths(&file_options, cloud_options.as_ref())?,
                     #[cfg(feature = "json")]
                     FileScan::NDJson { .. } => sources.expand_paths(&file_options, None)?,
                     FileScan::Anonymous { .. } => sources,
@@ -186,6 +190,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         } else {
                             let (file_info, md) = scans::parquet_file_info(
                                 &sources,
+                                options,
                                 &file_options,
                                 cloud_options.as_ref(),
                             )
@@ -207,6 +212,11 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         *metadata = Some(md);
                         file_info
                     },
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { cloud_options, .. } => {
+                        scans::ipc_stream_file_info(&sources, &file_options, cloud_options.as_ref())
+                            .map_err(|e| e.context(failed_here!(ipc stream scan)))?
+                    },
                     #[cfg(feature = "csv")]
                     FileScan::Csv {
                         options,
@@ -267,6 +277,8 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         FileScan::Parquet { .. } => true,
                         #[cfg(feature = "ipc")]
                         FileScan::Ipc { .. } => true,
+                        #[cfg(feature = "ipc_streaming")]
+                        FileScan::IpcStream { .. } => true,
                         #[cfg(feature = "csv")]
                         FileScan::Csv { .. } => true,
                         #[cfg(feature = "json")]
@@ -5,6 +5,7 @@ mod expr_to_ir;
 mod ir_to_dsl;
 #[cfg(any(
     feature = "ipc",
+    feature = "ipc_streaming",
     feature = "parquet",
     feature = "csv",
     feature = "json"
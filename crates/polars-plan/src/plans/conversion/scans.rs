@@ -3,12 +3,12 @@ use polars_io::path_utils::is_cloud_url;
 #[cfg(feature = "cloud")]
 use polars_io::pl_async::get_runtime;
 use polars_io::prelude::*;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::maybe_decompressing_reader;
 use polars_io::RowIndex;
 
 use super::*;
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "ipc_streaming"))]
 fn prepare_output_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef {
     if let Some(rc) = row_index {
         let _ = schema.insert_at_index(0, rc.name.clone(), IDX_DTYPE);
@@ -31,6 +31,7 @@ fn prepare_schemas(mut schema: Schema, row_index: Option<&RowIndex>) -> (SchemaR
 #[cfg(feature = "parquet")]
 pub(super) fn parquet_file_info(
     sources: &ScanSources,
+    options: &ParquetOptions,
     file_options: &FileScanOptions,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
@@ -66,6 +67,16 @@ pub(super) fn parquet_file_info(
         }
     };
 
+    let reader_schema = if options.schema_evolution && sources.len() > 1 {
+        let schemas = parquet_schemas(sources, cloud_options)?;
+        Arc::new(unify_schemas_to_supertypes(
+            std::iter::once(reader_schema.as_ref()).chain(schemas.iter().map(|s| s.as_ref())),
+            file_options.allow_missing_columns,
+        )?)
+    } else {
+        reader_schema
+    };
+
     let schema = prepare_output_schema(
         Schema::from_arrow_schema(reader_schema.as_ref()),
         file_options.row_index.as_ref(),
@@ -80,6 +91,42 @@ pub(super) fn parquet_file_info(
     Ok((file_info, metadata))
 }
 
+/// Read the schemas of the files after the first one, for schema evolution.
+#[cfg(feature = "parquet")]
+fn parquet_schemas(
+    sources: &ScanSources,
+    #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<Vec<arrow::datatypes::ArrowSchemaRef>> {
+    use polars_core::error::feature_gated;
+    use polars_core::POOL;
+    use rayon::prelude::*;
+
+    if sources.is_cloud_url() {
+        let paths = sources.as_paths().unwrap();
+        feature_gated!("cloud", {
+            get_runtime().block_on(futures::future::try_join_all(paths[1..].iter().map(
+                |path| async move {
+                    let uri = path.to_string_lossy();
+                    ParquetAsyncReader::from_uri(&uri, cloud_options, None)
+                        .await?
+                        .schema()
+                        .await
+                },
+            )))
+        })
+    } else {
+        POOL.install(|| {
+            (1..sources.len())
+                .into_par_iter()
+                .map(|i| {
+                    let memslice = sources.at(i).to_memslice()?;
+                    ParquetReader::new(std::io::Cursor::new(memslice)).schema()
+                })
+                .collect()
+        })
+    }
+}
+
 // TODO! return metadata arced
 #[cfg(feature = "ipc")]
 pub(super) fn ipc_file_info(
@@ -131,6 +178,55 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "ipc_streaming")]
+pub(super) fn ipc_stream_file_info(
+    sources: &ScanSources,
+    file_options: &FileScanOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    polars_ensure!(!sources.is_empty(), ComputeError: "expected at least 1 source");
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    // The schema of a stream is in its first message, streams can't be read from the end.
+    let memslice =
+        sources
+            .at(0)
+            .to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = arrow::io::ipc::read::read_stream_metadata(&mut std::io::Cursor::new(memslice))?;
+
+    Ok(FileInfo::new(
+        prepare_output_schema(
+            Schema::from_arrow_schema(&metadata.schema),
+            file_options.row_index.as_ref(),
+        ),
+        Some(Either::Left(Arc::new(metadata.schema))),
+        (None, usize::MAX),
+    ))
+}
+
 #[cfg(feature = "csv")]
 pub(super) fn csv_file_info(
     sources: &ScanSources,
@@ -138,12 +234,9 @@ pub(super) fn csv_file_info(
     csv_options: &mut CsvReadOptions,
     cloud_options: Option<&polars_io::cloud::CloudOptions>,
 ) -> PolarsResult<FileInfo> {
-    use std::io::{Read, Seek};
-
     use polars_core::error::feature_gated;
     use polars_core::{config, POOL};
     use polars_io::csv::read::schema_inference::SchemaInferenceResult;
-    use polars_io::utils::get_reader_bytes;
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
     polars_ensure!(!sources.is_empty(), ComputeError: "expected at least 1 source");
@@ -177,17 +270,8 @@ pub(super) fn csv_file_info(
     let infer_schema_func = |i| {
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
-        let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
-        if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
-            polars_bail!(NoData: "empty CSV")
-        }
-        reader.rewind()?;
-
-        let reader_bytes = get_reader_bytes(&mut reader).expect("could not mmap file");
-
         // this needs a way to estimated bytes/rows.
-        SchemaInferenceResult::try_from_reader_bytes_and_options(&reader_bytes, csv_options)
+        SchemaInferenceResult::try_from_maybe_compressed_bytes_and_options(&memslice, csv_options)
     };
 
     let merge_func = |a: PolarsResult<SchemaInferenceResult>,
@@ -265,9 +349,7 @@ pub(super) fn ndjson_file_info(
     use polars_core::config;
     use polars_core::error::feature_gated;
 
-    let Some(first) = sources.first() else {
-        polars_bail!(ComputeError: "expected at least 1 source");
-    };
+    polars_ensure!(!sources.is_empty(), ComputeError: "expected at least 1 source");
 
     let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
 
@@ -290,8 +372,6 @@ pub(super) fn ndjson_file_info(
         }
     };
 
-    let owned = &mut vec![];
-
     let (mut reader_schema, schema) = if let Some(schema) = ndjson_options.schema.take() {
         if file_options.row_index.is_none() {
             (schema.clone(), schema.clone())
@@ -302,13 +382,38 @@ pub(super) fn ndjson_file_info(
             )
         }
     } else {
-        let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
-
-        let schema =
-            polars_io::ndjson::infer_schema(&mut reader, ndjson_options.infer_schema_length)?;
+        let infer_row_dtype = |i, infer_schema_length| {
+            let memslice =
+                sources
+                    .at(i)
+                    .to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            // Only decompress the lines that are needed for inference.
+            let mut reader = std::io::BufReader::new(maybe_decompressing_reader(&memslice)?);
+            polars_io::ndjson::infer_row_dtype(&mut reader, infer_schema_length)
+        };
+
+        let dtype = if ndjson_options.infer_schema_all_files && sources.len() > 1 {
+            use polars_core::POOL;
+            use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+            POOL.install(|| {
+                // Every row of every file is inferred, new fields can appear anywhere.
+                (0..sources.len())
+                    .into_par_iter()
+                    .map(|i| infer_row_dtype(i, None))
+                    .reduce(
+                        || Ok(DataType::Null),
+                        |a, b| polars_io::ndjson::merge_row_dtypes(&a?, &b?),
+                    )
+            })?
+        } else {
+            infer_row_dtype(0, ndjson_options.infer_schema_length)?
+        };
 
-        prepare_schemas(schema, file_options.row_index.as_ref())
+        prepare_schemas(
+            polars_io::ndjson::row_dtype_to_schema(&dtype),
+            file_options.row_index.as_ref(),
+        )
     };
 
     if let Some(overwriting_schema) = &ndjson_options.schema_overwrite {
@@ -4,6 +4,8 @@ use std::hash::{Hash, Hasher};
 use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "ipc_streaming")]
+use polars_io::ipc::IpcStreamScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
@@ -33,6 +35,11 @@ pub enum FileScan {
         #[cfg_attr(feature = "serde", serde(skip))]
         metadata: Option<arrow::io::ipc::read::FileMetadata>,
     },
+    #[cfg(feature = "ipc_streaming")]
+    IpcStream {
+        options: IpcStreamScanOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+    },
     #[cfg(feature = "json")]
     NDJson {
         options: NDJsonReadOptions,
@@ -85,6 +92,17 @@ impl PartialEq for FileScan {
                     ..
                 },
             ) => l == r && c_l == c_r,
+            #[cfg(feature = "ipc_streaming")]
+            (
+                FileScan::IpcStream {
+                    options: l,
+                    cloud_options: c_l,
+                },
+                FileScan::IpcStream {
+                    options: r,
+                    cloud_options: c_r,
+                },
+            ) => l == r && c_l == c_r,
             #[cfg(feature = "json")]
             (
                 FileScan::NDJson {
@@ -133,6 +151,14 @@ impl Hash for FileScan {
                 options.hash(state);
                 cloud_options.hash(state);
             },
+            #[cfg(feature = "ipc_streaming")]
+            FileScan::IpcStream {
+                options,
+                cloud_options,
+            } => {
+                options.hash(state);
+                cloud_options.hash(state);
+            },
             #[cfg(feature = "json")]
             FileScan::NDJson {
                 options,
@@ -167,6 +193,8 @@ impl FileScan {
             Self::Csv { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => _file_options.row_index.is_some(),
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream { .. } => _file_options.row_index.is_some(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => _file_options.row_index.is_some(),
             #[allow(unreachable_patterns)]
@@ -180,6 +208,8 @@ impl FileScan {
             Self::Csv { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => false,
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream { .. } => false,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
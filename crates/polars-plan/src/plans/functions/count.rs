@@ -1,13 +1,16 @@
 #[cfg(feature = "ipc")]
 use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
+#[cfg(feature = "ipc_streaming")]
+use arrow::io::ipc::read::get_stream_row_count;
 #[cfg(any(
     feature = "parquet",
     feature = "ipc",
+    feature = "ipc_streaming",
     feature = "json",
     feature = "csv"
 ))]
 use polars_core::error::feature_gated;
-#[cfg(any(feature = "parquet", feature = "json"))]
+#[cfg(any(feature = "parquet", feature = "ipc_streaming", feature = "json"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::{
@@ -33,6 +36,7 @@ pub fn count_rows(
     #[cfg(not(any(
         feature = "parquet",
         feature = "ipc",
+        feature = "ipc_streaming",
         feature = "json",
         feature = "csv"
     )))]
@@ -43,6 +47,7 @@ pub fn count_rows(
     #[cfg(any(
         feature = "parquet",
         feature = "ipc",
+        feature = "ipc_streaming",
         feature = "json",
         feature = "csv"
     ))]
@@ -68,6 +73,10 @@ pub fn count_rows(
                 cloud_options.as_ref(),
                 metadata.as_ref(),
             ),
+            #[cfg(feature = "ipc_streaming")]
+            FileScan::IpcStream { cloud_options, .. } => {
+                count_rows_ipc_stream(sources, cloud_options.as_ref())
+            },
             #[cfg(feature = "json")]
             FileScan::NDJson {
                 options,
@@ -213,13 +222,57 @@ async fn count_rows_cloud_ipc(
         .map(|rows| rows.iter().map(|v| *v as usize).sum())
 }
 
+#[cfg(feature = "ipc_streaming")]
+pub(super) fn count_rows_ipc_stream(
+    sources: &ScanSources,
+    #[allow(unused)] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    // Streams have no footer, the lengths of all record batch messages are summed.
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            get_stream_row_count(&mut std::io::Cursor::new(memslice)).map(|v| v as usize)
+        })
+        .sum()
+}
+
 #[cfg(feature = "json")]
 pub(super) fn count_rows_ndjson(
     sources: &ScanSources,
     cloud_options: Option<&CloudOptions>,
 ) -> PolarsResult<usize> {
     use polars_core::config;
-    use polars_io::utils::compression::maybe_decompress_bytes;
+    use polars_io::utils::compression::{decompress_chunk_size, DecompressedChunks};
 
     if sources.is_empty() {
         return Ok(0);
@@ -253,11 +306,22 @@ pub(super) fn count_rows_ndjson(
             let memslice =
                 source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
 
-            let owned = &mut vec![];
-            let reader = polars_io::ndjson::core::JsonLineReader::new(std::io::Cursor::new(
-                maybe_decompress_bytes(&memslice[..], owned)?,
-            ));
-            reader.count()
+            let count = |bytes: &[u8]| {
+                polars_io::ndjson::core::JsonLineReader::new(std::io::Cursor::new(bytes)).count()
+            };
+
+            // Compressed files are counted chunk by chunk, so that the decompressed file
+            // never has to be fully materialized.
+            let chunks =
+                DecompressedChunks::try_new(&memslice, decompress_chunk_size(), b'\n', None)?;
+            let Some(mut chunks) = chunks else {
+                return count(&memslice[..]);
+            };
+            let mut n_rows = 0;
+            while let Some(chunk) = chunks.next_chunk()? {
+                n_rows += count(&chunk)?;
+            }
+            Ok(n_rows)
         })
         .sum()
 }
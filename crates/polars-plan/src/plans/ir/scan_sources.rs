@@ -101,6 +101,7 @@ impl ScanSources {
                 paths,
                 file_options.glob,
                 cloud_options,
+                &file_options.file_filter,
             )?)),
             v => Ok(v.clone()),
         }
@@ -120,6 +121,7 @@ impl ScanSources {
                     file_options.glob,
                     cloud_options,
                     hive_enabled.unwrap_or(false),
+                    &file_options.file_filter,
                 )?;
                 let inferred_hive_enabled = hive_enabled.unwrap_or_else(|| {
                     expanded_from_single_directory(paths, expanded_paths.as_ref())
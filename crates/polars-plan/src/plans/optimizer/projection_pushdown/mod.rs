@@ -414,6 +414,8 @@ impl ProjectionPushDown {
                     FileScan::NDJson { .. } => true,
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc { .. } => true,
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { .. } => true,
                     #[cfg(feature = "csv")]
                     FileScan::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
@@ -13,7 +13,7 @@ use polars_io::ipc::IpcWriterOptions;
 use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
-use polars_io::{HiveOptions, RowIndex};
+use polars_io::{FileFilterOptions, HiveOptions, RowIndex};
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
 #[cfg(feature = "serde")]
@@ -40,6 +40,8 @@ pub struct FileScanOptions {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Filters on the files that are found when the paths are expanded.
+    pub file_filter: FileFilterOptions,
 }
 
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
@@ -404,9 +406,16 @@ impl From<UnionArgs> for UnionOptions {
 pub struct NDJsonReadOptions {
     pub n_threads: Option<usize>,
     pub infer_schema_length: Option<NonZeroUsize>,
+    /// Infer the schema from every row of every source instead of the first
+    /// `infer_schema_length` rows of the first one.
+    pub infer_schema_all_files: bool,
     pub chunk_size: NonZeroUsize,
     pub low_memory: bool,
     pub ignore_errors: bool,
+    /// Validate every row against the schema, see `JsonLineReader::with_strict`.
+    pub strict: bool,
+    /// Fields that every row must have in strict mode.
+    pub required_fields: Option<Arc<[PlSmallStr]>>,
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
 }
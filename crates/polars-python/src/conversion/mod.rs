@@ -14,6 +14,8 @@ use polars::frame::NullStrategy;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(all(feature = "streaming", any(feature = "csv", feature = "json")))]
+use polars::io::utils::compression::TextCompression;
 use polars::series::ops::NullBehavior;
 use polars_core::utils::arrow::array::Array;
 use polars_core::utils::arrow::types::NativeType;
@@ -1253,6 +1255,32 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
+#[cfg(all(feature = "streaming", any(feature = "csv", feature = "json")))]
+pub(crate) fn parse_text_compression(
+    compression: &str,
+    compression_level: Option<i32>,
+) -> PyResult<Option<TextCompression>> {
+    let parsed = match compression {
+        "uncompressed" => None,
+        "gzip" => Some(TextCompression::Gzip(
+            compression_level
+                .map(|lvl| {
+                    u32::try_from(lvl).map_err(|_| {
+                        PyValueError::new_err(format!("invalid gzip compression level {lvl}"))
+                    })
+                })
+                .transpose()?,
+        )),
+        "zstd" => Some(TextCompression::Zstd(compression_level)),
+        e => {
+            return Err(PyValueError::new_err(format!(
+                "`compression` must be one of {{'uncompressed', 'gzip', 'zstd'}}, got {e}",
+            )))
+        },
+    };
+    Ok(parsed)
+}
+
 pub(crate) fn strings_to_pl_smallstr<I, S>(container: I) -> Vec<PlSmallStr>
 where
     I: IntoIterator<Item = S>,
@@ -309,6 +309,8 @@ impl PyLazyFrame {
             glob,
             include_file_paths: include_file_paths.map(|x| x.into()),
             allow_missing_columns,
+            schema_evolution: false,
+            file_filter: Default::default(),
         };
 
         let sources = sources.0;
@@ -369,6 +371,7 @@ impl PyLazyFrame {
             cloud_options: None,
             hive_options,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            file_filter: Default::default(),
         };
 
         let sources = sources.0;
@@ -683,6 +686,7 @@ impl PyLazyFrame {
         let options = IpcWriterOptions {
             compression: compression.map(|c| c.0),
             maintain_order,
+            batch_statistics: false,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -695,7 +699,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "csv"))]
-    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_scientific, float_precision, null_value, quote_style, maintain_order))]
+    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_scientific, float_precision, null_value, quote_style, maintain_order, compression, compression_level))]
     fn sink_csv(
         &self,
         py: Python,
@@ -714,7 +718,10 @@ impl PyLazyFrame {
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
         maintain_order: bool,
+        compression: &str,
+        compression_level: Option<i32>,
     ) -> PyResult<()> {
+        let compression = parse_text_compression(compression, compression_level)?;
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
         let null_value = null_value.unwrap_or(SerializeOptions::default().null);
 
@@ -737,6 +744,7 @@ impl PyLazyFrame {
             maintain_order,
             batch_size,
             serialize_options,
+            compression,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -750,9 +758,20 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(all(feature = "streaming", feature = "json"))]
-    #[pyo3(signature = (path, maintain_order))]
-    fn sink_json(&self, py: Python, path: PathBuf, maintain_order: bool) -> PyResult<()> {
-        let options = JsonWriterOptions { maintain_order };
+    #[pyo3(signature = (path, maintain_order, compression, compression_level))]
+    fn sink_json(
+        &self,
+        py: Python,
+        path: PathBuf,
+        maintain_order: bool,
+        compression: &str,
+        compression_level: Option<i32>,
+    ) -> PyResult<()> {
+        let compression = parse_text_compression(compression, compression_level)?;
+        let options = JsonWriterOptions {
+            maintain_order,
+            compression,
+        };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
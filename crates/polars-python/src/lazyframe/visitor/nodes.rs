@@ -363,6 +363,10 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                 },
                 #[cfg(feature = "ipc")]
                 FileScan::Ipc { .. } => return Err(PyNotImplementedError::new_err("ipc scan")),
+                #[cfg(feature = "ipc_streaming")]
+                FileScan::IpcStream { .. } => {
+                    return Err(PyNotImplementedError::new_err("ipc stream scan"))
+                },
                 #[cfg(feature = "json")]
                 FileScan::NDJson { options, .. } => {
                     // TODO: Also pass cloud_options
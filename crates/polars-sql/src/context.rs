@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::ops::Deref;
 
@@ -5,25 +6,46 @@ use polars_core::export::regex;
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
+use polars_ops::frame::pivot::{pivot_stable, PivotAgg};
 use polars_ops::frame::JoinCoalesce;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg,
-    GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset, OrderBy,
-    Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier, Statement,
-    TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    Assignment, AssignmentTarget, BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem,
+    Expr as SQLExpr, ExprWithAlias, FromTable, Function, FunctionArg, FunctionArgExpr,
+    FunctionArgumentList, FunctionArguments, GroupByExpr, GroupByWithModifier, Ident, Insert,
+    JoinConstraint, JoinOperator, MergeAction, MergeClauseKind, MergeInsertKind,
+    NamedWindowDefinition, NamedWindowExpr, ObjectName, ObjectType, Offset, OrderBy,
+    PivotValueSource, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator,
+    SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions, WindowSpec, WindowType,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::params::{bind_placeholders, tokenize_sql, SQLParams};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
 use crate::table_functions::PolarsTableFunctions;
+use crate::types::map_sql_dtype_to_polars;
+
+// row indices of the target and source tables of a MERGE statement
+const MERGE_TARGET_INDEX: &str = "__POLARS_MERGE_TARGET";
+const MERGE_SOURCE_INDEX: &str = "__POLARS_MERGE_SOURCE";
+
+// the bitmask of the GROUP BY keys that are rolled up in the grouping set of each row, and
+// the placeholders of the GROUPING(...) calls that are parsed before the GROUP BY keys
+const GROUPING_ID: &str = "__POLARS_GROUPING_ID";
+const GROUPING_CALL_PREFIX: &str = "__POLARS_GROUPING_CALL_";
+
+// a constant index column for PIVOT (on tables without other columns)
+const PIVOT_INDEX: &str = "__POLARS_PIVOT_INDEX";
+
+// the default maximum number of iterations of a recursive CTE
+const DEFAULT_RECURSION_LIMIT: usize = 1000;
 
 #[derive(Clone)]
 pub struct TableInfo {
@@ -32,6 +54,78 @@ pub struct TableInfo {
     pub(crate) schema: Arc<Schema>,
 }
 
+/// A statement registered with `PREPARE`, along with the declared types of its parameters.
+#[derive(Clone)]
+struct PreparedStatement {
+    param_types: Vec<DataType>,
+    statement: Statement,
+    // the plan of the statement, reused by `EXECUTE` until a table of the context changes
+    plan: Option<PreparedPlan>,
+}
+
+/// The plan of a prepared statement for parameters of the given types, in which the values
+/// of the parameters are (null) placeholders.
+#[derive(Clone)]
+struct PreparedPlan {
+    param_types: Vec<DataType>,
+    placeholders: Vec<Series>,
+    // `None` if the statement is not a query, or if its plan depends on the parameter values
+    frame: Option<LazyFrame>,
+}
+
+/// A join that brings the result of a (decorrelated) subquery into the outer query.
+#[derive(Clone)]
+struct SubqueryJoin {
+    frame: LazyFrame,
+    left_on: Vec<Expr>,
+    right_on: Vec<Expr>,
+}
+
+/// The kinds of subquery that can be used as an expression.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SubqueryKind {
+    /// `EXISTS (SELECT ...)`
+    Exists,
+    /// `(SELECT ...)`, which returns a single value
+    Scalar,
+}
+
+/// The GROUPING(...) calls of a SELECT with a GROUP BY clause.
+#[derive(Clone)]
+pub(crate) enum Grouping {
+    /// The projections are parsed before the GROUP BY keys are known, so each call is a
+    /// placeholder column (resolved once the keys are known) for the given arguments.
+    Pending(Vec<Vec<Expr>>),
+    /// The GROUP BY keys (and the projections, whose aliases the arguments may refer to),
+    /// and whether there are multiple grouping sets.
+    Resolved {
+        keys: Vec<Expr>,
+        projections: Vec<Expr>,
+        grouping_sets: bool,
+    },
+}
+
+impl Grouping {
+    /// The value of a GROUPING(...) call with the given arguments.
+    pub(crate) fn call(&mut self, args: Vec<Expr>) -> PolarsResult<Expr> {
+        match self {
+            Self::Pending(calls) => {
+                calls.push(args);
+                Ok(col(format_pl_smallstr!(
+                    "{GROUPING_CALL_PREFIX}{}",
+                    calls.len() - 1
+                )))
+            },
+            Self::Resolved {
+                keys,
+                projections,
+                grouping_sets,
+            } => grouping_mask(&args, keys, projections, *grouping_sets),
+        }
+    }
+}
+
+#[derive(Default)]
 struct SelectModifiers {
     exclude: PlHashSet<String>,                // SELECT * EXCLUDE
     ilike: Option<regex::Regex>,               // SELECT * ILIKE
@@ -60,10 +154,20 @@ pub struct SQLContext {
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
+    recursion_limit: usize,
+    prepared_statements: PlHashMap<String, PreparedStatement>,
+    // The values bound to the parameters of the statement being executed.
+    pub(crate) params: SQLParams,
 
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    // The GROUPING(...) calls of the SELECT being processed (if it has a GROUP BY clause).
+    pub(crate) grouping: RefCell<Option<Grouping>>,
+    named_windows: RefCell<PlHashMap<String, WindowSpec>>,
+    // The subqueries to join into the frame of the SELECT being processed.
+    subquery_joins: RefCell<Vec<SubqueryJoin>>,
+    subquery_count: usize,
 }
 
 impl Default for SQLContext {
@@ -74,8 +178,15 @@ impl Default for SQLContext {
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            grouping: Default::default(),
+            named_windows: Default::default(),
+            subquery_joins: Default::default(),
+            subquery_count: 0,
             lp_arena: Default::default(),
             expr_arena: Default::default(),
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            prepared_statements: Default::default(),
+            params: Default::default(),
         }
     }
 }
@@ -116,11 +227,13 @@ impl SQLContext {
     ///```
     pub fn register(&mut self, name: &str, lf: LazyFrame) {
         self.table_map.insert(name.to_owned(), lf);
+        self.reset_prepared_plans();
     }
 
     /// Unregister a [`LazyFrame`] table from the [`SQLContext`].
     pub fn unregister(&mut self, name: &str) {
         self.table_map.remove(&name.to_owned());
+        self.reset_prepared_plans();
     }
 
     /// Execute a SQL query, returning a [`LazyFrame`].
@@ -148,9 +261,9 @@ impl SQLContext {
             ..Default::default()
         });
 
+        let tokens = tokenize_sql(&GenericDialect, query)?;
         let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
+            .with_tokens_with_locations(tokens)
             .parse_statements()
             .map_err(to_sql_interface_err)?;
 
@@ -167,10 +280,29 @@ impl SQLContext {
         self.cte_map.borrow_mut().clear();
         self.table_aliases.borrow_mut().clear();
         self.joined_aliases.borrow_mut().clear();
+        self.grouping.take();
+        self.named_windows.borrow_mut().clear();
+        self.subquery_joins.borrow_mut().clear();
 
         Ok(res)
     }
 
+    /// Execute a SQL query with the given values bound to its parameters, returning a
+    /// [`LazyFrame`]; see [`SQLParams`] for the supported parameter styles.
+    ///
+    /// As the values are bound as typed literals (rather than interpolated into the
+    /// query text), they are never interpreted as SQL.
+    pub fn execute_with_params(
+        &mut self,
+        query: &str,
+        params: SQLParams,
+    ) -> PolarsResult<LazyFrame> {
+        self.params = params;
+        let res = self.execute(query);
+        self.params = Default::default();
+        res
+    }
+
     /// add a function registry to the SQLContext
     /// the registry provides the ability to add custom functions to the SQLContext
     pub fn with_function_registry(mut self, function_registry: Arc<dyn FunctionRegistry>) -> Self {
@@ -178,6 +310,13 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of times the recursive term of a `WITH RECURSIVE` CTE is
+    /// evaluated (before it reaches a fixpoint); queries that recurse further raise an error.
+    pub fn with_recursion_limit(mut self, limit: usize) -> Self {
+        self.recursion_limit = limit;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -202,6 +341,13 @@ impl SQLContext {
             } => self.execute_drop_table(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
+            Statement::Insert(insert) => self.execute_insert(insert)?,
+            stmt @ Statement::Update { .. } => self.execute_update(stmt)?,
+            Statement::Delete(delete) => self.execute_delete(delete)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge(stmt)?,
+            stmt @ Statement::Prepare { .. } => self.execute_prepare(stmt)?,
+            stmt @ Statement::Execute { .. } => self.execute_prepared(stmt)?,
+            stmt @ Statement::Deallocate { .. } => self.execute_deallocate(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type {:?} is not supported", ast,
             ),
@@ -493,6 +639,7 @@ impl SQLContext {
                 names.iter().for_each(|name| {
                     self.table_map.remove(&name.to_string());
                 });
+                self.reset_prepared_plans();
                 Ok(DataFrame::empty().lazy())
             },
             _ => unreachable!(),
@@ -516,7 +663,9 @@ impl SQLContext {
                                 .as_ref(),
                         )
                         .lazy();
-                        Ok(lf.clone())
+                        let lf = lf.clone();
+                        self.reset_prepared_plans();
+                        Ok(lf)
                     } else {
                         polars_bail!(SQLInterface: "table '{}' does not exist", tbl);
                     }
@@ -530,25 +679,637 @@ impl SQLContext {
         }
     }
 
+    /// The name and frame of a table registered in the context (which DML statements can
+    /// modify).
+    fn get_registered_table(&self, name: &ObjectName) -> PolarsResult<(String, LazyFrame)> {
+        let tbl_name = name.0.first().unwrap().value.as_str();
+        match self.table_map.get(tbl_name) {
+            Some(lf) => Ok((tbl_name.to_string(), lf.clone())),
+            None => polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name),
+        }
+    }
+
+    fn dml_response(&self, response: &str) -> LazyFrame {
+        df! {
+            "Response" => [response]
+        }
+        .unwrap()
+        .lazy()
+    }
+
+    // PREPARE name [(type, ...)] AS statement
+    fn execute_prepare(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Prepare {
+            name,
+            data_types,
+            statement,
+        } = stmt
+        else {
+            unreachable!()
+        };
+        let param_types = data_types
+            .iter()
+            .map(map_sql_dtype_to_polars)
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        // queries are planned once; with declared types, that is already done here
+        let plan = if param_types.is_empty() {
+            None
+        } else {
+            Some(self.plan_prepared(statement, &param_types)?)
+        };
+        self.prepared_statements.insert(
+            name.value.clone(),
+            PreparedStatement {
+                param_types,
+                statement: (**statement).clone(),
+                plan,
+            },
+        );
+        Ok(self.dml_response("PREPARE"))
+    }
+
+    /// Plan a prepared statement with placeholders for (positional) parameters of the given
+    /// types. Only queries are planned; DML statements modify their table when executed.
+    fn plan_prepared(
+        &mut self,
+        stmt: &Statement,
+        param_types: &[DataType],
+    ) -> PolarsResult<PreparedPlan> {
+        let params = SQLParams::with_placeholders(param_types);
+        let placeholders = params.placeholders().to_vec();
+        let frame = match stmt {
+            Statement::Query(query) => {
+                let outer_params = std::mem::replace(&mut self.params, params);
+                let res = self.execute_query(query);
+                let params = std::mem::replace(&mut self.params, outer_params);
+                match res {
+                    Ok(lf) => Some(lf),
+                    // a query that uses the values of its parameters while it is planned, such
+                    // as in `IN` lists, is planned every time it is executed
+                    Err(_) if params.value_needed() => None,
+                    Err(err) => return Err(err),
+                }
+            },
+            _ => None,
+        };
+        Ok(PreparedPlan {
+            param_types: param_types.to_vec(),
+            placeholders,
+            frame,
+        })
+    }
+
+    /// Discard the plans of the prepared statements (which refer to the tables as they were
+    /// when the statements were planned).
+    fn reset_prepared_plans(&mut self) {
+        for prepared in self.prepared_statements.values_mut() {
+            prepared.plan = None;
+        }
+    }
+
+    // EXECUTE name [(value, ...)]
+    fn execute_prepared(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Execute {
+            name,
+            parameters,
+            using,
+        } = stmt
+        else {
+            unreachable!()
+        };
+        polars_ensure!(using.is_empty(), SQLInterface: "EXECUTE ... USING is not supported");
+        let prepared = self
+            .prepared_statements
+            .get(&name.value)
+            .cloned()
+            .ok_or_else(
+                || polars_err!(SQLInterface: "prepared statement '{}' does not exist", name.value),
+            )?;
+
+        let n_types = prepared.param_types.len();
+        polars_ensure!(
+            n_types == 0 || n_types == parameters.len(),
+            SQLInterface: "prepared statement '{}' expects {} parameters; found {}", name.value, n_types, parameters.len()
+        );
+
+        // evaluate the (constant) parameter values, casting them to the declared types
+        let exprs = parameters
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let expr = parse_sql_expr(e, self, None)?;
+                Ok(match prepared.param_types.get(i) {
+                    Some(dtype) => expr.strict_cast(dtype.clone()),
+                    None => expr,
+                }
+                .alias(format_pl_smallstr!("{}", i)))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let values = DataFrame::empty().lazy().select(exprs).collect()?;
+        let params = values
+            .get_columns()
+            .iter()
+            .map(|c| {
+                let value = c.get(0)?.into_static();
+                Ok(Scalar::new(c.dtype().clone(), value))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let param_types = params
+            .iter()
+            .map(|value| value.dtype().clone())
+            .collect::<Vec<_>>();
+        let plan = match prepared.plan {
+            Some(plan) if plan.param_types == param_types => plan,
+            _ => {
+                let plan = self.plan_prepared(&prepared.statement, &param_types)?;
+                if let Some(prepared) = self.prepared_statements.get_mut(&name.value) {
+                    prepared.plan = Some(plan.clone());
+                }
+                plan
+            },
+        };
+        if let Some(frame) = &plan.frame {
+            return Ok(bind_placeholders(frame, &plan.placeholders, &params));
+        }
+        let outer_params = std::mem::replace(&mut self.params, params.into());
+        let res = self.execute_statement(&prepared.statement);
+        self.params = outer_params;
+        res
+    }
+
+    // DEALLOCATE [PREPARE] name
+    fn execute_deallocate(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Deallocate { name, .. } = stmt else {
+            unreachable!()
+        };
+        polars_ensure!(
+            self.prepared_statements.remove(&name.value).is_some(),
+            SQLInterface: "prepared statement '{}' does not exist", name.value
+        );
+        Ok(self.dml_response("DEALLOCATE"))
+    }
+
+    // INSERT INTO tbl [(col, ...)] { VALUES ... | SELECT ... }
+    fn execute_insert(&mut self, insert: &Insert) -> PolarsResult<LazyFrame> {
+        let Insert {
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            on,
+            returning,
+            ..
+        } = insert;
+        if partitioned.is_some() || on.is_some() || returning.is_some() {
+            polars_bail!(SQLInterface: "INSERT does not support PARTITION, ON CONFLICT or RETURNING clauses")
+        }
+        let Some(source) = source else {
+            polars_bail!(SQLInterface: "INSERT requires VALUES or a SELECT query")
+        };
+        let (tbl_name, mut lf) = self.get_registered_table(table_name)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let mut rows = self.execute_query(source)?;
+        let rows_schema = self.get_frame_schema(&mut rows)?;
+        let columns = if columns.is_empty() {
+            schema.iter_names().cloned().collect::<Vec<_>>()
+        } else {
+            columns
+                .iter()
+                .map(|c| PlSmallStr::from_str(&c.value))
+                .collect()
+        };
+        polars_ensure!(
+            columns.len() == rows_schema.len(),
+            SQLSyntax: "INSERT expects {} column(s); found {}", columns.len(), rows_schema.len()
+        );
+        // the inserted columns are matched by position, and cast to the type of the table
+        let mut values = PlHashMap::with_capacity(columns.len());
+        for (column, source_column) in columns.iter().zip(rows_schema.iter_names()) {
+            polars_ensure!(
+                schema.contains(column),
+                SQLInterface: "column '{}' does not exist in table '{}'", column, tbl_name
+            );
+            polars_ensure!(
+                values.insert(column.clone(), col(source_column.clone())).is_none(),
+                SQLSyntax: "column '{}' is specified more than once", column
+            );
+        }
+        let rows = rows.select(
+            schema
+                .iter()
+                .map(|(name, dtype)| {
+                    values
+                        .remove(name)
+                        .unwrap_or(lit(NULL))
+                        .cast(dtype.clone())
+                        .alias(name.clone())
+                })
+                .collect::<Vec<_>>(),
+        );
+        let lf = if *overwrite {
+            rows
+        } else {
+            polars_lazy::dsl::concat(vec![lf, rows], UnionArgs::default())?
+        };
+        self.register(&tbl_name, lf);
+        Ok(self.dml_response("INSERT"))
+    }
+
+    // UPDATE tbl SET col = expr, ... [WHERE ...]
+    fn execute_update(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+        } = stmt
+        else {
+            unreachable!()
+        };
+        if !table.joins.is_empty() || from.is_some() || returning.is_some() {
+            polars_bail!(SQLInterface: "UPDATE does not support JOIN, FROM or RETURNING clauses")
+        }
+        let TableFactor::Table { name, .. } = &table.relation else {
+            polars_bail!(SQLInterface: "UPDATE expects a table; found {}", table.relation)
+        };
+        let (tbl_name, _) = self.get_registered_table(name)?;
+        let (_, mut lf) = self.get_table(&table.relation)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let condition = selection
+            .as_ref()
+            .map(|expr| parse_sql_expr(expr, self, Some(&schema)))
+            .transpose()?;
+        let updates = self.process_assignments(assignments, &schema, condition)?;
+        self.register(&tbl_name, lf.with_columns(updates));
+        Ok(self.dml_response("UPDATE"))
+    }
+
+    /// The updated columns of 'SET col = expr, ...' assignments; the values are only
+    /// updated where the condition (if any) is true.
+    fn process_assignments(
+        &mut self,
+        assignments: &[Assignment],
+        schema: &Schema,
+        condition: Option<Expr>,
+    ) -> PolarsResult<Vec<Expr>> {
+        let mut updated = PlHashSet::with_capacity(assignments.len());
+        assignments
+            .iter()
+            .map(|Assignment { target, value }| {
+                let AssignmentTarget::ColumnName(column) = target else {
+                    polars_bail!(SQLInterface: "UPDATE does not support tuple assignments; found {}", target)
+                };
+                let column = column.0.last().unwrap().value.as_str();
+                let Some(dtype) = schema.get(column) else {
+                    polars_bail!(SQLInterface: "column '{}' does not exist", column)
+                };
+                polars_ensure!(
+                    updated.insert(column.to_string()),
+                    SQLSyntax: "column '{}' is assigned more than once", column
+                );
+                let value = parse_sql_expr(value, self, Some(schema))?.cast(dtype.clone());
+                Ok(match &condition {
+                    Some(condition) => when(condition.clone())
+                        .then(value)
+                        .otherwise(col(column)),
+                    None => value,
+                }
+                .alias(column))
+            })
+            .collect()
+    }
+
+    // DELETE FROM tbl [WHERE ...]
+    fn execute_delete(&mut self, delete: &Delete) -> PolarsResult<LazyFrame> {
+        let Delete {
+            tables,
+            from,
+            using,
+            selection,
+            returning,
+            order_by,
+            limit,
+        } = delete;
+        if !tables.is_empty()
+            || using.is_some()
+            || returning.is_some()
+            || !order_by.is_empty()
+            || limit.is_some()
+        {
+            polars_bail!(SQLInterface: "DELETE does not support multiple tables, USING, RETURNING, ORDER BY or LIMIT clauses")
+        }
+        let (FromTable::WithFromKeyword(from) | FromTable::WithoutKeyword(from)) = from;
+        let [TableWithJoins {
+            relation: relation @ TableFactor::Table { name, .. },
+            joins,
+        }] = from.as_slice()
+        else {
+            polars_bail!(SQLInterface: "DELETE expects a single table")
+        };
+        polars_ensure!(joins.is_empty(), SQLInterface: "DELETE does not support JOIN clauses");
+        let (tbl_name, _) = self.get_registered_table(name)?;
+        let (_, mut lf) = self.get_table(relation)?;
+
+        // rows where the condition is null are kept
+        let lf = match selection {
+            Some(expr) => {
+                let schema = self.get_frame_schema(&mut lf)?;
+                let condition = parse_sql_expr(expr, self, Some(&schema))?;
+                lf.filter(condition.fill_null(lit(false)).not())
+            },
+            None => lf.filter(lit(false)),
+        };
+        self.register(&tbl_name, lf);
+        Ok(self.dml_response("DELETE"))
+    }
+
+    // MERGE INTO tbl USING source ON ... WHEN [NOT] MATCHED [AND ...] THEN ...
+    fn execute_merge(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        else {
+            unreachable!()
+        };
+        let TableFactor::Table { name, .. } = table else {
+            polars_bail!(SQLInterface: "MERGE expects a target table; found {}", table)
+        };
+        let (tbl_name, _) = self.get_registered_table(name)?;
+        let (l_name, mut lf) = self.get_table(table)?;
+        let (r_name, rf) = self.get_table(source)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        // Full join the target and the source (tracking the row indices of both), then
+        // evaluate the first matching clause of every row.
+        let mut lf = lf.with_row_index(MERGE_TARGET_INDEX, None);
+        let mut rf = rf.with_row_index(MERGE_SOURCE_INDEX, None);
+        let left_schema = self.get_frame_schema(&mut lf)?;
+        let right_schema = self.get_frame_schema(&mut rf)?;
+        let mut joined = self.process_join(
+            &TableInfo {
+                frame: lf,
+                name: l_name,
+                schema: left_schema.clone(),
+            },
+            &TableInfo {
+                frame: rf,
+                name: r_name.clone(),
+                schema: right_schema.clone(),
+            },
+            &JoinConstraint::On(*on.clone()),
+            JoinType::Full,
+        )?;
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
+
+        let in_target = col(MERGE_TARGET_INDEX).is_not_null();
+        let in_source = col(MERGE_SOURCE_INDEX).is_not_null();
+        let mut action: Option<Expr> = None;
+        let mut keep = in_target.clone();
+        let mut values: Vec<Vec<(Expr, Expr)>> = vec![vec![]; schema.len()];
+        for (i, clause) in clauses.iter().enumerate() {
+            let applies = match clause.clause_kind {
+                MergeClauseKind::Matched => in_target.clone().and(in_source.clone()),
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                    in_target.clone().not()
+                },
+                MergeClauseKind::NotMatchedBySource => in_source.clone().not(),
+            };
+            let applies = match &clause.predicate {
+                Some(predicate) => applies.and(
+                    parse_sql_expr(predicate, self, Some(&joined_schema))?.fill_null(lit(false)),
+                ),
+                None => applies,
+            };
+            let clause_action = match action {
+                None => when(applies)
+                    .then(typed_lit(i as IdxSize))
+                    .otherwise(lit(NULL)),
+                Some(action) => when(action.clone().is_null().and(applies))
+                    .then(typed_lit(i as IdxSize))
+                    .otherwise(action),
+            };
+            let is_clause = clause_action
+                .clone()
+                .eq(typed_lit(i as IdxSize))
+                .fill_null(lit(false));
+            action = Some(clause_action);
+            match (&clause.clause_kind, &clause.action) {
+                (
+                    MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget,
+                    MergeAction::Insert(insert),
+                ) => {
+                    let MergeInsertKind::Values(Values { rows, .. }) = &insert.kind else {
+                        polars_bail!(SQLInterface: "MERGE INSERT expects VALUES; found {}", insert)
+                    };
+                    let [row] = rows.as_slice() else {
+                        polars_bail!(SQLSyntax: "MERGE INSERT expects a single row of VALUES")
+                    };
+                    let columns = if insert.columns.is_empty() {
+                        schema.iter_names().cloned().collect::<Vec<_>>()
+                    } else {
+                        insert
+                            .columns
+                            .iter()
+                            .map(|c| PlSmallStr::from_str(&c.value))
+                            .collect()
+                    };
+                    polars_ensure!(
+                        columns.len() == row.len(),
+                        SQLSyntax: "MERGE INSERT expects {} value(s); found {}", columns.len(), row.len()
+                    );
+                    for (column, value) in columns.iter().zip(row) {
+                        let Some(idx) = schema.index_of(column) else {
+                            polars_bail!(SQLInterface: "column '{}' does not exist in table '{}'", column, tbl_name)
+                        };
+                        let value = parse_sql_expr(value, self, Some(&joined_schema))?;
+                        values[idx].push((is_clause.clone(), value));
+                    }
+                    keep = keep.or(is_clause);
+                },
+                (
+                    MergeClauseKind::Matched | MergeClauseKind::NotMatchedBySource,
+                    MergeAction::Update { assignments },
+                ) => {
+                    for update in self.process_assignments(assignments, &joined_schema, None)? {
+                        let column = update.clone().meta().output_name()?;
+                        let idx = schema.index_of(&column).ok_or_else(|| polars_err!(SQLInterface: "column '{}' does not exist in table '{}'", column, tbl_name))?;
+                        values[idx].push((is_clause.clone(), update));
+                    }
+                },
+                (
+                    MergeClauseKind::Matched | MergeClauseKind::NotMatchedBySource,
+                    MergeAction::Delete,
+                ) => {
+                    keep = keep.and(is_clause.not());
+                },
+                (kind, action) => polars_bail!(
+                    SQLSyntax: "MERGE does not support WHEN {} THEN {}", kind, action
+                ),
+            }
+        }
+
+        // a row of the target can match (at most) one row of the source (checked when the
+        // table is collected, before any row is dropped)
+        let matches = when(in_target.and(in_source))
+            .then(len().over([col(MERGE_TARGET_INDEX)]))
+            .otherwise(lit(NULL));
+        let joined = joined.with_column(merge_cardinality_guard(
+            col(MERGE_TARGET_INDEX),
+            matches,
+            &tbl_name,
+        ));
+
+        // the rows of the target keep their order, and the inserted rows are appended
+        let projection = schema
+            .iter()
+            .zip(values)
+            .map(|((name, dtype), values)| {
+                // every row is changed by (at most) one clause
+                let value = values
+                    .into_iter()
+                    .fold(col(name.clone()), |expr, (is_clause, value)| {
+                        when(is_clause).then(value).otherwise(expr)
+                    });
+                value.cast(dtype.clone()).alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+        let lf = joined
+            .filter(keep)
+            .sort(
+                [MERGE_TARGET_INDEX, MERGE_SOURCE_INDEX],
+                SortMultipleOptions::default()
+                    .with_nulls_last(true)
+                    .with_maintain_order(true),
+            )
+            .select(projection);
+        self.register(&tbl_name, lf);
+        Ok(self.dml_response("MERGE"))
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = match &*cte.query.body {
+                    SetExpr::SetOperation {
+                        op: SetOperator::Union,
+                        set_quantifier,
+                        left,
+                        right,
+                    } if with.recursive && set_expr_references_table(right, &cte_name) => self
+                        .execute_recursive_cte(
+                            &cte.alias,
+                            &cte.query,
+                            left,
+                            right,
+                            set_quantifier,
+                        )?,
+                    _ => {
+                        let lf = self.execute_query(&cte.query)?;
+                        self.rename_columns_from_table_alias(lf, &cte.alias)?
+                    },
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a recursive CTE ("anchor UNION [ALL] recursive term"); the recursive term is
+    /// evaluated on the rows produced by the previous iteration until it returns no new rows.
+    fn execute_recursive_cte(
+        &mut self,
+        alias: &TableAlias,
+        query: &Query,
+        anchor: &SetExpr,
+        recursive_term: &SetExpr,
+        quantifier: &SetQuantifier,
+    ) -> PolarsResult<LazyFrame> {
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => {
+                polars_bail!(SQLInterface: "'UNION {}' is not supported in a recursive CTE", quantifier)
+            },
+        };
+        let cte_name = alias.name.value.as_str();
+        // ORDER BY, LIMIT and OFFSET apply to the result of the recursion, not to each term
+        let bare_query = Query {
+            order_by: None,
+            limit: None,
+            offset: None,
+            ..query.clone()
+        };
+        let lf = self.process_query(anchor, &bare_query)?;
+        let mut lf = self.rename_columns_from_table_alias(lf, alias)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+        let mut result = lf.collect()?;
+        if distinct {
+            result = result.unique_stable(None, UniqueKeepStrategy::First, None)?;
+        }
+
+        // the columns of the recursive term are matched by position, and cast to the
+        // types of the anchor
+        let mut working = result.clone();
+        let mut iterations = 0;
+        while working.height() > 0 {
+            polars_ensure!(
+                iterations < self.recursion_limit,
+                SQLInterface: "recursive CTE '{}' did not complete within {} iterations (see `with_recursion_limit`)",
+                cte_name, self.recursion_limit
+            );
+            iterations += 1;
+            self.register_cte(cte_name, working.lazy());
+            let mut rf = self.process_query(recursive_term, &bare_query)?;
+            let rf_schema = self.get_frame_schema(&mut rf)?;
+            polars_ensure!(
+                rf_schema.len() == schema.len(),
+                SQLInterface: "recursive CTE '{}' requires equal number of columns in each term", cte_name
+            );
+            let rows = rf
+                .select(
+                    rf_schema
+                        .iter_names()
+                        .zip(schema.iter())
+                        .map(|(rf_name, (name, dtype))| {
+                            col(rf_name.clone()).cast(dtype.clone()).alias(name.clone())
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .collect()?;
+            working = if distinct {
+                // only the rows that were not produced before are new
+                let n_rows = result.height();
+                let all_rows =
+                    result
+                        .vstack(&rows)?
+                        .unique_stable(None, UniqueKeepStrategy::First, None)?;
+                all_rows.slice(n_rows as i64, all_rows.height() - n_rows)
+            } else {
+                rows
+            };
+            result.vstack_mut(&working)?;
+        }
+        result.as_single_chunk_par();
+        let lf = self.process_order_by(result.lazy(), &query.order_by, None)?;
+        self.process_limit_offset(lf, &query.limit, &query.offset)
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
@@ -604,31 +1365,54 @@ impl SQLContext {
 
                 // track join-aliased columns so we can resolve them later
                 let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.to_string(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{}:{}", name, r_name);
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
             }
         };
         Ok(lf)
     }
 
+    fn register_joined_aliases(
+        &self,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        joined_schema: &Schema,
+    ) {
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{}:{}", name, r_name);
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
+        // The named windows ("WINDOW w AS (...)") are only visible in their own SELECT.
+        let outer_named_windows = self.named_windows.take();
+        let outer_subquery_joins = self.subquery_joins.take();
+        let lf = self
+            .register_named_windows(&select_stmt.named_window)
+            .and_then(|_| self.process_select(select_stmt, query));
+        self.named_windows.replace(outer_named_windows);
+        let unused_subquery_joins = self.subquery_joins.replace(outer_subquery_joins);
+        polars_ensure!(
+            lf.is_err() || unused_subquery_joins.is_empty(),
+            SQLInterface: "subqueries are only supported in the SELECT, WHERE and HAVING clauses"
+        );
+        lf
+    }
+
+    fn process_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
         let mut lf = if select_stmt.from.is_empty() {
             DataFrame::empty().lazy()
         } else {
@@ -646,46 +1430,71 @@ impl SQLContext {
         lf = self.process_where(lf, &select_stmt.selection)?;
 
         // 'SELECT *' modifiers
-        let mut select_modifiers = SelectModifiers {
-            ilike: None,
-            exclude: PlHashSet::new(),
-            rename: PlHashMap::new(),
-            replace: vec![],
+        let mut select_modifiers = SelectModifiers::default();
+
+        // GROUPING(...) is only valid with a GROUP BY clause
+        let has_group_by = match &select_stmt.group_by {
+            GroupByExpr::Expressions(group_by_exprs, _) => !group_by_exprs.is_empty(),
+            GroupByExpr::All(_) => true,
         };
+        let outer_grouping = self
+            .grouping
+            .replace(has_group_by.then(|| Grouping::Pending(vec![])));
+        let projections = self.column_projections(select_stmt, &schema, &mut select_modifiers);
+        let grouping_calls = match self.grouping.replace(None) {
+            Some(Grouping::Pending(calls)) => calls,
+            _ => vec![],
+        };
+        let projections = projections?;
 
-        let projections = self.column_projections(select_stmt, &schema, &mut select_modifiers)?;
+        // Join the subqueries of the projections (the final projection drops their columns)
+        let schema = if self.subquery_joins.borrow().is_empty() {
+            schema
+        } else {
+            lf = self.process_subquery_joins(lf);
+            self.get_frame_schema(&mut lf)?
+        };
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
-        match &select_stmt.group_by {
+        let mut grouping_sets: Vec<Vec<Expr>> = Vec::new();
+        let modifiers = match &select_stmt.group_by {
             // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
             GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
+                // translate the group expressions, allowing ordinal values; the grouping
+                // sets of the group expressions are combined with each other
+                grouping_sets.push(vec![]);
+                for e in group_by_exprs {
+                    let sets = self.group_by_expr_sets(e, &projections, &schema)?;
+                    grouping_sets = grouping_sets
+                        .iter()
+                        .flat_map(|set| {
+                            sets.iter().map(move |other| {
+                                let mut combined = set.clone();
+                                for key in other {
+                                    if !combined.contains(key) {
+                                        combined.push(key.clone());
+                                    }
+                                }
+                                combined
+                            })
+                        })
+                        .collect();
+                    for key in sets.into_iter().flatten() {
+                        if !group_by_keys.contains(&key) {
+                            group_by_keys.push(key);
+                        }
+                    }
                 }
-                // translate the group expressions, allowing ordinal values
-                group_by_keys = group_by_exprs
-                    .iter()
-                    .map(|e| {
-                        self.expr_or_ordinal(
-                            e,
-                            &projections,
-                            None,
-                            Some(schema.deref()),
-                            "GROUP BY",
-                        )
-                    })
-                    .collect::<PolarsResult<_>>()?
+                modifiers
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
             GroupByExpr::All(modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
                 projections.iter().for_each(|expr| match expr {
                     // immediately match the most common cases (col|agg|len|lit, optionally aliased).
                     Expr::Agg(_) | Expr::Len | Expr::Literal(_) => (),
+                    _ if has_expr(expr, is_grouping_call) => (),
                     Expr::Column(_) => group_by_keys.push(expr.clone()),
                     Expr::Alias(e, _)
                         if matches!(&**e, Expr::Agg(_) | Expr::Len | Expr::Literal(_)) => {},
@@ -705,18 +1514,45 @@ impl SQLContext {
                         }
                     },
                 });
+                grouping_sets.push(group_by_keys.clone());
+                modifiers
             },
         };
 
+        // "WITH ROLLUP", "WITH CUBE" and "WITH TOTALS" modifiers apply to all the keys
+        for modifier in modifiers {
+            let groups = group_by_keys.iter().map(|key| vec![key.clone()]);
+            grouping_sets = match modifier {
+                _ if grouping_sets.len() > 1 => polars_bail!(
+                    SQLInterface: "GROUP BY {} cannot be combined with ROLLUP, CUBE or GROUPING SETS",
+                    modifier
+                ),
+                GroupByWithModifier::Rollup => rollup_sets(groups.collect()),
+                GroupByWithModifier::Cube => cube_sets(groups.collect()),
+                GroupByWithModifier::Totals => vec![group_by_keys.clone(), vec![]],
+            };
+        }
+
+        // GROUPING(...) is evaluated from the grouping id of the grouping sets (and is zero if
+        // there is only one); the HAVING, QUALIFY and ORDER BY clauses can refer to it too
+        let multiple_sets = grouping_sets.len() > 1;
+        let mut grouping = Grouping::Resolved {
+            keys: group_by_keys.clone(),
+            projections: projections.clone(),
+            grouping_sets: multiple_sets,
+        };
+        let projections = resolve_grouping_calls(projections, &grouping_calls, &mut grouping)?;
+        self.grouping.replace(has_group_by.then_some(grouping));
+
         lf = if group_by_keys.is_empty() {
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
-            let have_order_by = query.order_by.is_some();
+            let have_order_by = query.order_by.is_some() || select_stmt.qualify.is_some();
 
-            // Note: if there is an 'order by' then we project everything (original cols
-            // and new projections) and *then* select the final cols; the retained cols
-            // are used to ensure a correct final projection. If there's no 'order by',
-            // clause then we can project the final column *expressions* directly.
+            // Note: if there is an 'order by' (or 'qualify') then we project everything
+            // (original cols and new projections) and *then* select the final cols; the
+            // retained cols are used to ensure a correct final projection. If there's no
+            // 'order by', clause then we can project the final column *expressions* directly.
             for p in projections.iter() {
                 let name = p
                     .to_field(schema.deref(), Context::Default)?
@@ -744,6 +1580,7 @@ impl SQLContext {
                 lf = lf.with_columns(select_modifiers.renamed_cols());
             }
 
+            lf = self.process_qualify(lf, &select_stmt.qualify)?;
             lf = self.process_order_by(lf, &query.order_by, Some(&retained_cols))?;
             lf = lf.select(retained_cols);
 
@@ -756,16 +1593,36 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
-            lf = self.process_order_by(lf, &query.order_by, None)?;
+            lf = if multiple_sets {
+                self.process_grouping_sets(
+                    lf,
+                    &schema,
+                    &group_by_keys,
+                    &grouping_sets,
+                    &projections,
+                )?
+            } else {
+                self.process_group_by(lf, &group_by_keys, &projections)?
+            };
 
             // Apply optional 'having' clause, post-aggregation.
-            let schema = Some(self.get_frame_schema(&mut lf)?);
-            match select_stmt.having.as_ref() {
-                Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
+            let schema = self.get_frame_schema(&mut lf)?;
+            lf = match select_stmt.having.as_ref() {
+                Some(expr) => {
+                    let predicate = parse_sql_expr(expr, self, Some(&schema))?;
+                    self.filter_with_subqueries(lf, &schema, predicate)
+                },
                 None => lf,
+            };
+            lf = self.process_qualify(lf, &select_stmt.qualify)?;
+            lf = self.process_order_by(lf, &query.order_by, None)?;
+            if multiple_sets {
+                lf.drop([GROUPING_ID])
+            } else {
+                lf
             }
         };
+        self.grouping.replace(outer_grouping);
 
         // Apply optional DISTINCT clause.
         lf = match &select_stmt.distinct {
@@ -843,6 +1700,80 @@ impl SQLContext {
         Ok(flattened_exprs)
     }
 
+    /// Apply the optional 'qualify' clause, which filters on the result of window functions
+    /// (after they are evaluated).
+    fn process_qualify(
+        &mut self,
+        mut lf: LazyFrame,
+        qualify: &Option<SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        Ok(match qualify {
+            Some(expr) => {
+                let schema = self.get_frame_schema(&mut lf)?;
+                lf.filter(parse_sql_expr(expr, self, Some(&schema))?)
+            },
+            None => lf,
+        })
+    }
+
+    fn register_named_windows(
+        &mut self,
+        named_windows: &[NamedWindowDefinition],
+    ) -> PolarsResult<()> {
+        for NamedWindowDefinition(name, window) in named_windows {
+            let spec = match window {
+                NamedWindowExpr::NamedWindow(other) => self.named_window(other)?,
+                NamedWindowExpr::WindowSpec(spec) => self.resolve_window_spec(spec)?,
+            };
+            self.named_windows
+                .borrow_mut()
+                .insert(name.value.clone(), spec);
+        }
+        Ok(())
+    }
+
+    fn named_window(&self, name: &Ident) -> PolarsResult<WindowSpec> {
+        self.named_windows
+            .borrow()
+            .get(&name.value)
+            .cloned()
+            .ok_or_else(|| polars_err!(SQLSyntax: "window '{}' is not defined", name))
+    }
+
+    /// Resolve a window spec that refers to a named window (e.g. "OVER (w ORDER BY x)");
+    /// the spec adds to the named window, but cannot override it.
+    fn resolve_window_spec(&self, spec: &WindowSpec) -> PolarsResult<WindowSpec> {
+        let Some(name) = &spec.window_name else {
+            return Ok(spec.clone());
+        };
+        let mut base = self.named_window(name)?;
+        polars_ensure!(
+            spec.partition_by.is_empty(),
+            SQLSyntax: "cannot override PARTITION BY clause of window '{}'", name
+        );
+        polars_ensure!(
+            spec.order_by.is_empty() || base.order_by.is_empty(),
+            SQLSyntax: "cannot override ORDER BY clause of window '{}'", name
+        );
+        polars_ensure!(
+            base.window_frame.is_none(),
+            SQLSyntax: "cannot copy window '{}' because it has a frame clause", name
+        );
+        if !spec.order_by.is_empty() {
+            base.order_by.clone_from(&spec.order_by);
+        }
+        base.window_frame.clone_from(&spec.window_frame);
+        Ok(base)
+    }
+
+    /// The window spec of an OVER clause, resolving named windows.
+    pub(crate) fn resolve_window(&self, window: &WindowType) -> PolarsResult<WindowSpec> {
+        match window {
+            WindowType::WindowSpec(spec) => self.resolve_window_spec(spec),
+            WindowType::NamedWindow(name) => self.named_window(name),
+        }
+    }
+
     fn process_where(
         &mut self,
         mut lf: LazyFrame,
@@ -869,20 +1800,402 @@ impl SQLContext {
                 return Ok(DataFrame::empty_with_schema(schema.as_ref()).lazy());
             }
 
+            // correlated [NOT] EXISTS predicates are applied as semi/anti joins
+            #[cfg(feature = "semi_anti_join")]
+            let expr = &{
+                let mut predicates = vec![];
+                for predicate in split_conjunction(expr) {
+                    match predicate {
+                        SQLExpr::Exists { subquery, negated } => {
+                            match self.process_exists_join(
+                                lf.clone(),
+                                subquery,
+                                *negated,
+                                &schema,
+                            )? {
+                                Some(joined) => lf = joined,
+                                None => predicates.push(predicate.clone()),
+                            }
+                        },
+                        _ => predicates.push(predicate.clone()),
+                    }
+                }
+                match predicates.into_iter().reduce(sql_and) {
+                    Some(expr) => expr,
+                    None => return Ok(lf),
+                }
+            };
+
             // ...otherwise parse and apply the filter as normal
-            let mut filter_expression = parse_sql_expr(expr, self, Some(schema).as_deref())?;
+            let mut filter_expression = parse_sql_expr(expr, self, Some(&schema))?;
             if filter_expression.clone().meta().has_multiple_outputs() {
                 filter_expression = all_horizontal([filter_expression])?;
             }
-            lf = self.process_subqueries(lf, vec![&mut filter_expression]);
-            lf = lf.filter(filter_expression);
+            lf = self.filter_with_subqueries(lf, &schema, filter_expression);
         }
         Ok(lf)
     }
 
-    pub(super) fn process_join(
+    /// Apply a filter that can refer to the columns of (decorrelated) subqueries, which are
+    /// joined before (and dropped after) filtering. The `IN` subqueries of the filter are
+    /// added as its context (after the joins, as the filter must be applied to the context).
+    fn filter_with_subqueries(
         &mut self,
-        tbl_left: &TableInfo,
+        lf: LazyFrame,
+        schema: &Schema,
+        mut predicate: Expr,
+    ) -> LazyFrame {
+        if self.subquery_joins.borrow().is_empty() {
+            return self
+                .process_subqueries(lf, vec![&mut predicate])
+                .filter(predicate);
+        }
+        let columns = schema
+            .iter_names()
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        let lf = self.process_subquery_joins(lf);
+        self.process_subqueries(lf, vec![&mut predicate])
+            .filter(predicate)
+            .select(columns)
+    }
+
+    /// Join the subqueries of the expressions that were parsed (in the current SELECT).
+    fn process_subquery_joins(&mut self, mut lf: LazyFrame) -> LazyFrame {
+        for SubqueryJoin {
+            frame,
+            left_on,
+            right_on,
+        } in self.subquery_joins.take()
+        {
+            lf = if left_on.is_empty() {
+                lf.cross_join(frame, None)
+            } else {
+                lf.join_builder()
+                    .with(frame)
+                    .left_on(left_on)
+                    .right_on(right_on)
+                    .how(JoinType::Left)
+                    .finish()
+            };
+        }
+        lf
+    }
+
+    /// The expression of an `EXISTS` or scalar subquery.
+    ///
+    /// The subquery is evaluated once (rather than for every row of the outer query): its
+    /// correlated predicates (`inner = outer`) become the keys of a left join with the outer
+    /// query, which is applied before the expression is evaluated. Uncorrelated subqueries
+    /// are evaluated to a single row. A scalar subquery raises an error (when the query is
+    /// collected) if it returns more than one row for a row of the outer query.
+    pub(crate) fn process_subquery_expr(
+        &mut self,
+        subquery: &Query,
+        outer_schema: Option<&Schema>,
+        kind: SubqueryKind,
+    ) -> PolarsResult<Expr> {
+        self.subquery_count += 1;
+        let prefix = format!("__POLARS_SUBQUERY_{}_", self.subquery_count);
+        let marker = PlSmallStr::from_string(format!("{}MATCH", prefix));
+        let value = PlSmallStr::from_string(format!("{}VALUE", prefix));
+        let rows = PlSmallStr::from_string(format!("{}ROWS", prefix));
+
+        // the rows of `EXISTS (SELECT 1 ...)` are counted without selecting the literal
+        // (which would return a row even if there are none)
+        let mut subquery = Cow::Borrowed(subquery);
+        if let (SubqueryKind::Exists, SetExpr::Select(select)) = (kind, &*subquery.body) {
+            if select
+                .projection
+                .iter()
+                .all(|item| matches!(item, SelectItem::UnnamedExpr(SQLExpr::Value(_))))
+            {
+                let mut select = select.clone();
+                select.projection = vec![SelectItem::Wildcard(Default::default())];
+                subquery.to_mut().body = Box::new(SetExpr::Select(select));
+            }
+        }
+        let decorrelated = self.decorrelate_subquery(&subquery, outer_schema, &prefix)?;
+        let mut rf = self.execute_query_no_ctes(&decorrelated.query)?;
+        let rf_schema = self.get_frame_schema(&mut rf)?;
+        let n_keys = decorrelated.outer_keys.len();
+        let value_column = match kind {
+            SubqueryKind::Exists => None,
+            SubqueryKind::Scalar => {
+                polars_ensure!(
+                    rf_schema.len() == n_keys + 1,
+                    SQLSyntax: "SQL subquery returns more than one column"
+                );
+                Some(rf_schema.get_at_index(n_keys).unwrap().0.clone())
+            },
+        };
+
+        if n_keys == 0 {
+            let frame = match &value_column {
+                None => rf.select([len().gt(lit(0)).alias(marker.clone())]),
+                Some(column) => rf.select([
+                    col(column.clone()).first().alias(value.clone()),
+                    len().alias(rows.clone()),
+                ]),
+            };
+            self.subquery_joins.borrow_mut().push(SubqueryJoin {
+                frame,
+                left_on: vec![],
+                right_on: vec![],
+            });
+            return Ok(match kind {
+                SubqueryKind::Exists => col(marker),
+                SubqueryKind::Scalar => single_row_value(col(value), col(rows)),
+            });
+        }
+
+        let (left_on, right_on) =
+            self.subquery_join_keys(&decorrelated.outer_keys, outer_schema, &prefix)?;
+        let key_names = (0..n_keys)
+            .map(|i| PlSmallStr::from_string(format!("{}KEY_{}", prefix, i)))
+            .collect::<Vec<_>>();
+        let keys = key_names.iter().cloned().map(col).collect::<Vec<_>>();
+        let frame = match value_column {
+            None => {
+                let mut projection = keys;
+                projection.push(lit(true).alias(marker.clone()));
+                rf.select(projection)
+                    .unique_stable(Some(key_names), UniqueKeepStrategy::First)
+            },
+            Some(column) => rf.group_by(keys).agg([
+                col(column).first().alias(value.clone()),
+                len().alias(rows.clone()),
+            ]),
+        };
+        self.subquery_joins.borrow_mut().push(SubqueryJoin {
+            frame,
+            left_on,
+            right_on,
+        });
+
+        Ok(match (kind, decorrelated.empty_query) {
+            (SubqueryKind::Exists, _) => col(marker).is_not_null(),
+            (SubqueryKind::Scalar, None) => single_row_value(col(value), col(rows)),
+            // an aggregate over no rows can have a (non-null) value, eg: COUNT(*)
+            (SubqueryKind::Scalar, Some(empty_query)) => {
+                let empty_value = self.process_subquery_expr(&empty_query, outer_schema, kind)?;
+                when(col(rows.clone()).is_null())
+                    .then(empty_value)
+                    .otherwise(single_row_value(col(value), col(rows)))
+            },
+        })
+    }
+
+    /// Apply a correlated `[NOT] EXISTS` predicate as a semi (anti) join; returns `None` if
+    /// the subquery is not correlated.
+    #[cfg(feature = "semi_anti_join")]
+    fn process_exists_join(
+        &mut self,
+        lf: LazyFrame,
+        subquery: &Query,
+        negated: bool,
+        outer_schema: &Schema,
+    ) -> PolarsResult<Option<LazyFrame>> {
+        self.subquery_count += 1;
+        let prefix = format!("__POLARS_SUBQUERY_{}_", self.subquery_count);
+        let decorrelated = self.decorrelate_subquery(subquery, Some(outer_schema), &prefix)?;
+        if decorrelated.outer_keys.is_empty() {
+            return Ok(None);
+        }
+        let rf = self.execute_query_no_ctes(&decorrelated.query)?;
+        let (left_on, right_on) =
+            self.subquery_join_keys(&decorrelated.outer_keys, Some(outer_schema), &prefix)?;
+        Ok(Some(
+            lf.join_builder()
+                .with(rf)
+                .left_on(left_on)
+                .right_on(right_on)
+                .how(if negated {
+                    JoinType::Anti
+                } else {
+                    JoinType::Semi
+                })
+                .finish(),
+        ))
+    }
+
+    /// The join keys of a decorrelated subquery; the keys of the subquery take the types of
+    /// the (outer) keys of the outer query.
+    fn subquery_join_keys(
+        &mut self,
+        outer_keys: &[SQLExpr],
+        outer_schema: Option<&Schema>,
+        prefix: &str,
+    ) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+        let mut left_on = Vec::with_capacity(outer_keys.len());
+        let mut right_on = Vec::with_capacity(outer_keys.len());
+        for (i, key) in outer_keys.iter().enumerate() {
+            let outer_key = parse_sql_expr(key, self, outer_schema)?;
+            let inner_key = col(PlSmallStr::from_string(format!("{}KEY_{}", prefix, i)));
+            right_on.push(match outer_schema {
+                Some(schema) => inner_key.cast(outer_key.to_field(schema, Context::Default)?.dtype),
+                None => inner_key,
+            });
+            left_on.push(outer_key);
+        }
+        Ok((left_on, right_on))
+    }
+
+    /// Rewrite a correlated subquery as an uncorrelated one: the inner sides of its correlated
+    /// predicates (`inner = outer`) are projected as the columns "{prefix}KEY_{i}" (and added to
+    /// the GROUP BY clause if the subquery is aggregated).
+    fn decorrelate_subquery(
+        &mut self,
+        subquery: &Query,
+        outer_schema: Option<&Schema>,
+        prefix: &str,
+    ) -> PolarsResult<DecorrelatedSubquery> {
+        polars_ensure!(
+            subquery.with.is_none(),
+            SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause"
+        );
+        let uncorrelated = DecorrelatedSubquery {
+            query: subquery.clone(),
+            outer_keys: vec![],
+            empty_query: None,
+        };
+        let SetExpr::Select(select) = &*subquery.body else {
+            return Ok(uncorrelated);
+        };
+        let [from] = select.from.as_slice() else {
+            return Ok(uncorrelated);
+        };
+
+        // the tables and columns in the scope of the subquery (that shadow the outer query);
+        // the aliases registered by the FROM clause of the subquery are not kept
+        let outer_aliases = (
+            self.table_aliases.borrow().clone(),
+            self.joined_aliases.borrow().clone(),
+        );
+        let inner_lf = self.execute_from_statement(from);
+        self.table_aliases.replace(outer_aliases.0);
+        self.joined_aliases.replace(outer_aliases.1);
+        let mut inner_lf = inner_lf?;
+        let inner_schema = self.get_frame_schema(&mut inner_lf)?;
+        let inner_tables = std::iter::once(&from.relation)
+            .chain(from.joins.iter().map(|join| &join.relation))
+            .filter_map(table_factor_name)
+            .collect::<PlHashSet<_>>();
+        let is_outer = |idents: &[Ident]| match idents {
+            [ident] => {
+                !inner_schema.contains(&ident.value)
+                    && outer_schema.map_or(false, |schema| schema.contains(&ident.value))
+            },
+            [.., tbl, _] => !inner_tables.contains(tbl.value.as_str()),
+            [] => false,
+        };
+        // whether an expression refers to the subquery and/or the outer query
+        let scope = |expr: &SQLExpr| {
+            let (mut inner, mut outer) = (false, false);
+            visit_identifiers(expr, &mut |idents| match is_outer(idents) {
+                true => outer = true,
+                false => inner = true,
+            });
+            (inner, outer)
+        };
+
+        let mut inner_keys = vec![];
+        let mut outer_keys = vec![];
+        let mut predicates = vec![];
+        for predicate in select.selection.iter().flat_map(split_conjunction) {
+            let correlated = match predicate {
+                _ if !scope(predicate).1 => None,
+                SQLExpr::BinaryOp {
+                    left,
+                    op: BinaryOperator::Eq,
+                    right,
+                } => match (scope(left), scope(right)) {
+                    ((true, false), (false, true)) => Some((left, right)),
+                    ((false, true), (true, false)) => Some((right, left)),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match correlated {
+                Some((inner, outer)) => {
+                    inner_keys.push((**inner).clone());
+                    outer_keys.push((**outer).clone());
+                },
+                None if scope(predicate).1 => polars_bail!(
+                    SQLInterface: "correlated subquery predicate '{}' is not supported (only equality with the outer query is)", predicate
+                ),
+                None => predicates.push(predicate.clone()),
+            }
+        }
+        for item in &select.projection {
+            if let SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } = item {
+                polars_ensure!(
+                    !scope(expr).1,
+                    SQLInterface: "correlated subqueries can only refer to the outer query in their WHERE clause; found '{}'", expr
+                );
+            }
+        }
+        if outer_keys.is_empty() {
+            return Ok(uncorrelated);
+        }
+        polars_ensure!(
+            subquery.limit.is_none() && subquery.offset.is_none() && subquery.fetch.is_none(),
+            SQLInterface: "correlated subqueries do not support LIMIT, OFFSET or FETCH"
+        );
+
+        // an aggregated subquery (without GROUP BY) returns one row for every key
+        let group_by_keys = match &select.group_by {
+            GroupByExpr::Expressions(exprs, modifiers) if exprs.is_empty() => {
+                let outer_subquery_joins = self.subquery_joins.take();
+                let aggregated =
+                    self.column_projections(select, &inner_schema, &mut SelectModifiers::default());
+                self.subquery_joins.replace(outer_subquery_joins);
+                aggregated?
+                    .iter()
+                    .any(|expr| has_expr(expr, |e| matches!(e, Expr::Agg(_) | Expr::Len)))
+                    .then(|| (vec![], modifiers))
+            },
+            GroupByExpr::Expressions(exprs, modifiers) => Some((exprs.clone(), modifiers)),
+            GroupByExpr::All(_) => None,
+        };
+        let mut decorrelated = select.clone();
+        decorrelated.selection = predicates.into_iter().reduce(sql_and);
+        decorrelated.projection = inner_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| SelectItem::ExprWithAlias {
+                expr: key.clone(),
+                alias: Ident::new(format!("{}KEY_{}", prefix, i)),
+            })
+            .chain(select.projection.iter().cloned())
+            .collect();
+
+        let mut empty_query = None;
+        if let Some((mut exprs, modifiers)) = group_by_keys {
+            if exprs.is_empty() {
+                let mut empty_select = select.clone();
+                empty_select.selection = Some(SQLExpr::Value(SQLValue::Boolean(false)));
+                empty_query = Some(Query {
+                    body: Box::new(SetExpr::Select(empty_select)),
+                    ..subquery.clone()
+                });
+            }
+            exprs.extend(inner_keys);
+            decorrelated.group_by = GroupByExpr::Expressions(exprs, modifiers.clone());
+        }
+        Ok(DecorrelatedSubquery {
+            query: Query {
+                body: Box::new(SetExpr::Select(decorrelated)),
+                ..subquery.clone()
+            },
+            outer_keys,
+            empty_query,
+        })
+    }
+
+    pub(super) fn process_join(
+        &mut self,
+        tbl_left: &TableInfo,
         tbl_right: &TableInfo,
         constraint: &JoinConstraint,
         join_type: JoinType,
@@ -1068,11 +2381,270 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (tbl_name, lf) = self.get_table(table)?;
+                let lf = self.process_pivot(
+                    lf,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null,
+                )?;
+                self.register_table_factor_alias(tbl_name, lf, alias)
+            },
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                let (tbl_name, lf) = self.get_table(table)?;
+                let lf = self.process_unpivot(lf, value, name, columns)?;
+                self.register_table_factor_alias(tbl_name, lf, alias)
+            },
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
     }
 
+    fn register_table_factor_alias(
+        &mut self,
+        tbl_name: String,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((tbl_name, lf)),
+        }
+    }
+
+    // tbl PIVOT (agg(value) FOR column IN (v1 [AS a1], ...)) [DEFAULT ON NULL (expr)]
+    fn process_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: &Option<SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let [ExprWithAlias {
+            expr: agg_expr,
+            alias: None,
+        }] = aggregate_functions
+        else {
+            polars_bail!(SQLInterface: "PIVOT expects a single (unaliased) aggregate function")
+        };
+        let (agg_fn, values) = parse_pivot_aggregate(agg_expr)?;
+        let on = match value_column {
+            [.., ident] => PlSmallStr::from_str(&ident.value),
+            [] => polars_bail!(SQLSyntax: "PIVOT expects a column to pivot on"),
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        let (Some(on_dtype), Some(values_dtype)) = (schema.get(&on), schema.get(&values)) else {
+            polars_bail!(SQLInterface: "PIVOT columns '{}' and '{}' must exist in the table", on, values)
+        };
+        let index = schema
+            .iter_names()
+            .filter(|name| **name != on && **name != values)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // the (string) names of the pivoted columns, and their aliases
+        let (headers, aliases): (Series, Vec<Option<PlSmallStr>>) = match value_source {
+            PivotValueSource::List(pivot_values) => {
+                let exprs = pivot_values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, ExprWithAlias { expr, .. })| {
+                        Ok(parse_sql_expr(expr, self, None)?
+                            .cast(on_dtype.clone())
+                            .cast(DataType::String)
+                            .alias(format_pl_smallstr!("{}", i)))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let row = DataFrame::empty().lazy().select(exprs).collect()?;
+                let headers = row
+                    .get_columns()
+                    .iter()
+                    .map(|c| c.as_materialized_series().clone())
+                    .reduce(|mut acc, s| {
+                        acc.append(&s).unwrap();
+                        acc
+                    })
+                    .unwrap_or_else(|| Series::new_empty(PlSmallStr::EMPTY, &DataType::String));
+                let aliases = pivot_values
+                    .iter()
+                    .map(|v| v.alias.as_ref().map(|a| PlSmallStr::from_str(&a.value)))
+                    .collect();
+                (headers, aliases)
+            },
+            PivotValueSource::Any(order_by) if order_by.is_empty() => {
+                let headers = lf
+                    .clone()
+                    .select([col(on.clone()).unique_stable().cast(DataType::String)])
+                    .collect()?
+                    .drop_in_place(&on)?
+                    .take_materialized_series();
+                let aliases = vec![None; headers.len()];
+                (headers, aliases)
+            },
+            PivotValueSource::Subquery(query) => {
+                let mut rf = self.execute_query(query)?;
+                let rf_schema = self.get_frame_schema(&mut rf)?;
+                let Some((name, _)) = rf_schema.get_at_index(0) else {
+                    polars_bail!(SQLSyntax: "PIVOT subquery must return a column")
+                };
+                let headers = rf
+                    .select([col(name.clone())
+                        .cast(on_dtype.clone())
+                        .cast(DataType::String)])
+                    .collect()?
+                    .drop_in_place(name)?
+                    .take_materialized_series();
+                let aliases = vec![None; headers.len()];
+                (headers, aliases)
+            },
+            source => polars_bail!(SQLInterface: "PIVOT does not support IN ({})", source),
+        };
+        polars_ensure!(!headers.is_empty(), SQLSyntax: "PIVOT expects at least one value");
+
+        let mut pivoted = if let PivotValueSource::Any(_) = value_source {
+            // the values that are not listed are pivoted into a (dropped) null column, so
+            // that every row of the index is kept; the pivot also requires an index column
+            let idx_name = PlSmallStr::from_static(PIVOT_INDEX);
+            let pivot_df = lf
+                .with_columns([
+                    when(
+                        col(on.clone())
+                            .cast(DataType::String)
+                            .is_in(lit(headers.clone())),
+                    )
+                    .then(col(on.clone()))
+                    .otherwise(lit(NULL))
+                    .alias(on.clone()),
+                    lit(0i32).alias(idx_name.clone()),
+                ])
+                .collect()?;
+            let pivot_index = if index.is_empty() {
+                vec![idx_name.clone()]
+            } else {
+                index.clone()
+            };
+            let mut pivoted = pivot_stable(
+                &pivot_df,
+                [on],
+                Some(pivot_index),
+                Some([values]),
+                false,
+                Some(agg_fn.clone()),
+                None,
+            )?;
+            if index.is_empty() && pivoted.height() == 0 {
+                // no rows were pivoted; there is still one (aggregated) row
+                pivoted = df! { PIVOT_INDEX => [0i32] }?;
+            }
+            pivoted.lazy()
+        } else {
+            // the pivoted columns are known, so every value is aggregated (lazily) from the
+            // rows where it is the value of the pivoted column
+            let on_value = col(on.clone()).cast(DataType::String);
+            let mut aggs = Vec::with_capacity(headers.len());
+            let mut aggregated = PlHashSet::new();
+            for header in headers.str()?.into_iter().flatten() {
+                if !aggregated.insert(header) {
+                    continue;
+                }
+                let is_value = on_value.clone().eq(lit(header));
+                let agg = pivot_agg_expr(&agg_fn, col(values.clone()).filter(is_value.clone()));
+                aggs.push(
+                    when(is_value.any(true))
+                        .then(agg)
+                        .otherwise(lit(NULL))
+                        .alias(header),
+                );
+            }
+            if index.is_empty() {
+                lf.select(aggs)
+            } else {
+                lf.group_by_stable(index.iter().cloned().map(col).collect::<Vec<_>>())
+                    .agg(aggs)
+            }
+        };
+
+        let agg_dtype = match agg_fn {
+            PivotAgg::Count => IDX_DTYPE,
+            PivotAgg::Mean | PivotAgg::Median => DataType::Float64,
+            _ => values_dtype.clone(),
+        };
+        let default_on_null = default_on_null
+            .as_ref()
+            .map(|expr| parse_sql_expr(expr, self, None))
+            .transpose()?;
+        let pivoted_schema = self.get_frame_schema(&mut pivoted)?;
+        let mut projection = index.into_iter().map(col).collect::<Vec<_>>();
+        for (header, alias) in headers.str()?.into_iter().zip(aliases) {
+            let (mut expr, dtype) = match header.and_then(|h| pivoted_schema.get(h)) {
+                Some(dtype) => (col(header.unwrap()), dtype.clone()),
+                None => (lit(NULL).cast(agg_dtype.clone()), agg_dtype.clone()),
+            };
+            let header = header.unwrap_or("null");
+            if let Some(default) = &default_on_null {
+                expr = expr.fill_null(default.clone().cast(dtype));
+            }
+            projection.push(expr.alias(alias.unwrap_or_else(|| PlSmallStr::from_str(header))));
+        }
+        Ok(pivoted.select(projection))
+    }
+
+    // tbl UNPIVOT (value FOR name IN (col1, col2, ...))
+    fn process_unpivot(
+        &mut self,
+        mut lf: LazyFrame,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let on = columns
+            .iter()
+            .map(|c| {
+                polars_ensure!(
+                    schema.contains(&c.value),
+                    SQLInterface: "UNPIVOT column '{}' does not exist", c.value
+                );
+                Ok(PlSmallStr::from_str(&c.value))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let index = schema
+            .iter_names()
+            .filter(|name| !on.contains(name))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // as in other SQL dialects, the rows with null values are excluded
+        let value_name = PlSmallStr::from_str(&value.value);
+        let args = UnpivotArgsDSL {
+            on: on.into_iter().map(|c| Selector::new(col(c))).collect(),
+            index: index.into_iter().map(|c| Selector::new(col(c))).collect(),
+            variable_name: Some(PlSmallStr::from_str(&name.value)),
+            value_name: Some(value_name.clone()),
+        };
+        Ok(lf.unpivot(args).filter(col(value_name).is_not_null()))
+    }
+
     fn execute_table_function(
         &mut self,
         name: &ObjectName,
@@ -1165,6 +2737,10 @@ impl SQLContext {
         let mut group_key_aliases = PlHashSet::new();
 
         for mut e in projections {
+            // Projections that are group_by keys (eg: referred to by an ordinal) are selected as is.
+            if group_by_keys.contains(e) {
+                continue;
+            }
             // `Len` represents COUNT(*) so we treat as an aggregation here.
             let is_agg_or_window = has_expr(e, |e| {
                 matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. })
@@ -1218,7 +2794,9 @@ impl SQLContext {
             .iter_names()
             .zip(projections)
             .map(|(name, projection_expr)| {
-                if let Some(expr) = projection_overrides.get(name.as_str()) {
+                if group_by_keys.contains(projection_expr) {
+                    col(name.clone())
+                } else if let Some(expr) = projection_overrides.get(name.as_str()) {
                     expr.clone()
                 } else if group_by_keys_schema.get(name).is_some()
                     || projection_aliases.contains(name.as_str())
@@ -1234,6 +2812,87 @@ impl SQLContext {
         Ok(aggregated.select(&final_projection))
     }
 
+    /// The grouping sets of a GROUP BY expression; ROLLUP, CUBE and GROUPING SETS have
+    /// multiple sets, other expressions are a (single) key.
+    fn group_by_expr_sets(
+        &mut self,
+        e: &SQLExpr,
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<Vec<Vec<Expr>>> {
+        let mut parse_groups = |groups: &[Vec<SQLExpr>]| {
+            groups
+                .iter()
+                .map(|group| {
+                    group
+                        .iter()
+                        .map(|e| {
+                            self.expr_or_ordinal(e, projections, None, Some(schema), "GROUP BY")
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        };
+        Ok(match e {
+            SQLExpr::Rollup(groups) => rollup_sets(parse_groups(groups)?),
+            SQLExpr::Cube(groups) => cube_sets(parse_groups(groups)?),
+            SQLExpr::GroupingSets(sets) => parse_groups(sets)?,
+            e => vec![vec![self.expr_or_ordinal(
+                e,
+                projections,
+                None,
+                Some(schema),
+                "GROUP BY",
+            )?]],
+        })
+    }
+
+    /// Aggregate each grouping set separately (with null values for the keys that are not
+    /// in the set, whose bitmask is the grouping id), and combine the results.
+    fn process_grouping_sets(
+        &mut self,
+        lf: LazyFrame,
+        schema: &SchemaRef,
+        group_by_keys: &[Expr],
+        grouping_sets: &[Vec<Expr>],
+        projections: &[Expr],
+    ) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            group_by_keys.len() < 32,
+            SQLInterface: "grouping sets support at most 31 GROUP BY keys (found {})",
+            group_by_keys.len()
+        );
+        let mut aggregated = Vec::with_capacity(grouping_sets.len());
+        for set in grouping_sets {
+            let mut keys = Vec::with_capacity(group_by_keys.len() + 1);
+            let mut set_projections = projections.to_vec();
+            let mut grouping_id = 0i32;
+            for (i, key) in group_by_keys.iter().enumerate() {
+                if set.contains(key) {
+                    keys.push(key.clone());
+                } else {
+                    // (projections that are the key itself select its null value)
+                    let field = key.to_field(schema, Context::Default)?;
+                    let null_key = lit(NULL).cast(field.dtype).alias(field.name);
+                    for p in set_projections.iter_mut().filter(|p| *p == key) {
+                        *p = null_key.clone();
+                    }
+                    keys.push(null_key);
+                    grouping_id |= 1 << i;
+                }
+            }
+            keys.push(col(GROUPING_ID));
+            set_projections.push(col(GROUPING_ID));
+            let set_lf = lf.clone().with_column(lit(grouping_id).alias(GROUPING_ID));
+            aggregated.push(self.process_group_by(set_lf, &keys, &set_projections)?);
+        }
+        let opts = UnionArgs {
+            parallel: true,
+            ..Default::default()
+        };
+        polars_lazy::dsl::concat(aggregated, opts)
+    }
+
     fn process_limit_offset(
         &self,
         lf: LazyFrame,
@@ -1445,6 +3104,356 @@ fn expand_exprs(expr: Expr, schema: &SchemaRef) -> Vec<Expr> {
     }
 }
 
+/// The value of GROUPING(...): the bitmask of its arguments that are rolled up in the
+/// grouping set of each row (the first argument is the most significant bit).
+fn grouping_mask(
+    args: &[Expr],
+    keys: &[Expr],
+    projections: &[Expr],
+    grouping_sets: bool,
+) -> PolarsResult<Expr> {
+    // keys given by an ordinal are the (aliased) projection they refer to, and arguments
+    // may refer to a projection by its alias, so they are compared without aliases
+    let unaliased = |e: &Expr| e.clone().meta().undo_aliases();
+    let keys = keys.iter().map(unaliased).collect::<Vec<_>>();
+    let mut mask = lit(0i32);
+    for (i, arg) in args.iter().enumerate() {
+        let arg = match arg {
+            Expr::Column(name) if !keys.contains(arg) => projections
+                .iter()
+                .find(|p| matches!(p, Expr::Alias(_, alias) if alias == name))
+                .unwrap_or(arg),
+            _ => arg,
+        };
+        let Some(position) = keys.iter().position(|key| *key == unaliased(arg)) else {
+            polars_bail!(SQLSyntax: "GROUPING arguments must be GROUP BY keys (found {:?})", arg)
+        };
+        if grouping_sets {
+            let rolled_up = col(GROUPING_ID).floor_div(lit(1i32 << position)) % lit(2i32);
+            mask = mask + rolled_up * lit(1i32 << (args.len() - 1 - i));
+        }
+    }
+    Ok(mask)
+}
+
+fn is_grouping_call(e: &Expr) -> bool {
+    matches!(e, Expr::Column(name) if name.starts_with(GROUPING_CALL_PREFIX))
+}
+
+/// Replace the placeholders of the GROUPING(...) calls of the projections, once the GROUP BY
+/// keys are known.
+fn resolve_grouping_calls(
+    projections: Vec<Expr>,
+    calls: &[Vec<Expr>],
+    grouping: &mut Grouping,
+) -> PolarsResult<Vec<Expr>> {
+    if calls.is_empty() {
+        return Ok(projections);
+    }
+    let masks = calls
+        .iter()
+        .map(|args| grouping.call(args.clone()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(projections
+        .into_iter()
+        .map(|p| {
+            p.map_expr(|e| match e {
+                Expr::Column(name) if name.starts_with(GROUPING_CALL_PREFIX) => {
+                    masks[name[GROUPING_CALL_PREFIX.len()..].parse::<usize>().unwrap()].clone()
+                },
+                e => e,
+            })
+        })
+        .collect())
+}
+
+/// The grouping sets of `ROLLUP(a, b, c)`: `(a, b, c), (a, b), (a), ()`.
+fn rollup_sets(groups: Vec<Vec<Expr>>) -> Vec<Vec<Expr>> {
+    (0..=groups.len())
+        .rev()
+        .map(|n| groups[..n].concat())
+        .collect()
+}
+
+/// The grouping sets of `CUBE(a, b)`: `(a, b), (a), (b), ()`.
+fn cube_sets(groups: Vec<Vec<Expr>>) -> Vec<Vec<Expr>> {
+    let n = groups.len();
+    (0..1usize << n)
+        .rev()
+        .map(|mask| {
+            groups
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << (n - 1 - i)) != 0)
+                .flat_map(|(_, group)| group.iter().cloned())
+                .collect()
+        })
+        .collect()
+}
+
+/// The aggregation and the aggregated column of a PIVOT, eg: `SUM(amount)`.
+fn parse_pivot_aggregate(expr: &SQLExpr) -> PolarsResult<(PivotAgg, PlSmallStr)> {
+    let SQLExpr::Function(Function {
+        name,
+        args: FunctionArguments::List(FunctionArgumentList { args, .. }),
+        ..
+    }) = expr
+    else {
+        polars_bail!(SQLSyntax: "PIVOT expects an aggregate function; found {}", expr)
+    };
+    let agg_fn = match name.to_string().to_lowercase().as_str() {
+        "avg" | "mean" => PivotAgg::Mean,
+        "count" => PivotAgg::Count,
+        "first" => PivotAgg::First,
+        "last" => PivotAgg::Last,
+        "max" => PivotAgg::Max,
+        "median" => PivotAgg::Median,
+        "min" => PivotAgg::Min,
+        "sum" => PivotAgg::Sum,
+        _ => polars_bail!(SQLInterface: "PIVOT does not support the {} aggregate", name),
+    };
+    let column = match args.as_slice() {
+        [FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Identifier(ident)))] => ident,
+        [FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::CompoundIdentifier(idents)))] => {
+            idents.last().unwrap()
+        },
+        _ => polars_bail!(SQLSyntax: "PIVOT expects {} to aggregate a single column", name),
+    };
+    Ok((agg_fn, PlSmallStr::from_str(&column.value)))
+}
+
+/// The aggregation of the values of a PIVOT.
+fn pivot_agg_expr(agg_fn: &PivotAgg, e: Expr) -> Expr {
+    match agg_fn {
+        PivotAgg::Count => e.count(),
+        PivotAgg::First => e.first(),
+        PivotAgg::Last => e.last(),
+        PivotAgg::Max => e.max(),
+        PivotAgg::Mean => e.mean(),
+        PivotAgg::Median => e.median(),
+        PivotAgg::Min => e.min(),
+        PivotAgg::Sum => e.sum(),
+        PivotAgg::Expr(_) => unreachable!(),
+    }
+}
+
+/// A subquery whose correlated predicates were rewritten as join keys.
+struct DecorrelatedSubquery {
+    query: Query,
+    // the outer sides of the correlated predicates
+    outer_keys: Vec<SQLExpr>,
+    // the (uncorrelated) subquery evaluated on no rows, for aggregated subqueries
+    empty_query: Option<Query>,
+}
+
+/// The value of a scalar subquery, which raises an error if the subquery returned more than
+/// one row (for the key of any row of the outer query).
+fn single_row_value(value: Expr, rows: Expr) -> Expr {
+    value.map_many(
+        |s| {
+            let rows = s[1].as_materialized_series().max::<IdxSize>()?;
+            polars_ensure!(
+                rows.map_or(true, |n| n <= 1),
+                SQLInterface: "more than one row returned by a subquery used as an expression"
+            );
+            Ok(Some(std::mem::take(&mut s[0])))
+        },
+        &[rows],
+        GetOutput::same_type(),
+    )
+}
+
+/// Raise an error if a row of the target of a MERGE matches more than one source row.
+fn merge_cardinality_guard(index: Expr, matches: Expr, tbl_name: &str) -> Expr {
+    let tbl_name = tbl_name.to_string();
+    index.map_many(
+        move |s| {
+            let matches = s[1].as_materialized_series().max::<IdxSize>()?;
+            polars_ensure!(
+                matches.map_or(true, |n| n <= 1),
+                SQLInterface: "MERGE cardinality violation: a row of '{}' matches more than one source row",
+                tbl_name
+            );
+            Ok(Some(std::mem::take(&mut s[0])))
+        },
+        &[matches],
+        GetOutput::same_type(),
+    )
+}
+
+/// The conjuncts of a predicate, eg: `a AND (b AND c)` -> `a, b, c`.
+fn split_conjunction(expr: &SQLExpr) -> Vec<&SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut conjuncts = split_conjunction(left);
+            conjuncts.extend(split_conjunction(right));
+            conjuncts
+        },
+        SQLExpr::Nested(expr) => split_conjunction(expr),
+        _ => vec![expr],
+    }
+}
+
+fn sql_and(left: SQLExpr, right: SQLExpr) -> SQLExpr {
+    SQLExpr::BinaryOp {
+        left: Box::new(left),
+        op: BinaryOperator::And,
+        right: Box::new(right),
+    }
+}
+
+/// The name (or alias) that refers to a table in the FROM clause.
+fn table_factor_name(relation: &TableFactor) -> Option<&str> {
+    match relation {
+        TableFactor::Table {
+            alias: Some(alias), ..
+        }
+        | TableFactor::Derived {
+            alias: Some(alias), ..
+        } => Some(alias.name.value.as_str()),
+        TableFactor::Table { name, .. } => name.0.last().map(|ident| ident.value.as_str()),
+        _ => None,
+    }
+}
+
+/// Whether a query body reads from the named table (in its FROM clauses).
+fn set_expr_references_table(expr: &SetExpr, name: &str) -> bool {
+    match expr {
+        SetExpr::Select(select) => select
+            .from
+            .iter()
+            .any(|tbl| table_with_joins_references_table(tbl, name)),
+        SetExpr::Query(query) => set_expr_references_table(&query.body, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            set_expr_references_table(left, name) || set_expr_references_table(right, name)
+        },
+        _ => false,
+    }
+}
+
+fn table_with_joins_references_table(tbl: &TableWithJoins, name: &str) -> bool {
+    std::iter::once(&tbl.relation)
+        .chain(tbl.joins.iter().map(|join| &join.relation))
+        .any(|relation| match relation {
+            TableFactor::Table { name: table, .. } => {
+                matches!(table.0.as_slice(), [ident] if ident.value == name)
+            },
+            TableFactor::Derived { subquery, .. } => {
+                set_expr_references_table(&subquery.body, name)
+            },
+            TableFactor::NestedJoin {
+                table_with_joins, ..
+            } => table_with_joins_references_table(table_with_joins, name),
+            _ => false,
+        })
+}
+
+/// Call `f` with the (compound) identifiers of an expression (excluding those of nested
+/// subqueries).
+fn visit_identifiers(expr: &SQLExpr, f: &mut impl FnMut(&[Ident])) {
+    let mut visit = |expr: &SQLExpr| visit_identifiers(expr, f);
+    match expr {
+        SQLExpr::Identifier(ident) => f(std::slice::from_ref(ident)),
+        SQLExpr::CompoundIdentifier(idents) => f(idents),
+        SQLExpr::BinaryOp { left, right, .. }
+        | SQLExpr::AnyOp { left, right, .. }
+        | SQLExpr::AllOp { left, right, .. }
+        | SQLExpr::IsDistinctFrom(left, right)
+        | SQLExpr::IsNotDistinctFrom(left, right) => {
+            visit(left);
+            visit(right);
+        },
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::Extract { expr, .. }
+        | SQLExpr::Ceil { expr, .. }
+        | SQLExpr::Floor { expr, .. }
+        | SQLExpr::Collate { expr, .. }
+        | SQLExpr::Subscript { expr, .. }
+        | SQLExpr::InSubquery { expr, .. }
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::IsNotTrue(expr)
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::IsNotFalse(expr) => visit(expr),
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => {
+            visit(expr);
+            visit(low);
+            visit(high);
+        },
+        SQLExpr::InList { expr, list, .. } => {
+            visit(expr);
+            list.iter().for_each(visit);
+        },
+        SQLExpr::Like { expr, pattern, .. }
+        | SQLExpr::ILike { expr, pattern, .. }
+        | SQLExpr::RLike { expr, pattern, .. }
+        | SQLExpr::SimilarTo { expr, pattern, .. } => {
+            visit(expr);
+            visit(pattern);
+        },
+        SQLExpr::Position { expr, r#in } => {
+            visit(expr);
+            visit(r#in);
+        },
+        SQLExpr::Substring {
+            expr,
+            substring_from,
+            substring_for,
+            ..
+        } => {
+            visit(expr);
+            substring_from.iter().for_each(|e| visit(e));
+            substring_for.iter().for_each(|e| visit(e));
+        },
+        SQLExpr::Trim {
+            expr,
+            trim_what,
+            trim_characters,
+            ..
+        } => {
+            visit(expr);
+            trim_what.iter().for_each(|e| visit(e));
+            trim_characters.iter().flatten().for_each(visit);
+        },
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            operand.iter().for_each(|e| visit(e));
+            conditions.iter().chain(results).for_each(&mut visit);
+            else_result.iter().for_each(|e| visit(e));
+        },
+        SQLExpr::Tuple(exprs) => exprs.iter().for_each(visit),
+        SQLExpr::Function(Function {
+            args: FunctionArguments::List(FunctionArgumentList { args, .. }),
+            ..
+        }) => {
+            for arg in args {
+                if let FunctionArg::Named {
+                    arg: FunctionArgExpr::Expr(expr),
+                    ..
+                }
+                | FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) = arg
+                {
+                    visit(expr)
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
 fn is_regex_colname(nm: &str) -> bool {
     nm.starts_with('^') && nm.ends_with('$')
 }
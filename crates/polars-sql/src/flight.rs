@@ -0,0 +1,45 @@
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+use crate::SQLContext;
+
+/// Serves the tables of a [`SQLContext`] with a [`FlightServer`].
+///
+/// A ticket is either the name of a registered table or a SQL query, which is executed in the
+/// context. Only the registered tables are listed with `ListFlights`.
+pub struct SQLFlightSource {
+    ctx: Mutex<SQLContext>,
+}
+
+impl SQLFlightSource {
+    /// Create a source that serves the tables of `ctx`.
+    pub fn new(ctx: SQLContext) -> Self {
+        Self {
+            ctx: Mutex::new(ctx),
+        }
+    }
+
+    fn resolve(&self, ticket: &str) -> PolarsResult<LazyFrame> {
+        let mut ctx = self.ctx.lock().unwrap();
+        match ctx.table_map.get(ticket) {
+            Some(lf) => Ok(lf.clone()),
+            None => ctx.execute(ticket),
+        }
+    }
+}
+
+impl FlightSource for SQLFlightSource {
+    fn tickets(&self) -> Vec<String> {
+        self.ctx.lock().unwrap().get_tables()
+    }
+
+    fn schema(&self, ticket: &str) -> PolarsResult<SchemaRef> {
+        self.resolve(ticket)?.collect_schema()
+    }
+
+    fn collect(&self, ticket: &str) -> PolarsResult<DataFrame> {
+        self.resolve(ticket)?.collect()
+    }
+}
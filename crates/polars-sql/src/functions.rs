@@ -3,23 +3,32 @@ use std::ops::Sub;
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::export::regex;
 use polars_core::prelude::{
-    polars_bail, polars_err, DataType, PolarsResult, QuantileInterpolOptions, Schema, TimeUnit,
+    polars_bail, polars_ensure, polars_err, DataType, IdxSize, NamedFrom, PolarsResult,
+    QuantileInterpolOptions, RollingOptionsFixedWindow, Schema, Series, TimeUnit, IDX_DTYPE,
 };
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
+use polars_ops::prelude::{RankMethod, RankOptions};
+use polars_plan::dsl::{
+    coalesce, concat_str, int_range, len, max_horizontal, min_horizontal, when,
+};
 use polars_plan::plans::{typed_lit, LiteralValue};
 use polars_plan::prelude::LiteralValue::Null;
 use polars_plan::prelude::{col, cols, lit, StrptimeOptions};
+use polars_time::prelude::{ClosedWindow, Duration, RollingOptionsDynamicWindow};
 use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "hash_digest")]
+use sha2::Digest;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrameBound, WindowFrameUnits, WindowSpec, WindowType,
 };
 
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::sql_expr::{
+    adjust_one_indexed_param, parse_extract_date_part, parse_interval, parse_sql_expr,
+};
 use crate::SQLContext;
 
 pub(crate) struct SQLFunctionVisitor<'a> {
@@ -314,6 +323,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT column_2 from df WHERE ENDS_WITH(column_1, 'a');
     /// ```
     EndsWith,
+    /// SQL 'format' function (also 'printf')
+    /// Returns the format string with each `%s` replaced by the next argument
+    /// (null arguments are formatted as empty strings), and `%%` by a literal `%`.
+    /// ```sql
+    /// SELECT FORMAT('%s-%s', column_1, column_2) FROM df;
+    /// ```
+    Format,
     /// SQL 'initcap' function
     /// Returns the value with the first letter capitalized.
     /// ```sql
@@ -339,24 +355,67 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT LOWER(column_1) FROM df;
     /// ```
     Lower,
+    /// SQL 'lpad' function
+    /// Pads the value on the left to the given length (with spaces, or the given
+    /// character); longer values are truncated to that length.
+    /// ```sql
+    /// SELECT LPAD(column_1, 5) FROM df;
+    /// SELECT LPAD(column_1, 5, '0') FROM df;
+    /// ```
+    LPad,
     /// SQL 'ltrim' function
     /// Strip whitespaces from the left.
     /// ```sql
     /// SELECT LTRIM(column_1) FROM df;
     /// ```
     LTrim,
+    /// SQL 'md5' function
+    /// Returns the (hex-encoded) MD5 hash of the value.
+    /// ```sql
+    /// SELECT MD5(column_1) FROM df;
+    /// ```
+    #[cfg(feature = "hash_digest")]
+    Md5,
     /// SQL 'octet_length' function
     /// Returns the length of a given string in bytes.
     /// ```sql
     /// SELECT OCTET_LENGTH(column_1) FROM df;
     /// ```
     OctetLength,
+    /// SQL 'regexp_count' function
+    /// Returns the number of times `pattern` matches the value (optional: `flags`).
+    /// ```sql
+    /// SELECT REGEXP_COUNT(column_1, '[aeiou]', 'i') FROM df;
+    /// ```
+    RegexpCount,
+    /// SQL 'regexp_extract' function (also 'regexp_substr')
+    /// Returns the first match of `pattern` in the value, or the given capture group
+    /// of that match; returns null if there is no match.
+    /// ```sql
+    /// SELECT REGEXP_EXTRACT(column_1, '\d+') FROM df;
+    /// SELECT REGEXP_EXTRACT(column_1, '(\w+)@(\w+)', 2) FROM df;
+    /// ```
+    RegexpExtract,
     /// SQL 'regexp_like' function
     /// True if `pattern` matches the value (optional: `flags`).
     /// ```sql
     /// SELECT REGEXP_LIKE(column_1, 'xyz', 'i') FROM df;
     /// ```
     RegexpLike,
+    /// SQL 'regexp_replace' function
+    /// Replace the first match of `pattern` with the replacement string (or all
+    /// matches, with the 'g' flag); capture groups can be referenced as `$1` or `\1`.
+    /// ```sql
+    /// SELECT REGEXP_REPLACE(column_1, '(\w+) (\w+)', '\2 \1') FROM df;
+    /// SELECT REGEXP_REPLACE(column_1, '[aeiou]', '', 'gi') FROM df;
+    /// ```
+    RegexpReplace,
+    /// SQL 'repeat' function
+    /// Returns the value repeated `n` times.
+    /// ```sql
+    /// SELECT REPEAT(column_1, 3) FROM df;
+    /// ```
+    Repeat,
     /// SQL 'replace' function
     /// Replace a given substring with another string.
     /// ```sql
@@ -375,12 +434,34 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT RIGHT(column_1, 3) FROM df;
     /// ```
     Right,
+    /// SQL 'rpad' function
+    /// Pads the value on the right to the given length (with spaces, or the given
+    /// character); longer values are truncated to that length.
+    /// ```sql
+    /// SELECT RPAD(column_1, 5) FROM df;
+    /// SELECT RPAD(column_1, 5, '.') FROM df;
+    /// ```
+    RPad,
     /// SQL 'rtrim' function
     /// Strip whitespaces from the right.
     /// ```sql
     /// SELECT RTRIM(column_1) FROM df;
     /// ```
     RTrim,
+    /// SQL 'sha256' function
+    /// Returns the (hex-encoded) SHA-256 hash of the value.
+    /// ```sql
+    /// SELECT SHA256(column_1) FROM df;
+    /// ```
+    #[cfg(feature = "hash_digest")]
+    Sha256,
+    /// SQL 'split_part' function
+    /// Splits the value on the delimiter, returning the `n`th part (counting from
+    /// the end if `n` is negative), or an empty string if there is no such part.
+    /// ```sql
+    /// SELECT SPLIT_PART(column_1, ',', 2) FROM df;
+    /// ```
+    SplitPart,
     /// SQL 'starts_with' function
     /// Returns True if the value starts with the second argument.
     /// ```sql
@@ -388,6 +469,12 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT column_2 from df WHERE STARTS_WITH(column_1, 'a');
     /// ```
     StartsWith,
+    /// SQL 'string_to_array' function
+    /// Splits the value on the delimiter, returning an array of the parts.
+    /// ```sql
+    /// SELECT STRING_TO_ARRAY(column_1, ',') FROM df;
+    /// ```
+    StringToArray,
     /// SQL 'strpos' function
     /// Returns the index of the given substring in the target string.
     /// ```sql
@@ -416,6 +503,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT TIME('20.30', '%H.%M') FROM df;
     /// ```
     Time,
+    /// SQL 'translate' function
+    /// Replaces each character of the value that is in `from` with the character at the
+    /// same position in `to` (or removes it, if `to` has no such character).
+    /// ```sql
+    /// SELECT TRANSLATE(column_1, 'abc', 'xy') FROM df;
+    /// ```
+    Translate,
     /// SQL 'upper' function
     /// Returns an uppercased column.
     /// ```sql
@@ -488,6 +582,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function
+    /// Returns a bitmask of the given GROUP BY keys that are not in the grouping set of
+    /// the row (e.g. for the subtotals of ROLLUP, CUBE and GROUPING SETS).
+    /// ```sql
+    /// SELECT column_1, column_2, GROUPING(column_1, column_2) FROM df GROUP BY ROLLUP(column_1, column_2);
+    /// ```
+    Grouping,
     /// SQL 'last' function
     /// Returns the last element of the grouping.
     /// ```sql
@@ -538,6 +639,82 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Variance,
 
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'row_number' function
+    /// Returns the number of the row within its partition, counting from 1.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+    /// SQL 'rank' function
+    /// Returns the rank of the row within its partition, with gaps after ties.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'dense_rank' function
+    /// Returns the rank of the row within its partition, without gaps after ties.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'percent_rank' function
+    /// Returns the relative rank of the row within its partition, (rank - 1) / (rows - 1).
+    /// ```sql
+    /// SELECT PERCENT_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    PercentRank,
+    /// SQL 'cume_dist' function
+    /// Returns the fraction of the rows of the partition that precede or are peers of the row.
+    /// ```sql
+    /// SELECT CUME_DIST() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    CumeDist,
+    /// SQL 'ntile' function
+    /// Divides the rows of the partition into the given number of buckets, as equally as
+    /// possible, and returns the bucket of the row (counting from 1).
+    /// ```sql
+    /// SELECT NTILE(4) OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    NTile,
+    /// SQL 'lag' function
+    /// Returns the value of the row that is the given number of rows (default 1) before the
+    /// row within its partition, or the default value (default NULL) if there is no such row.
+    /// ```sql
+    /// SELECT LAG(column_1) OVER (ORDER BY column_2) FROM df;
+    /// SELECT LAG(column_1, 2, 0) OVER (PARTITION BY column_3 ORDER BY column_2) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function
+    /// Returns the value of the row that is the given number of rows (default 1) after the
+    /// row within its partition, or the default value (default NULL) if there is no such row.
+    /// ```sql
+    /// SELECT LEAD(column_1) OVER (ORDER BY column_2) FROM df;
+    /// SELECT LEAD(column_1, 2, 0) OVER (PARTITION BY column_3 ORDER BY column_2) FROM df;
+    /// ```
+    Lead,
+    /// SQL 'first_value' function
+    /// Returns the first value of the partition.
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    FirstValue,
+    /// SQL 'last_value' function
+    /// Returns the last value of the partition.
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    LastValue,
+    /// SQL 'nth_value' function
+    /// Returns the value of the given row of the partition (counting from 1), or NULL if the
+    /// partition has fewer rows.
+    /// ```sql
+    /// SELECT NTH_VALUE(column_1, 2) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    NthValue,
+
     // ----
     // Array functions
     // ----
@@ -664,18 +841,26 @@ impl PolarsSQLFunctions {
             "cot",
             "cotd",
             "count",
+            "cume_dist",
             "date",
             "date_part",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
+            "first_value",
             "floor",
+            "format",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "last_value",
+            "lead",
             "least",
             "left",
             "length",
@@ -685,40 +870,58 @@ impl PolarsSQLFunctions {
             "log1p",
             "log2",
             "lower",
+            "lpad",
             "ltrim",
+            "md5",
             "max",
             "median",
             "min",
             "mod",
+            "nth_value",
+            "ntile",
             "nullif",
             "octet_length",
+            "percent_rank",
             "pi",
             "pow",
             "power",
+            "printf",
             "quantile_cont",
             "radians",
+            "rank",
+            "regexp_count",
+            "regexp_extract",
             "regexp_like",
+            "regexp_replace",
+            "regexp_substr",
+            "repeat",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
+            "rpad",
             "rtrim",
+            "sha256",
             "sign",
             "sin",
             "sind",
             "sqrt",
+            "split_part",
             "starts_with",
             "stddev",
             "stddev_samp",
             "stdev",
             "stdev_samp",
             "strftime",
+            "string_to_array",
             "strpos",
             "strptime",
             "substr",
             "sum",
             "tan",
             "tand",
+            "translate",
             "unnest",
             "upper",
             "var",
@@ -800,23 +1003,37 @@ impl PolarsSQLFunctions {
             "date" => Self::Date,
             "timestamp" | "datetime" => Self::Timestamp,
             "ends_with" => Self::EndsWith,
+            "format" | "printf" => Self::Format,
             #[cfg(feature = "nightly")]
             "initcap" => Self::InitCap,
             "length" | "char_length" | "character_length" => Self::Length,
             "left" => Self::Left,
             "lower" => Self::Lower,
+            "lpad" => Self::LPad,
             "ltrim" => Self::LTrim,
+            #[cfg(feature = "hash_digest")]
+            "md5" => Self::Md5,
             "octet_length" => Self::OctetLength,
             "strpos" => Self::StrPos,
+            "regexp_count" => Self::RegexpCount,
+            "regexp_extract" | "regexp_substr" => Self::RegexpExtract,
             "regexp_like" => Self::RegexpLike,
+            "regexp_replace" => Self::RegexpReplace,
+            "repeat" => Self::Repeat,
             "replace" => Self::Replace,
             "reverse" => Self::Reverse,
             "right" => Self::Right,
+            "rpad" => Self::RPad,
             "rtrim" => Self::RTrim,
+            #[cfg(feature = "hash_digest")]
+            "sha256" => Self::Sha256,
+            "split_part" => Self::SplitPart,
             "starts_with" => Self::StartsWith,
+            "string_to_array" => Self::StringToArray,
             "strptime" => Self::Strptime,
             "substr" => Self::Substring,
             "time" => Self::Time,
+            "translate" => Self::Translate,
             "upper" => Self::Upper,
 
             // ----
@@ -825,6 +1042,7 @@ impl PolarsSQLFunctions {
             "avg" => Self::Avg,
             "count" => Self::Count,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -834,6 +1052,21 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "cume_dist" => Self::CumeDist,
+            "dense_rank" => Self::DenseRank,
+            "first_value" => Self::FirstValue,
+            "lag" => Self::Lag,
+            "last_value" => Self::LastValue,
+            "lead" => Self::Lead,
+            "nth_value" => Self::NthValue,
+            "ntile" => Self::NTile,
+            "percent_rank" => Self::PercentRank,
+            "rank" => Self::Rank,
+            "row_number" => Self::RowNumber,
+
             // ----
             // Array functions
             // ----
@@ -1051,6 +1284,17 @@ impl SQLFunctionVisitor<'_> {
                 }
             },
             EndsWith => self.visit_binary(|e, s| e.str().ends_with(s)),
+            Format => {
+                let args = extract_args(function)?;
+                if args.is_empty() {
+                    polars_bail!(SQLSyntax: "FORMAT expects at least 1 argument (found 0)");
+                } else {
+                    self.try_visit_variadic(|exprs: &[Expr]| match &exprs[0] {
+                        Expr::Literal(LiteralValue::String(fmt)) => format_str(fmt, &exprs[1..]),
+                        _ => polars_bail!(SQLSyntax: "FORMAT 'format' must be a literal string (found {:?})", exprs[0]),
+                    })
+                }
+            },
             #[cfg(feature = "nightly")]
             InitCap => self.visit_unary(|e| e.str().to_titlecase()),
             Left => self.try_visit_binary(|e, length| {
@@ -1078,6 +1322,7 @@ impl SQLFunctionVisitor<'_> {
             }),
             Length => self.visit_unary(|e| e.str().len_chars()),
             Lower => self.visit_unary(|e| e.str().to_lowercase()),
+            LPad => self.visit_pad(true),
             LTrim => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1088,35 +1333,91 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             },
+            #[cfg(feature = "hash_digest")]
+            Md5 => self.visit_unary(hex_digest::<md5::Md5>),
             OctetLength => self.visit_unary(|e| e.str().len_bytes()),
             StrPos => {
                 // // note: SQL is 1-indexed; returns zero if no match found
                 self.visit_binary(|expr, substring| {
-                    (expr.str().find(substring, true) + typed_lit(1u32)).fill_null(typed_lit(0u32))
+                    (expr.str().find_literal(substring) + typed_lit(1u32))
+                        .fill_null(typed_lit(0u32))
                 })
             },
+            RegexpCount => {
+                let args = extract_args(function)?;
+                match args.len() {
+                    2 => self.visit_binary(|e, pat| e.str().count_matches(pat, false)),
+                    3 => self.try_visit_ternary(|e, pat, flags| {
+                        let pat = regex_with_flags(pat, flags, "REGEXP_COUNT")?;
+                        Ok(e.str().count_matches(pat, false))
+                    }),
+                    _ => polars_bail!(SQLSyntax: "REGEXP_COUNT expects 2-3 arguments (found {})", args.len()),
+                }
+            },
+            RegexpExtract => {
+                let args = extract_args(function)?;
+                match args.len() {
+                    2 => self.visit_binary(|e, pat| e.str().extract(pat, 0)),
+                    3 => self.try_visit_ternary(|e, pat, group| match group {
+                        Expr::Literal(LiteralValue::Int(n)) if n >= 0 => {
+                            Ok(e.str().extract(pat, n as usize))
+                        },
+                        _ => polars_bail!(SQLSyntax: "REGEXP_EXTRACT 'group' must be a non-negative integer (found {:?})", group),
+                    }),
+                    _ => polars_bail!(SQLSyntax: "REGEXP_EXTRACT expects 2-3 arguments (found {})", args.len()),
+                }
+            },
             RegexpLike => {
                 let args = extract_args(function)?;
                 match args.len() {
                     2 => self.visit_binary(|e, s| e.str().contains(s, true)),
                     3 => self.try_visit_ternary(|e, pat, flags| {
-                        Ok(e.str().contains(
-                            match (pat, flags) {
-                                (Expr::Literal(LiteralValue::String(s)), Expr::Literal(LiteralValue::String(f))) => {
-                                    if f.is_empty() {
-                                        polars_bail!(SQLSyntax: "invalid/empty 'flags' for REGEXP_LIKE ({})", args[2]);
-                                    };
-                                    lit(format!("(?{}){}", f, s))
-                                },
-                                _ => {
-                                    polars_bail!(SQLSyntax: "invalid arguments for REGEXP_LIKE ({}, {})", args[1], args[2]);
-                                },
-                            },
-                            true))
+                        Ok(e.str().contains(regex_with_flags(pat, flags, "REGEXP_LIKE")?, true))
                     }),
                     _ => polars_bail!(SQLSyntax: "REGEXP_LIKE expects 2-3 arguments (found {})",args.len()),
                 }
             },
+            RegexpReplace => {
+                let args = extract_args(function)?;
+                match args.len() {
+                    3 | 4 => self.try_visit_variadic(|exprs: &[Expr]| {
+                        let (e, mut pat) = (exprs[0].clone(), exprs[1].clone());
+                        let value = match &exprs[2] {
+                            Expr::Literal(LiteralValue::String(s)) => lit(convert_backrefs(s)),
+                            value => value.clone(),
+                        };
+                        // the 'g' flag replaces all matches; other flags apply to the pattern
+                        let mut replace_all = false;
+                        match exprs.get(3) {
+                            None => {},
+                            Some(Expr::Literal(LiteralValue::String(f))) => {
+                                replace_all = f.contains('g');
+                                let flags = f.replace('g', "");
+                                if !flags.is_empty() {
+                                    pat = regex_with_flags(pat, lit(flags), "REGEXP_REPLACE")?;
+                                }
+                            },
+                            Some(flags) => {
+                                polars_bail!(SQLSyntax: "REGEXP_REPLACE 'flags' must be a literal string (found {:?})", flags)
+                            },
+                        };
+                        Ok(if replace_all {
+                            e.str().replace_all(pat, value, false)
+                        } else {
+                            e.str().replace(pat, value, false)
+                        })
+                    }),
+                    _ => polars_bail!(SQLSyntax: "REGEXP_REPLACE expects 3-4 arguments (found {})", args.len()),
+                }
+            },
+            Repeat => self.visit_binary(|e: Expr, n: Expr| {
+                // note: a negative `n` repeats the value zero times (and a null value is
+                // null however many times it is repeated)
+                let n = when(n.clone().lt(lit(0))).then(lit(0)).otherwise(n);
+                when(e.clone().is_null())
+                    .then(lit(Null))
+                    .otherwise(e.repeat_by(n).list().join(lit(""), false))
+            }),
             Replace => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1152,6 +1453,7 @@ impl SQLFunctionVisitor<'_> {
                         )),
                 })
             }),
+            RPad => self.visit_pad(false),
             RTrim => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1162,7 +1464,34 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             },
+            #[cfg(feature = "hash_digest")]
+            Sha256 => self.visit_unary(hex_digest::<sha2::Sha256>),
+            SplitPart => {
+                let args = extract_args(function)?;
+                match args.len() {
+                    3 => self.try_visit_ternary(|e, sep, n| {
+                        // note: SQL is 1-indexed (and negative values count from the end)
+                        let idx = match n {
+                            Expr::Literal(LiteralValue::Int(n)) if n > 0 => n - 1,
+                            Expr::Literal(LiteralValue::Int(n)) if n < 0 => n,
+                            _ => polars_bail!(SQLSyntax: "SPLIT_PART 'n' must be a non-zero integer (found {:?})", n),
+                        };
+                        let part = e.clone().str().split(sep).list().get(lit(idx), true);
+                        Ok(when(e.is_null())
+                            .then(lit(Null))
+                            .otherwise(part.fill_null(lit(""))))
+                    }),
+                    _ => polars_bail!(SQLSyntax: "SPLIT_PART expects 3 arguments (found {})", args.len()),
+                }
+            },
             StartsWith => self.visit_binary(|e, s| e.str().starts_with(s)),
+            StringToArray => {
+                let args = extract_args(function)?;
+                match args.len() {
+                    2 => self.visit_binary(|e, sep| e.str().split(sep)),
+                    _ => polars_bail!(SQLSyntax: "STRING_TO_ARRAY expects 2 arguments (found {})", args.len()),
+                }
+            },
             Strptime => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1243,16 +1572,47 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "SUBSTR expects 2-3 arguments (found {})", args.len()),
                 }
             },
+            Translate => self.try_visit_ternary(|e, from, to| match (from, to) {
+                (
+                    Expr::Literal(LiteralValue::String(from)),
+                    Expr::Literal(LiteralValue::String(to)),
+                ) => {
+                    // map each character of 'from' to the one at the same position in 'to'
+                    // (or to nothing); repeated characters keep their first mapping
+                    let mut to_chars = to.chars();
+                    let (mut patterns, mut replacements) = (vec![], vec![]);
+                    for c in from.chars().map(String::from) {
+                        let replacement = to_chars.next().map(String::from).unwrap_or_default();
+                        if !patterns.contains(&c) {
+                            patterns.push(c);
+                            replacements.push(replacement);
+                        }
+                    }
+                    Ok(if patterns.is_empty() {
+                        e
+                    } else {
+                        e.str().replace_many(
+                            lit(Series::new(PlSmallStr::EMPTY, patterns)),
+                            lit(Series::new(PlSmallStr::EMPTY, replacements)),
+                            false,
+                        )
+                    })
+                },
+                (from, to) => {
+                    polars_bail!(SQLSyntax: "TRANSLATE 'from' and 'to' must be literal strings (found {:?}, {:?})", from, to)
+                },
+            }),
             Upper => self.visit_unary(|e| e.str().to_uppercase()),
 
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_frame_agg(FrameAgg::Mean),
             Count => self.visit_count(),
-            First => self.visit_unary(Expr::first),
-            Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
+            First => self.visit_frame_value(FrameValue::First),
+            Grouping => self.visit_grouping(),
+            Last => self.visit_frame_value(FrameValue::Last),
+            Max => self.visit_frame_agg(FrameAgg::Max),
             Median => self.visit_unary(Expr::median),
             QuantileCont => {
                 let args = extract_args(function)?;
@@ -1280,11 +1640,41 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "QUANTILE_CONT expects 2 arguments (found {})", args.len()),
                 }
             },
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
+            Min => self.visit_frame_agg(FrameAgg::Min),
             StdDev => self.visit_unary(|e| e.std(1)),
-            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
+            Sum => self.visit_frame_agg(FrameAgg::Sum),
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            RowNumber => self.visit_row_number(),
+            Rank => self.visit_rank(|peers| (peers.start + lit(1i64)).cast(IDX_DTYPE)),
+            DenseRank => self.visit_rank(|peers| {
+                let options = RankOptions {
+                    method: RankMethod::Dense,
+                    descending: false,
+                };
+                peers.start.rank(options, None)
+            }),
+            PercentRank => self.visit_rank(|peers| {
+                when(len().gt(lit(1)))
+                    .then(
+                        peers.start.cast(DataType::Float64)
+                            / (len().cast(DataType::Float64) - lit(1.0)),
+                    )
+                    .otherwise(lit(0.0))
+            }),
+            CumeDist => self.visit_rank(|peers| {
+                (peers.end + lit(1i64)).cast(DataType::Float64) / len().cast(DataType::Float64)
+            }),
+            NTile => self.visit_ntile(),
+            Lag => self.visit_offset(1),
+            Lead => self.visit_offset(-1),
+            FirstValue => self.visit_value(FrameValue::First),
+            LastValue => self.visit_value(FrameValue::Last),
+            NthValue => self.visit_nth_value(),
+
             // ----
             // Array functions
             // ----
@@ -1374,45 +1764,6 @@ impl SQLFunctionVisitor<'_> {
             .call(args)
     }
 
-    /// Window specs without partition bys are essentially cumulative functions
-    /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
-    fn apply_cumulative_window(
-        &mut self,
-        f: impl Fn(Expr) -> Expr,
-        cumulative_f: impl Fn(Expr, bool) -> Expr,
-        WindowSpec {
-            partition_by,
-            order_by,
-            ..
-        }: &WindowSpec,
-    ) -> PolarsResult<Expr> {
-        if !order_by.is_empty() && partition_by.is_empty() {
-            let (order_by, desc): (Vec<Expr>, Vec<bool>) = order_by
-                .iter()
-                .map(|o| {
-                    let expr = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
-                    Ok(match o.asc {
-                        Some(b) => (expr, !b),
-                        None => (expr, false),
-                    })
-                })
-                .collect::<PolarsResult<Vec<_>>>()?
-                .into_iter()
-                .unzip();
-            self.visit_unary_no_window(|e| {
-                cumulative_f(
-                    e.sort_by(
-                        &order_by,
-                        SortMultipleOptions::default().with_order_descending_multi(desc.clone()),
-                    ),
-                    false,
-                )
-            })
-        } else {
-            self.visit_unary(f)
-        }
-    }
-
     fn visit_unary(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
         self.try_visit_unary(|e| Ok(f(e)))
     }
@@ -1433,28 +1784,6 @@ impl SQLFunctionVisitor<'_> {
         .and_then(|e| self.apply_window_spec(e, &self.func.over))
     }
 
-    /// Some functions have cumulative equivalents that can be applied to window specs
-    /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
-    /// visit_unary_with_cumulative_window will take in a function & a cumulative function
-    /// if there is a cumulative window spec, it will apply the cumulative function,
-    /// otherwise it will apply the function
-    fn visit_unary_with_opt_cumulative(
-        &mut self,
-        f: impl Fn(Expr) -> Expr,
-        cumulative_f: impl Fn(Expr, bool) -> Expr,
-    ) -> PolarsResult<Expr> {
-        match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
-            },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
-            _ => self.visit_unary(f),
-        }
-    }
-
     fn visit_unary_no_window(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
         let args = extract_args(self.func)?;
         match args.as_slice() {
@@ -1526,6 +1855,39 @@ impl SQLFunctionVisitor<'_> {
         }
     }
 
+    fn visit_pad(&mut self, left: bool) -> PolarsResult<Expr> {
+        let func_name = if left { "LPAD" } else { "RPAD" };
+        let args = extract_args(self.func)?;
+        if !(2..=3).contains(&args.len()) {
+            polars_bail!(SQLSyntax: "{} expects 2-3 arguments (found {})", func_name, args.len());
+        }
+        self.try_visit_variadic(|exprs: &[Expr]| {
+            let length = match &exprs[1] {
+                Expr::Literal(LiteralValue::Int(n)) if *n >= 0 => *n as usize,
+                length => {
+                    polars_bail!(SQLSyntax: "{} 'length' must be a non-negative integer (found {:?})", func_name, length)
+                },
+            };
+            let fill_char = match exprs.get(2) {
+                None => ' ',
+                Some(Expr::Literal(LiteralValue::String(s))) if s.chars().count() == 1 => {
+                    s.chars().next().unwrap()
+                },
+                Some(fill) => {
+                    polars_bail!(SQLSyntax: "{} 'fill' must be a single character (found {:?})", func_name, fill)
+                },
+            };
+            let e = exprs[0].clone().str();
+            let padded = if left {
+                e.pad_start(length, fill_char)
+            } else {
+                e.pad_end(length, fill_char)
+            };
+            // note: values that are already longer than 'length' are truncated
+            Ok(padded.str().head(lit(length as u64)))
+        })
+    }
+
     fn visit_nullary(&self, f: impl Fn() -> Expr) -> PolarsResult<Expr> {
         let args = extract_args(self.func)?;
         if !args.is_empty() {
@@ -1604,24 +1966,60 @@ impl SQLFunctionVisitor<'_> {
         let (args, is_distinct) = extract_args_distinct(self.func)?;
         match (is_distinct, args.as_slice()) {
             // count(*), count()
-            (false, [FunctionArgExpr::Wildcard] | []) => Ok(len()),
+            (false, [FunctionArgExpr::Wildcard] | []) => match self.func.over {
+                None => Ok(len()),
+                // count the rows of the window frame
+                Some(_) => self.apply_window_frame_agg(
+                    FrameAgg::Count,
+                    int_range(typed_lit(0 as IdxSize), len(), 1, IDX_DTYPE),
+                ),
+            },
             // count(column_name)
             (false, [FunctionArgExpr::Expr(sql_expr)]) => {
                 let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
-                let expr = self.apply_window_spec(expr, &self.func.over)?;
-                Ok(expr.count())
+                self.apply_window_frame_agg(FrameAgg::Count, expr)
             },
             // count(distinct column_name)
             (true, [FunctionArgExpr::Expr(sql_expr)]) => {
                 let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
-                let expr = self.apply_window_spec(expr, &self.func.over)?;
-                Ok(expr.clone().n_unique().sub(expr.null_count().gt(lit(0))))
+                let expr = expr.clone().n_unique().sub(expr.null_count().gt(lit(0)));
+                self.apply_window_spec(expr, &self.func.over)
             },
             _ => self.not_supported_error(),
         }
     }
 
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        polars_ensure!(
+            !args.is_empty() && self.func.over.is_none(),
+            SQLSyntax: "GROUPING expects one or more GROUP BY keys (found {})", self.func
+        );
+        let mut keys = Vec::with_capacity(args.len());
+        for arg in &args {
+            match arg {
+                FunctionArgExpr::Expr(sql_expr) => {
+                    keys.push(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?)
+                },
+                _ => return self.not_supported_error(),
+            }
+        }
+        let mut grouping = self.ctx.grouping.borrow_mut();
+        let Some(grouping) = grouping.as_mut() else {
+            polars_bail!(SQLSyntax: "GROUPING requires a GROUP BY clause")
+        };
+        Ok(grouping.call(keys)?.alias("grouping"))
+    }
+
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
+        let (by, options) = self.parse_order_by(order_by)?;
+        Ok(expr.sort_by(by, options))
+    }
+
+    fn parse_order_by(
+        &mut self,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<(Vec<Expr>, SortMultipleOptions)> {
         let mut by = Vec::with_capacity(order_by.len());
         let mut descending = Vec::with_capacity(order_by.len());
         let mut nulls_last = Vec::with_capacity(order_by.len());
@@ -1634,7 +2032,7 @@ impl SQLFunctionVisitor<'_> {
             nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
             descending.push(desc_order);
         }
-        Ok(expr.sort_by(
+        Ok((
             by,
             SortMultipleOptions::default()
                 .with_order_descending_multi(descending)
@@ -1643,39 +2041,383 @@ impl SQLFunctionVisitor<'_> {
         ))
     }
 
-    fn apply_window_spec(
+    /// The window spec of a window function, which must have an OVER clause.
+    fn window_spec(&self) -> PolarsResult<WindowSpec> {
+        match &self.func.over {
+            Some(window) => self.ctx.resolve_window(window),
+            None => polars_bail!(SQLSyntax: "{} requires an OVER clause", self.func.name),
+        }
+    }
+
+    fn parse_partition_by(&mut self, spec: &WindowSpec) -> PolarsResult<Vec<Expr>> {
+        spec.partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect()
+    }
+
+    /// Evaluate a window function in the partitions of its window, if any.
+    fn apply_partition_by(&mut self, expr: Expr, spec: &WindowSpec) -> PolarsResult<Expr> {
+        Ok(if spec.partition_by.is_empty() {
+            expr
+        } else {
+            expr.over(self.parse_partition_by(spec)?)
+        })
+    }
+
+    /// Evaluate an aggregation in the partitions of its window, and broadcast it over all
+    /// the rows of the partition.
+    fn apply_partition_by_agg(&mut self, expr: Expr, spec: &WindowSpec) -> PolarsResult<Expr> {
+        let partition_by = if spec.partition_by.is_empty() {
+            vec![lit(true)]
+        } else {
+            self.parse_partition_by(spec)?
+        };
+        Ok(expr.over(partition_by))
+    }
+
+    /// The indices that sort the rows by the ORDER BY of the window (if any), and the
+    /// sorted ORDER BY values.
+    fn window_permutation(&mut self, spec: &WindowSpec) -> PolarsResult<Option<(Expr, Vec<Expr>)>> {
+        if spec.order_by.is_empty() {
+            return Ok(None);
+        }
+        let (by, options) = self.parse_order_by(&spec.order_by)?;
+        let perm =
+            int_range(typed_lit(0 as IdxSize), len(), 1, IDX_DTYPE).sort_by(by.clone(), options);
+        let sorted_by = by.into_iter().map(|e| e.gather(perm.clone())).collect();
+        Ok(Some((perm, sorted_by)))
+    }
+
+    /// The (zero-based) position of the rows in the order of the window.
+    fn window_row_index(&mut self, spec: &WindowSpec) -> PolarsResult<Expr> {
+        Ok(match self.window_permutation(spec)? {
+            Some((perm, _)) => perm.arg_sort(SortOptions::default()),
+            None => int_range(typed_lit(0 as IdxSize), len(), 1, IDX_DTYPE),
+        })
+    }
+
+    /// Evaluate `f` on the values in the order of the window (given the sorted ORDER BY
+    /// values), restore the order of the rows, and evaluate it in the partitions.
+    fn apply_window_order(
         &mut self,
         expr: Expr,
-        window_type: &Option<WindowType>,
+        spec: &WindowSpec,
+        f: impl FnOnce(Expr, &[Expr]) -> Expr,
     ) -> PolarsResult<Expr> {
-        Ok(match &window_type {
-            Some(WindowType::WindowSpec(window_spec)) => {
-                if window_spec.partition_by.is_empty() {
-                    let exprs = window_spec
-                        .order_by
-                        .iter()
-                        .map(|o| {
-                            let e = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
-                            Ok(o.asc.map_or(e.clone(), |b| {
-                                e.sort(SortOptions::default().with_order_descending(!b))
-                            }))
-                        })
-                        .collect::<PolarsResult<Vec<_>>>()?;
-                    expr.over(exprs)
+        let expr = match self.window_permutation(spec)? {
+            Some((perm, by)) => {
+                f(expr.gather(perm.clone()), &by).gather(perm.arg_sort(SortOptions::default()))
+            },
+            None => f(expr, &[]),
+        };
+        self.apply_partition_by(expr, spec)
+    }
+
+    fn visit_row_number(&mut self) -> PolarsResult<Expr> {
+        let spec = self.window_spec()?;
+        if !extract_args(self.func)?.is_empty() {
+            return self.not_supported_error();
+        }
+        let row_index = self.window_row_index(&spec)?;
+        self.apply_partition_by(row_index + typed_lit(1 as IdxSize), &spec)
+    }
+
+    /// Ranking functions rank the rows by the positions of their first and last peers
+    /// in the order of the window (so null ORDER BY values are ranked like other values).
+    fn visit_rank(&mut self, f: impl FnOnce(FramePositions) -> Expr) -> PolarsResult<Expr> {
+        let spec = self.window_spec()?;
+        if !extract_args(self.func)?.is_empty() {
+            return self.not_supported_error();
+        }
+        let Some((perm, by)) = self.window_permutation(&spec)? else {
+            polars_bail!(SQLSyntax: "{} requires an ORDER BY in its OVER clause", self.func.name)
+        };
+        let peers = FramePositions::new(FrameBound::Peer, FrameBound::Peer, &by);
+        let rank = f(peers).gather(perm.arg_sort(SortOptions::default()));
+        self.apply_partition_by(rank, &spec)
+    }
+
+    fn visit_ntile(&mut self) -> PolarsResult<Expr> {
+        let spec = self.window_spec()?;
+        let args = extract_args(self.func)?;
+        let n = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => {
+                match parse_sql_expr(sql_expr, self.ctx, self.active_schema)? {
+                    Expr::Literal(LiteralValue::Int(n)) if n > 0 => n as i64,
+                    _ => {
+                        polars_bail!(SQLSyntax: "NTILE expects a positive integer; found {}", args[0])
+                    },
+                }
+            },
+            _ => polars_bail!(SQLSyntax: "NTILE expects 1 argument (found {})", args.len()),
+        };
+        // the first `rows % n` buckets have one row more than the others
+        let row_index = self.window_row_index(&spec)?.cast(DataType::Int64);
+        let rows = len().cast(DataType::Int64);
+        let size = rows.clone().floor_div(lit(n));
+        let larger = rows % lit(n);
+        let larger_rows = larger.clone() * (size.clone() + lit(1));
+        let bucket = when(row_index.clone().lt(larger_rows.clone()))
+            .then(row_index.clone().floor_div(size.clone() + lit(1)))
+            .otherwise(larger + (row_index - larger_rows).floor_div(size));
+        self.apply_partition_by(bucket + lit(1), &spec)
+    }
+
+    /// LAG and LEAD shift the values in the order of the window.
+    fn visit_offset(&mut self, direction: i64) -> PolarsResult<Expr> {
+        let spec = self.window_spec()?;
+        let args = extract_args(self.func)?;
+        let mut exprs = vec![];
+        for arg in &args {
+            match arg {
+                FunctionArgExpr::Expr(sql_expr) => {
+                    exprs.push(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?)
+                },
+                _ => return self.not_supported_error(),
+            }
+        }
+        let mut exprs = exprs.into_iter();
+        let (Some(expr), offset, default, None) =
+            (exprs.next(), exprs.next(), exprs.next(), exprs.next())
+        else {
+            polars_bail!(SQLSyntax: "{} expects 1-3 arguments (found {})", self.func.name, args.len())
+        };
+        let offset = match offset {
+            None => 1,
+            Some(Expr::Literal(LiteralValue::Int(n))) if n >= 0 => n as i64,
+            Some(_) => polars_bail!(
+                SQLSyntax: "{} expects a non-negative integer offset; found {}", self.func.name, args[1]
+            ),
+        };
+        let shift = |e: Expr| match default {
+            Some(default) => e.shift_and_fill(lit(offset * direction), default),
+            None => e.shift(lit(offset * direction)),
+        };
+        self.apply_window_order(expr, &spec, |e, _| shift(e))
+    }
+
+    /// The frame of the window, i.e. the rows of the partition that an aggregation is
+    /// evaluated over for every row.
+    fn parse_window_frame(&mut self, spec: &WindowSpec) -> PolarsResult<Frame> {
+        use WindowFrameBound::*;
+        let ordered = !spec.order_by.is_empty();
+        let Some(frame) = &spec.window_frame else {
+            // the default frame is 'RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW'
+            return Ok(if ordered {
+                Frame::Between(FrameBound::Unbounded, FrameBound::Peer)
+            } else {
+                Frame::Partition
+            });
+        };
+        let end_bound = frame.end_bound.as_ref().unwrap_or(&CurrentRow);
+        let parsed = match frame.units {
+            WindowFrameUnits::Rows => match (&frame.start_bound, end_bound) {
+                (Following(None), _) | (_, Preceding(None)) => {
+                    polars_bail!(SQLSyntax: "invalid window frame ({})", spec)
+                },
+                (start, end) => {
+                    Frame::Between(self.parse_frame_bound(start)?, self.parse_frame_bound(end)?)
+                },
+            },
+            WindowFrameUnits::Range => {
+                // without an ORDER BY, all the rows of the partition are peers
+                let peer = if ordered {
+                    FrameBound::Peer
                 } else {
-                    // Process for simple window specification, partition by first
-                    let partition_by = window_spec
-                        .partition_by
-                        .iter()
-                        .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
-                        .collect::<PolarsResult<Vec<_>>>()?;
-                    expr.over(partition_by)
+                    FrameBound::Unbounded
+                };
+                match (&frame.start_bound, end_bound) {
+                    (Preceding(None), CurrentRow) => Frame::Between(FrameBound::Unbounded, peer),
+                    (Preceding(None), Following(None)) => Frame::Partition,
+                    (CurrentRow, CurrentRow) => Frame::Between(peer, peer),
+                    (CurrentRow, Following(None)) => Frame::Between(peer, FrameBound::Unbounded),
+                    (Preceding(Some(offset)), CurrentRow) => {
+                        polars_ensure!(
+                            spec.order_by.len() == 1 && spec.order_by[0].asc != Some(false),
+                            SQLInterface: "RANGE frames with an offset require a single ascending ORDER BY value"
+                        );
+                        Frame::Range(self.parse_range_offset(offset)?)
+                    },
+                    _ => polars_bail!(
+                        SQLInterface: "RANGE frames with an offset must end at the CURRENT ROW ({})",
+                        spec
+                    ),
                 }
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
+            WindowFrameUnits::Groups => {
+                polars_bail!(SQLInterface: "GROUPS window frames are not currently supported")
+            },
+        };
+        Ok(match parsed {
+            Frame::Between(FrameBound::Unbounded, FrameBound::Unbounded) => Frame::Partition,
+            parsed => parsed,
+        })
+    }
+
+    fn parse_frame_bound(&mut self, bound: &WindowFrameBound) -> PolarsResult<FrameBound> {
+        let (offset, sign) = match bound {
+            WindowFrameBound::CurrentRow => return Ok(FrameBound::Offset(0)),
+            WindowFrameBound::Preceding(None) | WindowFrameBound::Following(None) => {
+                return Ok(FrameBound::Unbounded)
+            },
+            WindowFrameBound::Preceding(Some(offset)) => (offset, -1),
+            WindowFrameBound::Following(Some(offset)) => (offset, 1),
+        };
+        match parse_sql_expr(offset, self.ctx, self.active_schema)? {
+            Expr::Literal(LiteralValue::Int(n)) if n >= 0 => {
+                Ok(FrameBound::Offset(sign * n as i64))
+            },
+            _ => polars_bail!(
+                SQLSyntax: "ROWS frame offsets must be non-negative integers; found {}", offset
             ),
+        }
+    }
+
+    fn parse_range_offset(&mut self, offset: &SQLExpr) -> PolarsResult<Duration> {
+        if let SQLExpr::Interval(interval) = offset {
+            return parse_interval(interval);
+        }
+        match parse_sql_expr(offset, self.ctx, self.active_schema)? {
+            Expr::Literal(LiteralValue::Int(n)) if n >= 0 => Ok(Duration::parse(&format!("{n}i"))),
+            _ => polars_bail!(
+                SQLSyntax: "RANGE frame offsets must be intervals or non-negative integers; found {}",
+                offset
+            ),
+        }
+    }
+
+    fn visit_frame_agg(&mut self, agg: FrameAgg) -> PolarsResult<Expr> {
+        let expr = self.visit_unary_no_window(|e| e)?;
+        self.apply_window_frame_agg(agg, expr)
+    }
+
+    /// Aggregations with an OVER clause are evaluated over the frame of their window:
+    /// cumulatively if it is unbounded, and as a rolling aggregation otherwise.
+    fn apply_window_frame_agg(&mut self, agg: FrameAgg, expr: Expr) -> PolarsResult<Expr> {
+        if self.func.over.is_none() {
+            return Ok(agg.all(expr));
+        }
+        let spec = self.window_spec()?;
+        match self.parse_window_frame(&spec)? {
+            Frame::Partition => self.apply_partition_by_agg(agg.all(expr), &spec),
+            Frame::Range(window_size) => self.apply_window_order(expr, &spec, |e, by| {
+                agg.rolling_by(e, by[0].clone(), window_size)
+            }),
+            Frame::Between(start, end) => {
+                let window_size = match (start, end) {
+                    (FrameBound::Unbounded, _) | (_, FrameBound::Unbounded) => None,
+                    (FrameBound::Offset(start), FrameBound::Offset(end)) if start <= end => {
+                        Some((end - start + 1) as usize)
+                    },
+                    _ => polars_bail!(
+                        SQLInterface: "unsupported window frame for {} ({})", self.func.name, spec
+                    ),
+                };
+                self.apply_window_order(expr, &spec, |e, by| {
+                    let frame = FramePositions::new(start, end, by);
+                    let value = match (start, window_size) {
+                        (FrameBound::Unbounded, _) => agg
+                            .cumulative(e, false)
+                            .gather(frame.clip(frame.end.clone())),
+                        // frames that are cut off by the end of the partition are aggregated
+                        // (in reverse) from their start instead
+                        (_, Some(window_size)) => {
+                            when(frame.end.clone().gt(FramePositions::last()))
+                                .then(
+                                    agg.cumulative(e.clone(), true)
+                                        .gather(frame.clip(frame.start.clone())),
+                                )
+                                .otherwise(
+                                    agg.rolling(e, window_size)
+                                        .gather(frame.clip(frame.end.clone())),
+                                )
+                        },
+                        (_, None) => agg
+                            .cumulative(e, true)
+                            .gather(frame.clip(frame.start.clone())),
+                    };
+                    when(frame.is_empty()).then(agg.empty()).otherwise(value)
+                })
+            },
+        }
+    }
+
+    fn visit_frame_value(&mut self, value: FrameValue) -> PolarsResult<Expr> {
+        let expr = self.visit_unary_no_window(|e| e)?;
+        self.apply_window_frame_value(value, expr)
+    }
+
+    fn visit_value(&mut self, value: FrameValue) -> PolarsResult<Expr> {
+        self.window_spec()?;
+        self.visit_frame_value(value)
+    }
+
+    fn visit_nth_value(&mut self) -> PolarsResult<Expr> {
+        self.window_spec()?;
+        let args = extract_args(self.func)?;
+        let (expr, n) = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr1), FunctionArgExpr::Expr(sql_expr2)] => {
+                let expr = parse_sql_expr(sql_expr1, self.ctx, self.active_schema)?;
+                match parse_sql_expr(sql_expr2, self.ctx, self.active_schema)? {
+                    Expr::Literal(LiteralValue::Int(n)) if n > 0 => (expr, n as i64),
+                    _ => polars_bail!(
+                        SQLSyntax: "NTH_VALUE expects a positive integer; found {}", args[1]
+                    ),
+                }
+            },
+            _ => polars_bail!(SQLSyntax: "NTH_VALUE expects 2 arguments (found {})", args.len()),
+        };
+        self.apply_window_frame_value(FrameValue::Nth(n), expr)
+    }
+
+    /// FIRST_VALUE, LAST_VALUE and NTH_VALUE take the value of a row of the frame of
+    /// their window, in the order of the window.
+    fn apply_window_frame_value(&mut self, value: FrameValue, expr: Expr) -> PolarsResult<Expr> {
+        if self.func.over.is_none() {
+            return Ok(value.all(expr));
+        }
+        let spec = self.window_spec()?;
+        match self.parse_window_frame(&spec)? {
+            Frame::Partition => {
+                let expr = if spec.order_by.is_empty() {
+                    expr
+                } else {
+                    self.apply_order_by(expr, &spec.order_by)?
+                };
+                self.apply_partition_by_agg(value.all(expr), &spec)
+            },
+            Frame::Range(_) => polars_bail!(
+                SQLInterface: "RANGE frames with an offset are not supported for {}", self.func.name
+            ),
+            Frame::Between(start, end) => self.apply_window_order(expr, &spec, |e, by| {
+                let frame = FramePositions::new(start, end, by);
+                let end = frame.clip(frame.end.clone());
+                let idx = value.position(frame.clip(frame.start.clone()), end.clone());
+                when(frame.is_empty().or(idx.clone().gt(end)))
+                    .then(lit(Null))
+                    .otherwise(e.gather(frame.clip(idx)))
+            }),
+        }
+    }
+
+    /// Functions that are not evaluated over a window frame are evaluated over the whole
+    /// partition of their window; the ORDER BY of the window (if any) doesn't change the result.
+    fn apply_window_spec(
+        &mut self,
+        expr: Expr,
+        window_type: &Option<WindowType>,
+    ) -> PolarsResult<Expr> {
+        Ok(match &window_type {
+            Some(window) => {
+                let window_spec = self.ctx.resolve_window(window)?;
+                polars_ensure!(
+                    window_spec.window_frame.is_none(),
+                    SQLInterface: "{} does not support a frame in its OVER clause",
+                    self.func.name
+                );
+                self.apply_partition_by_agg(expr, &window_spec)?
+            },
             None => expr,
         })
     }
@@ -1689,6 +2431,261 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// An aggregation over the frame of a window.
+#[derive(Clone, Copy)]
+enum FrameAgg {
+    Count,
+    Max,
+    Mean,
+    Min,
+    Sum,
+}
+
+impl FrameAgg {
+    fn all(self, e: Expr) -> Expr {
+        match self {
+            Self::Count => e.count(),
+            Self::Max => e.max(),
+            Self::Mean => e.mean(),
+            Self::Min => e.min(),
+            Self::Sum => e.sum(),
+        }
+    }
+
+    /// The aggregation from the first row up to every row (or from every row up to the
+    /// last row, if `reverse`).
+    fn cumulative(self, e: Expr, reverse: bool) -> Expr {
+        // cumulative aggregations are null where the values are null
+        let fill = |e: Expr| {
+            if reverse {
+                e.backward_fill(None)
+            } else {
+                e.forward_fill(None)
+            }
+        };
+        match self {
+            Self::Count => e.cum_count(reverse),
+            Self::Max => fill(e.cum_max(reverse)),
+            Self::Mean => {
+                fill(e.clone().cum_sum(reverse)).cast(DataType::Float64)
+                    / e.cum_count(reverse).cast(DataType::Float64)
+            },
+            Self::Min => fill(e.cum_min(reverse)),
+            Self::Sum => fill(e.cum_sum(reverse)),
+        }
+    }
+
+    /// The aggregation over the `window_size` rows up to every row.
+    fn rolling(self, e: Expr, window_size: usize) -> Expr {
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods: 1,
+            ..Default::default()
+        };
+        match self {
+            Self::Count => e.is_not_null().cast(IDX_DTYPE).rolling_sum(options),
+            Self::Max => e.rolling_max(options),
+            Self::Mean => e.rolling_mean(options),
+            Self::Min => e.rolling_min(options),
+            Self::Sum => e.rolling_sum(options),
+        }
+    }
+
+    /// The aggregation over the rows whose (sorted) `by` value is within `window_size`
+    /// before the value of every row.
+    fn rolling_by(self, e: Expr, by: Expr, window_size: Duration) -> Expr {
+        let options = RollingOptionsDynamicWindow {
+            window_size,
+            min_periods: 1,
+            closed_window: ClosedWindow::Both,
+            fn_params: None,
+        };
+        match self {
+            Self::Count => e.is_not_null().cast(IDX_DTYPE).rolling_sum_by(by, options),
+            Self::Max => e.rolling_max_by(by, options),
+            Self::Mean => e.rolling_mean_by(by, options),
+            Self::Min => e.rolling_min_by(by, options),
+            Self::Sum => e.rolling_sum_by(by, options),
+        }
+    }
+
+    /// The aggregation over an empty frame.
+    fn empty(self) -> Expr {
+        match self {
+            Self::Count => typed_lit(0 as IdxSize),
+            _ => lit(Null),
+        }
+    }
+}
+
+/// The value of a row of the frame of a window.
+#[derive(Clone, Copy)]
+enum FrameValue {
+    First,
+    Last,
+    /// The n-th (one-indexed) row of the frame.
+    Nth(i64),
+}
+
+impl FrameValue {
+    fn all(self, e: Expr) -> Expr {
+        match self {
+            Self::First => e.first(),
+            Self::Last => e.last(),
+            Self::Nth(n) => e.slice(lit(n - 1), lit(1)).first(),
+        }
+    }
+
+    /// The position of the value in a frame, given the positions of its first and last row.
+    fn position(self, start: Expr, end: Expr) -> Expr {
+        match self {
+            Self::First => start,
+            Self::Last => end,
+            Self::Nth(n) => start + lit(n - 1),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum FrameBound {
+    Unbounded,
+    /// A number of rows after (or before, if negative) the current row.
+    Offset(i64),
+    /// The first (or last) row with the same ORDER BY values as the current row.
+    Peer,
+}
+
+/// The frame of a window.
+#[derive(Clone, Copy)]
+enum Frame {
+    /// All the rows of the partition.
+    Partition,
+    Between(FrameBound, FrameBound),
+    /// The rows whose ORDER BY value is within the duration before the current value.
+    Range(Duration),
+}
+
+/// The positions of the first and last rows of the frame of every row, in the order of the
+/// window; these are out of bounds where the frame extends past the partition.
+struct FramePositions {
+    start: Expr,
+    end: Expr,
+}
+
+impl FramePositions {
+    fn new(start: FrameBound, end: FrameBound, by: &[Expr]) -> Self {
+        let row = || int_range(typed_lit(0 as IdxSize), len(), 1, IDX_DTYPE).cast(DataType::Int64);
+        // peers are consecutive, so the first peer is the last row where the ORDER BY values
+        // changed, and vice versa
+        let peer = |offset: i64, bound: Expr| {
+            let changed = by.iter().fold(row().eq(bound), |changed, e| {
+                changed.or(e.clone().neq_missing(e.clone().shift(lit(offset))))
+            });
+            when(changed).then(row()).otherwise(lit(Null))
+        };
+        // positions are evaluated for every row, as scalars can't be gathered in groups
+        let zero = || row() * lit(0i64);
+        let start = match start {
+            FrameBound::Unbounded => zero(),
+            FrameBound::Offset(n) => row() + lit(n),
+            FrameBound::Peer => peer(1, lit(0i64)).forward_fill(None),
+        };
+        let end = match end {
+            FrameBound::Unbounded => zero() + Self::last(),
+            FrameBound::Offset(n) => row() + lit(n),
+            FrameBound::Peer => peer(-1, Self::last()).backward_fill(None),
+        };
+        Self { start, end }
+    }
+
+    fn last() -> Expr {
+        len().cast(DataType::Int64) - lit(1i64)
+    }
+
+    fn clip(&self, position: Expr) -> Expr {
+        position.clip(lit(0i64), Self::last())
+    }
+
+    fn is_empty(&self) -> Expr {
+        self.start
+            .clone()
+            .gt(self.end.clone())
+            .or(self.start.clone().gt(Self::last()))
+            .or(self.end.clone().lt(lit(0i64)))
+    }
+}
+
+/// Build a string from a `FORMAT` template, replacing each `%s` with the next value
+/// (null values are formatted as empty strings) and each `%%` with a literal `%`.
+fn format_str(fmt: &str, values: &[Expr]) -> PolarsResult<Expr> {
+    let mut parts = vec![];
+    let mut text = String::new();
+    let mut values = values.iter();
+    let mut chars = fmt.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('%') => text.push('%'),
+            Some('s') => {
+                let value = values.next().ok_or_else(
+                    || polars_err!(SQLSyntax: "FORMAT has too few arguments for {:?}", fmt),
+                )?;
+                parts.push(lit(std::mem::take(&mut text)));
+                parts.push(value.clone().cast(DataType::String));
+            },
+            spec => {
+                polars_bail!(SQLSyntax: "FORMAT only supports the '%s' and '%%' specifiers (found '%{}')", spec.map(String::from).unwrap_or_default())
+            },
+        }
+    }
+    polars_ensure!(
+        values.next().is_none(),
+        SQLSyntax: "FORMAT has too many arguments for {:?}", fmt
+    );
+    parts.push(lit(text));
+    Ok(concat_str(parts, "", true))
+}
+
+/// Hash each (string) value, returning the hex-encoded digest.
+#[cfg(feature = "hash_digest")]
+fn hex_digest<D: Digest + 'static>(e: Expr) -> Expr {
+    use std::borrow::Cow;
+
+    use polars_core::prelude::{ChunkApply, IntoColumn};
+    use polars_plan::dsl::GetOutput;
+
+    e.map(
+        |c| {
+            let ca = c.str()?;
+            let out = ca.apply_values(|s| Cow::Owned(hex::encode(D::digest(s.as_bytes()))));
+            Ok(Some(out.into_column()))
+        },
+        GetOutput::from_type(DataType::String),
+    )
+}
+
+/// Apply regex flags (such as 'i', for case-insensitive matching) to a literal pattern.
+fn regex_with_flags(pattern: Expr, flags: Expr, func_name: &str) -> PolarsResult<Expr> {
+    match (pattern, flags) {
+        (Expr::Literal(LiteralValue::String(s)), Expr::Literal(LiteralValue::String(f))) => {
+            polars_ensure!(!f.is_empty(), SQLSyntax: "invalid/empty 'flags' for {}", func_name);
+            Ok(lit(format!("(?{}){}", f, s)))
+        },
+        (pattern, flags) => {
+            polars_bail!(SQLSyntax: "invalid arguments for {} ({:?}, {:?})", func_name, pattern, flags)
+        },
+    }
+}
+
+/// Convert `\N` capture group references in a regex replacement string to the `${N}` form.
+fn convert_backrefs(value: &str) -> String {
+    let rx = regex::Regex::new(r"\\(\d+)").unwrap();
+    rx.replace_all(value, "$${$1}").into_owned()
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
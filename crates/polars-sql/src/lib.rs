@@ -2,12 +2,18 @@
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
 mod context;
+#[cfg(feature = "flight")]
+mod flight;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
+mod params;
 mod sql_expr;
 mod table_functions;
 mod types;
 
 pub use context::SQLContext;
+#[cfg(feature = "flight")]
+pub use flight::SQLFlightSource;
+pub use params::SQLParams;
 pub use sql_expr::sql_expr;
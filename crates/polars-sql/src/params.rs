@@ -0,0 +1,304 @@
+use std::cell::Cell;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_plan::constants::get_literal_name;
+use polars_plan::prelude::*;
+use sqlparser::dialect::Dialect;
+use sqlparser::tokenizer::{Token, TokenWithLocation, Tokenizer};
+
+use crate::sql_expr::to_sql_interface_err;
+
+/// The (typed) values bound to the parameters of a SQL query.
+///
+/// Positional parameters (`$1`, `?1`, or `?`, which is numbered by its position in the
+/// query) take the values added with [`SQLParams::with_value`], in order; named parameters
+/// (`:name` or `$name`) take the values added with [`SQLParams::with_named_value`].
+/// ```rust
+/// # use polars_sql::{SQLContext, SQLParams};
+/// # use polars_core::prelude::*;
+/// # use polars_lazy::prelude::*;
+/// # fn main() {
+///
+/// let mut ctx = SQLContext::new();
+/// let df = df! {
+///    "a" =>  [1, 2, 3],
+/// }
+/// .unwrap();
+///
+/// ctx.register("df", df.lazy());
+/// let params = SQLParams::new().with_named_value("min", 2);
+/// let sql_df = ctx
+///     .execute_with_params("SELECT * FROM df WHERE a >= :min", params)
+///     .unwrap()
+///     .collect()
+///     .unwrap();
+/// assert_eq!(sql_df.height(), 2);
+/// # }
+///```
+#[derive(Clone, Debug, Default)]
+pub struct SQLParams {
+    positional: Vec<Scalar>,
+    named: PlHashMap<String, Scalar>,
+    // typed (null) stand-ins for the positional values, which are bound to the values once
+    // the plan of a prepared statement is executed (see `bind_placeholders`)
+    placeholders: Vec<Series>,
+    // set when the value of a placeholder was needed while planning (e.g. in an `IN` list)
+    value_needed: Cell<bool>,
+}
+
+impl SQLParams {
+    /// Create a new (empty) set of parameter values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind a value to the next positional parameter.
+    pub fn with_value(mut self, value: impl Into<Scalar>) -> Self {
+        self.positional.push(value.into());
+        self
+    }
+
+    /// Bind a value to the named parameter `name` (given without its `:` or `$` prefix).
+    pub fn with_named_value(mut self, name: &str, value: impl Into<Scalar>) -> Self {
+        self.named.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// Stand-ins for positional parameters of the given types, to plan a prepared statement
+    /// (without its values).
+    pub(crate) fn with_placeholders(dtypes: &[DataType]) -> Self {
+        Self {
+            placeholders: dtypes
+                .iter()
+                .map(|dtype| Series::full_null(get_literal_name().clone(), 1, dtype))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn placeholders(&self) -> &[Series] {
+        &self.placeholders
+    }
+
+    /// Whether planning with placeholders failed because the value of one was needed.
+    pub(crate) fn value_needed(&self) -> bool {
+        self.value_needed.get()
+    }
+
+    /// Get the expression of a placeholder: the literal of its value, or its stand-in (when
+    /// planning a prepared statement).
+    pub(crate) fn get_expr(&self, placeholder: &str) -> PolarsResult<Expr> {
+        let key = placeholder.get(1..).unwrap_or_default();
+        let stand_in = key
+            .parse::<usize>()
+            .ok()
+            .and_then(|idx| self.placeholders.get(idx.checked_sub(1)?));
+        match stand_in {
+            Some(s) => Ok(lit(s.clone())),
+            None => Ok(lit(self.get(placeholder)?.clone())),
+        }
+    }
+
+    /// Get the value bound to a placeholder, such as `$1` or `:name`.
+    pub(crate) fn get(&self, placeholder: &str) -> PolarsResult<&Scalar> {
+        let key = placeholder.get(1..).unwrap_or_default();
+        let value = match key.parse::<usize>() {
+            // note: positional parameters are 1-indexed
+            Ok(idx) => idx.checked_sub(1).and_then(|idx| self.positional.get(idx)),
+            Err(_) => self.named.get(key),
+        };
+        value.ok_or_else(|| {
+            if !self.placeholders.is_empty() {
+                self.value_needed.set(true);
+            }
+            polars_err!(SQLInterface: "no value is bound to parameter '{}'", placeholder)
+        })
+    }
+}
+
+impl From<Vec<Scalar>> for SQLParams {
+    fn from(positional: Vec<Scalar>) -> Self {
+        Self {
+            positional,
+            ..Default::default()
+        }
+    }
+}
+
+/// Tokenize a SQL query, numbering its anonymous (`?`) parameters in order of appearance
+/// (so that each resolves to its own positional value once parsed).
+pub(crate) fn tokenize_sql(
+    dialect: &dyn Dialect,
+    query: &str,
+) -> PolarsResult<Vec<TokenWithLocation>> {
+    let mut tokens = Tokenizer::new(dialect, query)
+        .tokenize_with_location()
+        .map_err(to_sql_interface_err)?;
+
+    let mut n_anonymous = 0;
+    for tok in tokens.iter_mut() {
+        if matches!(&tok.token, Token::Placeholder(s) if s == "?") {
+            n_anonymous += 1;
+            tok.token = Token::Placeholder(format!("?{}", n_anonymous));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Bind the values of the positional parameters of a prepared statement to the plan of the
+/// statement (that was planned with the given placeholders for them).
+pub(crate) fn bind_placeholders(
+    lf: &LazyFrame,
+    placeholders: &[Series],
+    values: &[Scalar],
+) -> LazyFrame {
+    let binder = PlaceholderBinder {
+        placeholders,
+        values,
+    };
+    LazyFrame::from(binder.bind_plan(&lf.logical_plan))
+        .with_optimizations(lf.get_current_optimizations())
+}
+
+struct PlaceholderBinder<'a> {
+    placeholders: &'a [Series],
+    values: &'a [Scalar],
+}
+
+impl PlaceholderBinder<'_> {
+    fn bind_expr(&self, expr: &Expr) -> Expr {
+        expr.clone().map_expr(|e| match e {
+            Expr::Literal(LiteralValue::Series(s)) => {
+                let idx = self
+                    .placeholders
+                    .iter()
+                    .position(|placeholder| Arc::ptr_eq(&placeholder.0, &s.0));
+                match idx {
+                    Some(idx) => lit(self.values[idx].clone()),
+                    None => Expr::Literal(LiteralValue::Series(s)),
+                }
+            },
+            Expr::SubPlan(plan, names) => {
+                Expr::SubPlan(SpecialEq::new(Arc::new(self.bind_plan(&plan))), names)
+            },
+            e => e,
+        })
+    }
+
+    fn bind_exprs(&self, exprs: &[Expr]) -> Vec<Expr> {
+        exprs.iter().map(|e| self.bind_expr(e)).collect()
+    }
+
+    fn bind_input(&self, input: &DslPlan) -> Arc<DslPlan> {
+        Arc::new(self.bind_plan(input))
+    }
+
+    fn bind_plan(&self, plan: &DslPlan) -> DslPlan {
+        match plan {
+            DslPlan::Filter { input, predicate } => DslPlan::Filter {
+                input: self.bind_input(input),
+                predicate: self.bind_expr(predicate),
+            },
+            DslPlan::Cache { input, id } => DslPlan::Cache {
+                input: self.bind_input(input),
+                id: *id,
+            },
+            DslPlan::Select {
+                expr,
+                input,
+                options,
+            } => DslPlan::Select {
+                expr: self.bind_exprs(expr),
+                input: self.bind_input(input),
+                options: *options,
+            },
+            DslPlan::GroupBy {
+                input,
+                keys,
+                aggs,
+                apply,
+                maintain_order,
+                options,
+            } => DslPlan::GroupBy {
+                input: self.bind_input(input),
+                keys: self.bind_exprs(keys),
+                aggs: self.bind_exprs(aggs),
+                apply: apply.clone(),
+                maintain_order: *maintain_order,
+                options: options.clone(),
+            },
+            DslPlan::Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                predicates,
+                options,
+            } => DslPlan::Join {
+                input_left: self.bind_input(input_left),
+                input_right: self.bind_input(input_right),
+                left_on: self.bind_exprs(left_on),
+                right_on: self.bind_exprs(right_on),
+                predicates: self.bind_exprs(predicates),
+                options: options.clone(),
+            },
+            DslPlan::HStack {
+                input,
+                exprs,
+                options,
+            } => DslPlan::HStack {
+                input: self.bind_input(input),
+                exprs: self.bind_exprs(exprs),
+                options: *options,
+            },
+            DslPlan::Distinct { input, options } => DslPlan::Distinct {
+                input: self.bind_input(input),
+                options: options.clone(),
+            },
+            DslPlan::Sort {
+                input,
+                by_column,
+                slice,
+                sort_options,
+            } => DslPlan::Sort {
+                input: self.bind_input(input),
+                by_column: self.bind_exprs(by_column),
+                slice: *slice,
+                sort_options: sort_options.clone(),
+            },
+            DslPlan::Slice { input, offset, len } => DslPlan::Slice {
+                input: self.bind_input(input),
+                offset: *offset,
+                len: *len,
+            },
+            DslPlan::MapFunction { input, function } => DslPlan::MapFunction {
+                input: self.bind_input(input),
+                function: match function {
+                    DslFunction::FillNan(e) => DslFunction::FillNan(self.bind_expr(e)),
+                    function => function.clone(),
+                },
+            },
+            DslPlan::Union { inputs, args } => DslPlan::Union {
+                inputs: inputs.iter().map(|p| self.bind_plan(p)).collect(),
+                args: *args,
+            },
+            DslPlan::HConcat { inputs, options } => DslPlan::HConcat {
+                inputs: inputs.iter().map(|p| self.bind_plan(p)).collect(),
+                options: *options,
+            },
+            DslPlan::ExtContext { input, contexts } => DslPlan::ExtContext {
+                input: self.bind_input(input),
+                contexts: contexts.iter().map(|p| self.bind_plan(p)).collect(),
+            },
+            DslPlan::Sink { input, payload } => DslPlan::Sink {
+                input: self.bind_input(input),
+                payload: payload.clone(),
+            },
+            // (the arenas of a converted plan belong to the context that planned it)
+            DslPlan::IR { dsl, .. } => self.bind_plan(dsl),
+            // scans (without expressions)
+            plan => plan.clone(),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "flight")]
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+#[test]
+fn serve_sql_context_tables() {
+    let df = df! {
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.clone().lazy());
+
+    let server = FlightServer::new(SQLFlightSource::new(ctx));
+    let (addr, _server) = server.spawn("127.0.0.1:0".parse().unwrap()).unwrap();
+    let uri = format!("http://{addr}");
+
+    let out = LazyFrame::scan_flight(uri.as_str(), "df", Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(out, df);
+
+    let out = LazyFrame::scan_flight(
+        uri.as_str(),
+        "SELECT b FROM df WHERE a >= 2 ORDER BY a DESC",
+        Default::default(),
+    )
+    .unwrap()
+    .collect()
+    .unwrap();
+    assert_eq!(out, df! { "b" => ["z", "y"] }.unwrap());
+}
@@ -129,3 +129,155 @@ fn test_array_literal() {
     assert!(df_sql.equals(&df_expected));
     assert!(df_sql.height() == 1);
 }
+
+fn execute(df: DataFrame, sql: &str) -> DataFrame {
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+    context.execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_regexp_functions() {
+    let df = df! {
+        "a" => [Some("John Smith"), Some("jane doe 42"), None],
+    }
+    .unwrap();
+    let sql = r#"
+        SELECT
+            REGEXP_REPLACE(a, '(\w+) (\w+)', '\2, \1') AS swapped,
+            REGEXP_REPLACE(a, 'o', '0') AS replace_first,
+            REGEXP_REPLACE(a, 'J|O', '_', 'gi') AS replace_all,
+            REGEXP_EXTRACT(a, '\d+') AS digits,
+            REGEXP_SUBSTR(a, '(\w+) (\w+)', 2) AS second_word,
+            REGEXP_COUNT(a, '[aeiou]') AS n_vowels,
+            REGEXP_COUNT(a, 'j', 'i') AS n_j,
+        FROM df"#;
+    let expected = df! {
+        "swapped" => [Some("Smith, John"), Some("doe, jane 42"), None],
+        "replace_first" => [Some("J0hn Smith"), Some("jane d0e 42"), None],
+        "replace_all" => [Some("__hn Smith"), Some("_ane d_e 42"), None],
+        "digits" => [None, Some("42"), None],
+        "second_word" => [Some("Smith"), Some("doe"), None],
+        "n_vowels" => [Some(2u32), Some(4), None],
+        "n_j" => [Some(1u32), Some(1), None],
+    }
+    .unwrap();
+    let actual = execute(df, sql);
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_split_and_pad_functions() {
+    let df = df! {
+        "a" => [Some("a,b,c"), Some("xyz"), None],
+    }
+    .unwrap();
+    let sql = r#"
+        SELECT
+            SPLIT_PART(a, ',', 2) AS second,
+            SPLIT_PART(a, ',', -1) AS last,
+            STRING_TO_ARRAY(a, ',') AS parts,
+            LPAD(a, 4) AS lpad,
+            RPAD(a, 4, '.') AS rpad,
+            LPAD(a, 2, '0') AS truncated,
+            REPEAT(a, 2) AS repeated,
+            TRANSLATE(a, 'a,x', 'A') AS translated,
+        FROM df"#;
+    let actual = execute(df, sql);
+    let expected = df! {
+        "second" => [Some("b"), Some(""), None],
+        "last" => [Some("c"), Some("xyz"), None],
+        "lpad" => [Some("a,b,"), Some(" xyz"), None],
+        "rpad" => [Some("a,b,"), Some("xyz."), None],
+        "truncated" => [Some("a,"), Some("xy"), None],
+        "repeated" => [Some("a,b,ca,b,c"), Some("xyzxyz"), None],
+        "translated" => [Some("Abc"), Some("yz"), None],
+    }
+    .unwrap();
+    assert!(
+        actual.drop("parts").unwrap().equals_missing(&expected),
+        "{actual:?}"
+    );
+    let parts = actual.column("parts").unwrap().list().unwrap();
+    assert_eq!(
+        parts.get_as_series(0).unwrap(),
+        Series::new("".into(), ["a", "b", "c"])
+    );
+    assert_eq!(parts.null_count(), 1);
+
+    // the number of repetitions can be an expression (and is at least zero)
+    let df = df! {
+        "a" => [Some("ab"), Some("c"), Some("d"), None],
+        "n" => [Some(3), Some(-1), None, Some(2)],
+    }
+    .unwrap();
+    let actual = execute(
+        df,
+        "SELECT REPEAT(a, n) AS repeated, REPEAT(a, 0) AS empty FROM df",
+    );
+    let expected = df! {
+        "repeated" => [Some("ababab"), Some(""), None, None],
+        "empty" => [Some(""), Some(""), Some(""), None],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_format_functions() {
+    let df = df! {
+        "a" => [Some("abc"), None],
+        "n" => [1, 2],
+    }
+    .unwrap();
+    let sql = r#"
+        SELECT
+            FORMAT('%s-%s (100%%)', a, n) AS formatted,
+            PRINTF('n=%s', n) AS printed,
+            POSITION('.' IN 'ab.c') AS pos,
+            STRPOS(a, 'c') AS strpos,
+        FROM df"#;
+    let actual = execute(df, sql);
+    let expected = df! {
+        "formatted" => ["abc-1 (100%)", "-2 (100%)"],
+        "printed" => ["n=1", "n=2"],
+        "pos" => [3u32, 3],
+        "strpos" => [3u32, 0],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+#[cfg(feature = "hash_digest")]
+fn test_hash_functions() {
+    let df = df! {
+        "a" => [Some("abc"), None],
+    }
+    .unwrap();
+    let sql = "SELECT MD5(a) AS md5, SHA256(a) AS sha256 FROM df";
+    let actual = execute(df, sql);
+    let expected = df! {
+        "md5" => [Some("900150983cd24fb0d6963f7d28e17f72"), None],
+        "sha256" => [Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"), None],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_string_function_errors() {
+    let mut context = SQLContext::new();
+    context.register("df", df! { "a" => ["x"] }.unwrap().lazy());
+    for sql in [
+        "SELECT FORMAT('%s %s', a) FROM df",
+        "SELECT FORMAT('%d', a) FROM df",
+        "SELECT LPAD(a, 3, 'ab') FROM df",
+        "SELECT RPAD(a, -1) FROM df",
+        "SELECT SPLIT_PART(a, ',', 0) FROM df",
+        "SELECT REGEXP_REPLACE(a, 'x') FROM df",
+        "SELECT TRANSLATE(a, a, 'x') FROM df",
+    ] {
+        assert!(context.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,442 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_df() -> LazyFrame {
+    df! {
+      "Id" => [1, 2, 3, 4, 5, 6, 7],
+      "Country" => ["US", "UK", "US", "UK", "US", "UK", "US"],
+      "Sales" => [3000, 2000, 1000, 4000, 3000, 2000, 5000]
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("Id").cast(DataType::Date).alias("Day"))
+}
+
+fn execute(sql: &str) -> DataFrame {
+    let query = format!(
+        r#"
+      SELECT
+          Id,
+          {sql} AS TEST
+      FROM
+          df
+      ORDER BY
+        Id
+      "#
+    );
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    ctx.execute(&query).unwrap().collect().unwrap()
+}
+
+fn assert_result(sql: &str, expected: Series) {
+    let actual = execute(sql);
+    let actual = actual.column("TEST").unwrap().as_materialized_series();
+    let expected = expected.with_name("TEST".into());
+    assert!(
+        actual.equals_missing(&expected),
+        "{sql}: expected {expected:?}, found {actual:?}"
+    );
+}
+
+fn idx(values: &[IdxSize]) -> Series {
+    Series::new("".into(), values)
+}
+
+#[test]
+fn test_row_number() {
+    assert_result(
+        "ROW_NUMBER() OVER (ORDER BY Sales DESC, Id)",
+        idx(&[3, 5, 7, 2, 4, 6, 1]),
+    );
+    assert_result(
+        "ROW_NUMBER() OVER (PARTITION BY Country ORDER BY Sales)",
+        idx(&[2, 1, 1, 3, 3, 2, 4]),
+    );
+    assert_result(
+        "ROW_NUMBER() OVER (PARTITION BY Country)",
+        idx(&[1, 1, 2, 2, 3, 3, 4]),
+    );
+}
+
+#[test]
+fn test_rank() {
+    assert_result("RANK() OVER (ORDER BY Sales)", idx(&[4, 2, 1, 6, 4, 2, 7]));
+    assert_result(
+        "DENSE_RANK() OVER (ORDER BY Sales)",
+        idx(&[3, 2, 1, 4, 3, 2, 5]),
+    );
+    assert_result(
+        "RANK() OVER (PARTITION BY Country ORDER BY Sales DESC)",
+        idx(&[2, 2, 4, 1, 2, 2, 1]),
+    );
+    assert_result(
+        "RANK() OVER (ORDER BY Country, Sales)",
+        idx(&[5, 1, 4, 3, 5, 1, 7]),
+    );
+    assert_result(
+        "RANK() OVER (ORDER BY Country, Sales DESC)",
+        idx(&[5, 2, 7, 1, 5, 2, 4]),
+    );
+}
+
+#[test]
+fn test_rank_null_keys() {
+    // null ORDER BY values are peers, ranked last (ascending) or first (descending)
+    // unless NULLS FIRST/LAST is given
+    let mut ctx = SQLContext::new();
+    let df = df! {
+      "Id" => [1, 2, 3, 4, 5],
+      "Value" => [Some(2), None, Some(1), None, Some(2)],
+    }
+    .unwrap();
+    ctx.register("df", df.lazy());
+    for (sql, expected) in [
+        ("RANK() OVER (ORDER BY Value)", idx(&[2, 4, 1, 4, 2])),
+        ("DENSE_RANK() OVER (ORDER BY Value)", idx(&[2, 3, 1, 3, 2])),
+        (
+            "RANK() OVER (ORDER BY Value NULLS FIRST)",
+            idx(&[4, 1, 3, 1, 4]),
+        ),
+        ("RANK() OVER (ORDER BY Value DESC)", idx(&[3, 1, 5, 1, 3])),
+        (
+            "PERCENT_RANK() OVER (ORDER BY Value)",
+            Series::new("".into(), &[0.25, 0.75, 0.0, 0.75, 0.25]),
+        ),
+        (
+            "CUME_DIST() OVER (ORDER BY Value DESC)",
+            Series::new("".into(), &[0.8, 0.4, 1.0, 0.4, 0.8]),
+        ),
+    ] {
+        let query = format!("SELECT {sql} AS TEST FROM df ORDER BY Id");
+        let actual = ctx.execute(&query).unwrap().collect().unwrap();
+        let actual = actual.column("TEST").unwrap().as_materialized_series();
+        let expected = expected.with_name("TEST".into());
+        assert!(
+            actual.equals_missing(&expected),
+            "{sql}: expected {expected:?}, found {actual:?}"
+        );
+    }
+}
+
+#[test]
+fn test_percent_rank_cume_dist() {
+    assert_result(
+        "PERCENT_RANK() OVER (PARTITION BY Country ORDER BY Sales)",
+        Series::new("".into(), &[1.0 / 3.0, 0.0, 0.0, 1.0, 1.0 / 3.0, 0.0, 1.0]),
+    );
+    assert_result(
+        "CUME_DIST() OVER (PARTITION BY Country ORDER BY Sales)",
+        Series::new(
+            "".into(),
+            &[0.75, 2.0 / 3.0, 0.25, 1.0, 0.75, 2.0 / 3.0, 1.0],
+        ),
+    );
+}
+
+#[test]
+fn test_ntile() {
+    assert_result(
+        "NTILE(3) OVER (ORDER BY Id)",
+        Series::new("".into(), &[1i64, 1, 1, 2, 2, 3, 3]),
+    );
+    assert_result(
+        "NTILE(2) OVER (PARTITION BY Country ORDER BY Id)",
+        Series::new("".into(), &[1i64, 1, 1, 1, 2, 2, 2]),
+    );
+    assert_result(
+        "NTILE(10) OVER (ORDER BY Id)",
+        Series::new("".into(), &[1i64, 2, 3, 4, 5, 6, 7]),
+    );
+}
+
+#[test]
+fn test_lag_lead() {
+    assert_result(
+        "LAG(Sales) OVER (ORDER BY Id)",
+        Series::new(
+            "".into(),
+            &[
+                None,
+                Some(3000),
+                Some(2000),
+                Some(1000),
+                Some(4000),
+                Some(3000),
+                Some(2000),
+            ],
+        ),
+    );
+    assert_result(
+        "LAG(Sales, 1, 0) OVER (PARTITION BY Country ORDER BY Id)",
+        Series::new("".into(), &[0, 0, 3000, 2000, 1000, 4000, 3000]),
+    );
+    assert_result(
+        "LEAD(Id, 2) OVER (PARTITION BY Country ORDER BY Sales DESC, Id)",
+        Series::new(
+            "".into(),
+            &[Some(3), None, None, Some(6), None, None, Some(5)],
+        ),
+    );
+}
+
+#[test]
+fn test_first_last_nth_value() {
+    assert_result(
+        "FIRST_VALUE(Id) OVER (PARTITION BY Country ORDER BY Sales)",
+        Series::new("".into(), &[3, 2, 3, 2, 3, 2, 3]),
+    );
+    // the default frame ends at the last peer of the current row
+    assert_result(
+        "LAST_VALUE(Id) OVER (PARTITION BY Country ORDER BY Sales)",
+        Series::new("".into(), &[5, 6, 3, 4, 5, 6, 7]),
+    );
+    assert_result(
+        "LAST_VALUE(Id) OVER (PARTITION BY Country ORDER BY Sales ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING)",
+        Series::new("".into(), &[7, 4, 7, 4, 7, 4, 7]),
+    );
+    assert_result(
+        "FIRST_VALUE(Sales) OVER (ORDER BY Id DESC)",
+        Series::new("".into(), &[5000; 7]),
+    );
+    assert_result(
+        "NTH_VALUE(Sales, 2) OVER (PARTITION BY Country ORDER BY Id)",
+        Series::new(
+            "".into(),
+            &[
+                None,
+                None,
+                Some(1000),
+                Some(4000),
+                Some(1000),
+                Some(4000),
+                Some(1000),
+            ],
+        ),
+    );
+    assert_result(
+        "NTH_VALUE(Sales, 4) OVER (PARTITION BY Country ORDER BY Id RANGE BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING)",
+        Series::new(
+            "".into(),
+            &[
+                Some(5000),
+                None,
+                Some(5000),
+                None,
+                Some(5000),
+                None,
+                Some(5000),
+            ],
+        ),
+    );
+    assert_result(
+        "LAST_VALUE(Sales) OVER (ORDER BY Id ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING)",
+        Series::new(
+            "".into(),
+            &[
+                None,
+                Some(3000),
+                Some(2000),
+                Some(1000),
+                Some(4000),
+                Some(3000),
+                Some(2000),
+            ],
+        ),
+    );
+}
+
+#[test]
+fn test_window_frame_running_total() {
+    // the default frame includes the peers of the current row
+    assert_result(
+        "SUM(Sales) OVER (PARTITION BY Country ORDER BY Sales)",
+        Series::new("".into(), &[7000, 4000, 1000, 8000, 7000, 4000, 12000]),
+    );
+    assert_result(
+        "SUM(Sales) OVER (PARTITION BY Country ORDER BY Sales ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)",
+        Series::new("".into(), &[4000, 2000, 1000, 8000, 7000, 4000, 12000]),
+    );
+    assert_result(
+        "SUM(Sales) OVER (ORDER BY Id ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING)",
+        Series::new("".into(), &[20000, 17000, 15000, 14000, 10000, 7000, 5000]),
+    );
+    assert_result(
+        "COUNT(*) OVER (ORDER BY Sales)",
+        idx(&[5, 3, 1, 6, 5, 3, 7]),
+    );
+    assert_result(
+        "COUNT(*) OVER (PARTITION BY Country)",
+        idx(&[4, 3, 4, 3, 4, 3, 4]),
+    );
+}
+
+#[test]
+fn test_window_order_by_without_frame_support() {
+    // aggregations that don't support frames ignore the ORDER BY of their window, and
+    // are evaluated over the whole partition
+    assert_result(
+        "MEDIAN(Sales) OVER (PARTITION BY Country ORDER BY Sales)",
+        Series::new(
+            "".into(),
+            &[3000.0, 2000.0, 3000.0, 2000.0, 3000.0, 2000.0, 3000.0],
+        ),
+    );
+    for func in ["STDDEV", "VARIANCE"] {
+        let ordered = execute(&format!(
+            "{func}(Sales) OVER (PARTITION BY Country ORDER BY Id DESC)"
+        ));
+        let unordered = execute(&format!("{func}(Sales) OVER (PARTITION BY Country)"));
+        assert!(ordered.equals_missing(&unordered), "{func}: {ordered:?}");
+    }
+}
+
+#[test]
+fn test_window_frame_rows() {
+    assert_result(
+        "AVG(Sales) OVER (ORDER BY Id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING)",
+        Series::new(
+            "".into(),
+            &[
+                2500.0,
+                2000.0,
+                7000.0 / 3.0,
+                8000.0 / 3.0,
+                3000.0,
+                10000.0 / 3.0,
+                3500.0,
+            ],
+        ),
+    );
+    assert_result(
+        "MIN(Sales) OVER (PARTITION BY Country ORDER BY Id ROWS BETWEEN CURRENT ROW AND 2 FOLLOWING)",
+        Series::new("".into(), &[1000, 2000, 1000, 2000, 3000, 2000, 5000]),
+    );
+    assert_result(
+        "MAX(Sales) OVER (ORDER BY Id ROWS BETWEEN 3 PRECEDING AND 2 PRECEDING)",
+        Series::new(
+            "".into(),
+            &[
+                None,
+                None,
+                Some(3000),
+                Some(3000),
+                Some(2000),
+                Some(4000),
+                Some(4000),
+            ],
+        ),
+    );
+    assert_result(
+        "COUNT(Sales) OVER (ORDER BY Id ROWS BETWEEN 3 PRECEDING AND 2 PRECEDING)",
+        idx(&[0, 0, 1, 2, 2, 2, 2]),
+    );
+}
+
+#[test]
+fn test_window_frame_range() {
+    assert_result(
+        "SUM(Sales) OVER (ORDER BY Id RANGE BETWEEN 2 PRECEDING AND CURRENT ROW)",
+        Series::new("".into(), &[3000, 5000, 6000, 7000, 8000, 9000, 10000]),
+    );
+    assert_result(
+        "SUM(Sales) OVER (ORDER BY Day RANGE BETWEEN INTERVAL '2 days' PRECEDING AND CURRENT ROW)",
+        Series::new("".into(), &[3000, 5000, 6000, 7000, 8000, 9000, 10000]),
+    );
+    // the frame includes the peers following the current row
+    assert_result(
+        "COUNT(*) OVER (ORDER BY Sales RANGE BETWEEN 1000 PRECEDING AND CURRENT ROW)",
+        idx(&[4, 3, 1, 3, 4, 3, 2]),
+    );
+}
+
+#[test]
+fn test_window_function_errors() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    for sql in [
+        "SELECT ROW_NUMBER() FROM df",
+        "SELECT RANK() OVER (PARTITION BY Country) FROM df",
+        "SELECT NTILE(0) OVER (ORDER BY Id) FROM df",
+        "SELECT LAG(Sales, -1) OVER (ORDER BY Id) FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Id ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING) FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Id ROWS BETWEEN UNBOUNDED FOLLOWING AND CURRENT ROW) FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Id RANGE BETWEEN 1 PRECEDING AND 1 FOLLOWING) FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Id DESC RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Id GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT MEDIAN(Sales) OVER (ORDER BY Id ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
+
+fn execute_query(sql: &str) -> DataFrame {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    ctx.execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_qualify() {
+    let df = execute_query(
+        r#"
+      SELECT Id, Country, Sales
+      FROM df
+      QUALIFY ROW_NUMBER() OVER (PARTITION BY Country ORDER BY Sales DESC) = 1
+      ORDER BY Id
+      "#,
+    );
+    let expected = df! {
+      "Id" => [4, 7],
+      "Country" => ["UK", "US"],
+      "Sales" => [4000, 5000],
+    }
+    .unwrap();
+    assert!(df.equals(&expected), "{df:?}");
+}
+
+#[test]
+fn test_named_window() {
+    let df = execute_query(
+        r#"
+      SELECT Id, ROW_NUMBER() OVER w AS rn
+      FROM df
+      WINDOW w AS (PARTITION BY Country ORDER BY Sales DESC, Id)
+      QUALIFY rn <= 2
+      ORDER BY Id
+      "#,
+    );
+    let expected = df! {
+      "Id" => [1, 2, 4, 7],
+      "rn" => [2 as IdxSize, 2, 1, 1],
+    }
+    .unwrap();
+    assert!(df.equals(&expected), "{df:?}");
+
+    // named windows can be referenced by other windows, and refined with a frame
+    let df = execute_query(
+        r#"
+      SELECT Id, SUM(Sales) OVER (w2 ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS TEST
+      FROM df
+      WINDOW w1 AS (PARTITION BY Country), w2 AS (w1 ORDER BY Id)
+      ORDER BY Id
+      "#,
+    );
+    let expected = Series::new("TEST".into(), &[3000, 2000, 4000, 6000, 4000, 6000, 8000]);
+    let actual = df.column("TEST").unwrap().as_materialized_series();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_named_window_errors() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    for sql in [
+        "SELECT ROW_NUMBER() OVER w FROM df",
+        "SELECT ROW_NUMBER() OVER (w PARTITION BY Id) FROM df WINDOW w AS (PARTITION BY Country)",
+        "SELECT ROW_NUMBER() OVER (w ORDER BY Id) FROM df WINDOW w AS (ORDER BY Sales)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
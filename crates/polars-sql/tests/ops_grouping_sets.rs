@@ -0,0 +1,182 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "Year" => [2018, 2018, 2019, 2019, 2020],
+      "Country" => ["US", "UK", "US", "UK", "US"],
+      "Sales" => [1, 2, 3, 4, 5]
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+fn execute(group_by: &str) -> DataFrame {
+    let sql = format!(
+        r#"
+      SELECT
+          Country,
+          Year,
+          SUM(Sales) AS Sales,
+          GROUPING(Country, Year) AS Grouping
+      FROM
+          df
+      {group_by}
+      ORDER BY
+          Grouping,
+          Country,
+          Year"#
+    );
+    create_ctx().execute(&sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_group_by_rollup() {
+    let expected = df! {
+      "Country" => [Some("UK"), Some("UK"), Some("US"), Some("US"), Some("US"), Some("UK"), Some("US"), None],
+      "Year" => [Some(2018), Some(2019), Some(2018), Some(2019), Some(2020), None, None, None],
+      "Sales" => [2, 4, 1, 3, 5, 6, 9, 15],
+      "Grouping" => [0, 0, 0, 0, 0, 1, 1, 3],
+    }
+    .unwrap();
+    for group_by in [
+        "GROUP BY ROLLUP(Country, Year)",
+        "GROUP BY Country, Year WITH ROLLUP",
+        "GROUP BY GROUPING SETS ((Country, Year), (Country), ())",
+    ] {
+        let actual = execute(group_by);
+        assert!(actual.equals_missing(&expected), "{group_by}: {actual:?}");
+    }
+
+    // the grouping sets of the GROUP BY expressions are combined
+    let actual = execute("GROUP BY Country, ROLLUP(Year)");
+    assert!(actual.equals_missing(&expected.head(Some(7))), "{actual:?}");
+}
+
+#[test]
+fn test_group_by_cube() {
+    let expected = df! {
+      "Country" => [Some("UK"), Some("UK"), Some("US"), Some("US"), Some("US"), Some("UK"), Some("US"), None, None, None, None],
+      "Year" => [Some(2018), Some(2019), Some(2018), Some(2019), Some(2020), None, None, Some(2018), Some(2019), Some(2020), None],
+      "Sales" => [2, 4, 1, 3, 5, 6, 9, 3, 7, 5, 15],
+      "Grouping" => [0, 0, 0, 0, 0, 1, 1, 2, 2, 2, 3],
+    }
+    .unwrap();
+    for group_by in [
+        "GROUP BY CUBE(Country, Year)",
+        "GROUP BY Country, Year WITH CUBE",
+    ] {
+        let actual = execute(group_by);
+        assert!(actual.equals_missing(&expected), "{group_by}: {actual:?}");
+    }
+}
+
+#[test]
+fn test_group_by_grouping_sets_having() {
+    let sql = r#"
+      SELECT
+          Country,
+          Year,
+          SUM(Sales) AS Sales
+      FROM
+          df
+      GROUP BY GROUPING SETS ((Country), (Year))
+      HAVING SUM(Sales) > 4 AND GROUPING(Year) = 1
+      ORDER BY
+          Sales"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "Country" => ["UK", "US"],
+      "Year" => [None::<i32>, None],
+      "Sales" => [6, 9],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_grouping_ordinal_and_alias_keys() {
+    let expected = df! {
+      "c" => [Some("uk"), Some("us"), None],
+      "Sales" => [6, 9, 15],
+      "g" => [0, 0, 1],
+    }
+    .unwrap();
+    for (group_by, grouping) in [
+        ("ROLLUP(1)", "GROUPING(c)"),
+        ("ROLLUP(1)", "GROUPING(LOWER(Country))"),
+        ("ROLLUP(LOWER(Country))", "GROUPING(c)"),
+    ] {
+        let sql = format!(
+            r#"
+          SELECT
+              LOWER(Country) AS c,
+              SUM(Sales) AS Sales,
+              {grouping} AS g
+          FROM
+              df
+          GROUP BY {group_by}
+          ORDER BY
+              g,
+              c"#
+        );
+        let actual = create_ctx().execute(&sql).unwrap().collect().unwrap();
+        assert!(actual.equals_missing(&expected), "{sql}: {actual:?}");
+    }
+}
+
+#[test]
+fn test_grouping_sets_having_subquery() {
+    let sql = r#"
+      SELECT
+          Country,
+          SUM(Sales) AS Sales
+      FROM
+          df
+      GROUP BY ROLLUP(Country)
+      HAVING Sales >= (SELECT SUM(Sales) FROM df WHERE Country = 'US')
+      ORDER BY
+          Sales"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "Country" => [Some("US"), None],
+      "Sales" => [9, 15],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    // with a single grouping set, nothing is rolled up
+    let sql = r#"
+      SELECT
+          Country,
+          SUM(Sales) AS Sales
+      FROM
+          df
+      GROUP BY Country
+      HAVING GROUPING(Country) = 0
+      ORDER BY
+          Country"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "Country" => ["UK", "US"],
+      "Sales" => [6, 9],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_grouping_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT GROUPING(Country) FROM df",
+        "SELECT Country, GROUPING(Year) FROM df GROUP BY ROLLUP(Country)",
+        "SELECT Country, SUM(Sales) FROM df GROUP BY ROLLUP(Country) WITH CUBE",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,123 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let sales = df! {
+      "store" => ["A", "A", "A", "B", "B", "C"],
+      "month" => ["JAN", "FEB", "JAN", "JAN", "MAR", "FEB"],
+      "amount" => [10, 20, 30, 40, 50, 60]
+    }
+    .unwrap()
+    .lazy();
+    let quarters = df! {
+      "store" => ["A", "B"],
+      "q1" => [Some(1), Some(2)],
+      "q2" => [Some(3), None],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales);
+    ctx.register("quarters", quarters);
+    ctx
+}
+
+#[test]
+fn test_pivot() {
+    let sql = r#"
+      SELECT *
+      FROM sales
+      PIVOT (SUM(amount) FOR month IN ('JAN', 'FEB' AS feb, 'APR'))
+      ORDER BY store"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "store" => ["A", "B", "C"],
+      "JAN" => [Some(40), Some(40), None],
+      "feb" => [Some(20), None, Some(60)],
+      "APR" => [None::<i32>, None, None],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_pivot_default_on_null() {
+    let sql = r#"
+      SELECT store, jan, mar
+      FROM sales
+      PIVOT (COUNT(amount) FOR month IN ('JAN', 'MAR') DEFAULT ON NULL (0)) AS p (store, jan, mar)
+      ORDER BY store"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "store" => ["A", "B", "C"],
+      "jan" => [2 as IdxSize, 1, 0],
+      "mar" => [0 as IdxSize, 1, 0],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_pivot_without_index() {
+    let sql = r#"
+      SELECT *
+      FROM (SELECT month, amount FROM sales) AS s
+      PIVOT (MAX(amount) FOR month IN ('FEB', 'MAR'))"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "FEB" => [60],
+      "MAR" => [50],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_pivot_is_lazy() {
+    // with a list of values, the table is only read when the result is collected
+    let mut ctx = create_ctx();
+    let sales = ctx.execute("SELECT * FROM sales").unwrap();
+    ctx.register(
+        "invalid",
+        sales.with_column(col("store").strict_cast(DataType::Int32).alias("invalid")),
+    );
+    let sql = r#"
+      SELECT *
+      FROM invalid
+      PIVOT (SUM(amount) FOR month IN ('JAN', 'FEB'))"#;
+    let lf = ctx.execute(sql).unwrap();
+    assert!(lf.collect().is_err());
+    let sql = sql.replace("('JAN', 'FEB')", "(ANY)");
+    assert!(ctx.execute(&sql).is_err());
+}
+
+#[test]
+fn test_unpivot() {
+    let sql = r#"
+      SELECT store, quarter, total
+      FROM quarters
+      UNPIVOT (total FOR quarter IN (q1, q2))
+      ORDER BY store, quarter"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "store" => ["A", "A", "B"],
+      "quarter" => ["q1", "q2", "q1"],
+      "total" => [1, 3, 2],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_pivot_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT * FROM sales PIVOT (STDDEV(amount) FOR month IN ('JAN'))",
+        "SELECT * FROM sales PIVOT (SUM(amount), MIN(amount) FOR month IN ('JAN'))",
+        "SELECT * FROM sales PIVOT (SUM(missing) FOR month IN ('JAN'))",
+        "SELECT * FROM quarters UNPIVOT (total FOR quarter IN (q1, q3))",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
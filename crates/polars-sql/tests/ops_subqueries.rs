@@ -0,0 +1,196 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let customers = df! {
+      "id" => [1, 2, 3, 4],
+      "name" => ["Ann", "Bob", "Cid", "Dee"],
+      "region" => ["N", "S", "N", "S"],
+    }
+    .unwrap()
+    .lazy();
+    let orders = df! {
+      "order_id" => [10, 11, 12, 13, 14],
+      "customer_id" => [1i64, 1, 2, 3, 3],
+      "amount" => [100, 50, 70, 20, 500],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers);
+    ctx.register("orders", orders);
+    ctx
+}
+
+fn execute(sql: &str) -> DataFrame {
+    create_ctx().execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_exists() {
+    let sql = r#"
+      SELECT name
+      FROM customers c
+      WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.amount > 60)
+      ORDER BY name"#;
+    let expected = df! { "name" => ["Ann", "Bob", "Cid"] }.unwrap();
+    assert!(execute(sql).equals(&expected));
+
+    let sql = r#"
+      SELECT name
+      FROM customers
+      WHERE NOT EXISTS (SELECT * FROM orders WHERE customer_id = id) OR region = 'N'
+      ORDER BY name"#;
+    let expected = df! { "name" => ["Ann", "Cid", "Dee"] }.unwrap();
+    assert!(execute(sql).equals(&expected));
+
+    // uncorrelated subqueries are evaluated once
+    let sql = r#"
+      SELECT name
+      FROM customers
+      WHERE NOT EXISTS (SELECT 1 FROM orders WHERE amount > 1000)
+      ORDER BY name"#;
+    assert_eq!(execute(sql).height(), 4);
+}
+
+#[test]
+fn test_exists_projection() {
+    let sql = r#"
+      SELECT
+          name,
+          EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id) AS has_orders
+      FROM customers c
+      ORDER BY name"#;
+    let expected = df! {
+      "name" => ["Ann", "Bob", "Cid", "Dee"],
+      "has_orders" => [true, true, true, false],
+    }
+    .unwrap();
+    let actual = execute(sql);
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_scalar_subquery() {
+    let sql = r#"
+      SELECT
+          c.name,
+          (SELECT SUM(o.amount) FROM orders o WHERE o.customer_id = c.id) AS total,
+          (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id) AS n_orders
+      FROM customers c
+      ORDER BY c.name"#;
+    // (as for the subquery on its own, the sum of no rows is zero)
+    let expected = df! {
+      "name" => ["Ann", "Bob", "Cid", "Dee"],
+      "total" => [150, 70, 520, 0],
+      "n_orders" => [2 as IdxSize, 1, 2, 0],
+    }
+    .unwrap();
+    let actual = execute(sql);
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_scalar_subquery_filter() {
+    // orders that are larger than the average order of their customer
+    let sql = r#"
+      SELECT order_id
+      FROM orders o1
+      WHERE amount > (
+          SELECT AVG(o2.amount) FROM orders o2 WHERE o2.customer_id = o1.customer_id
+      )
+      ORDER BY order_id"#;
+    let expected = df! { "order_id" => [10, 14] }.unwrap();
+    let actual = execute(sql);
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // uncorrelated scalar subquery
+    let sql = r#"
+      SELECT order_id
+      FROM orders
+      WHERE amount > (SELECT AVG(amount) FROM orders)
+      ORDER BY order_id"#;
+    let expected = df! { "order_id" => [14] }.unwrap();
+    let actual = execute(sql);
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_scalar_subquery_with_in_subquery() {
+    let sql = r#"
+      SELECT name
+      FROM customers c
+      WHERE id IN (SELECT customer_id FROM orders WHERE amount < 100)
+        AND (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id) > 1
+      ORDER BY name"#;
+    let expected = df! { "name" => ["Ann", "Cid"] }.unwrap();
+    let actual = execute(sql);
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_scalar_subquery_having() {
+    let sql = r#"
+      SELECT region, COUNT(*) AS n
+      FROM customers c
+      GROUP BY region
+      HAVING COUNT(*) >= (SELECT COUNT(*) FROM customers WHERE region = 'N')
+      ORDER BY region"#;
+    let expected = df! {
+      "region" => ["N", "S"],
+      "n" => [2 as IdxSize, 2],
+    }
+    .unwrap();
+    let actual = execute(sql);
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_scalar_subquery_more_than_one_row() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT name, (SELECT amount FROM orders) AS amount FROM customers",
+        "SELECT name, (SELECT amount FROM orders o WHERE o.customer_id = c.id) AS amount FROM customers c",
+    ] {
+        let err = ctx.execute(sql).unwrap().collect().err().unwrap();
+        assert!(
+            err.to_string()
+                .contains("more than one row returned by a subquery"),
+            "{sql}: {err}"
+        );
+    }
+
+    // the subquery only returns a single row for the customers that are selected
+    let sql = r#"
+      SELECT
+          c.name,
+          (SELECT amount FROM orders o WHERE o.customer_id = c.id) AS amount
+      FROM customers c
+      WHERE c.id IN (2, 4)
+      ORDER BY c.name"#;
+    let expected = df! {
+      "name" => ["Bob", "Dee"],
+      "amount" => [Some(70), None],
+    }
+    .unwrap();
+    let actual = execute(sql);
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_subquery_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        // only equality predicates can be correlated
+        "SELECT * FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id > c.id)",
+        // the subquery returns more than one column
+        "SELECT (SELECT order_id, amount FROM orders o WHERE o.customer_id = c.id) FROM customers c",
+        // the outer query is referenced outside of the WHERE clause
+        "SELECT (SELECT c.name FROM orders o WHERE o.customer_id = c.id) FROM customers c",
+        // correlated subqueries do not support LIMIT
+        "SELECT (SELECT amount FROM orders o WHERE o.customer_id = c.id LIMIT 1) FROM customers c",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,176 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "id" => [1, 2, 3],
+      "name" => ["a", "b", "c"],
+      "value" => [10.0, 20.0, 30.0]
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("tbl", df);
+    ctx
+}
+
+fn execute_statements(ctx: &mut SQLContext, statements: &[&str]) -> DataFrame {
+    for sql in statements {
+        ctx.execute(sql).unwrap().collect().unwrap();
+    }
+    ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_insert() {
+    let mut ctx = create_ctx();
+    let actual = execute_statements(
+        &mut ctx,
+        &[
+            "INSERT INTO tbl VALUES (4, 'd', 40)",
+            "INSERT INTO tbl (value, id) VALUES (50.5, 5), (60, 6)",
+            "INSERT INTO tbl SELECT id + 10, name, value FROM tbl WHERE id = 1",
+        ],
+    );
+    let expected = df! {
+      "id" => [1, 2, 3, 4, 5, 6, 11],
+      "name" => [Some("a"), Some("b"), Some("c"), Some("d"), None, None, Some("a")],
+      "value" => [10.0, 20.0, 30.0, 40.0, 50.5, 60.0, 10.0],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    let actual = execute_statements(
+        &mut ctx,
+        &["INSERT OVERWRITE TABLE tbl SELECT * FROM tbl WHERE id > 5"],
+    );
+    assert!(actual.equals_missing(&expected.tail(Some(2))), "{actual:?}");
+}
+
+#[test]
+fn test_update() {
+    let mut ctx = create_ctx();
+    let actual = execute_statements(
+        &mut ctx,
+        &[
+            "UPDATE tbl SET value = value * 2, name = UPPER(name) WHERE id >= 2",
+            "UPDATE tbl SET id = id + 100",
+        ],
+    );
+    let expected = df! {
+      "id" => [101, 102, 103],
+      "name" => ["a", "B", "C"],
+      "value" => [10.0, 40.0, 60.0],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_delete() {
+    let mut ctx = create_ctx();
+    let actual = execute_statements(&mut ctx, &["DELETE FROM tbl WHERE name = 'b'"]);
+    let expected = df! {
+      "id" => [1, 3],
+      "name" => ["a", "c"],
+      "value" => [10.0, 30.0],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    let actual = execute_statements(&mut ctx, &["DELETE FROM tbl"]);
+    assert_eq!(actual.shape(), (0, 3));
+}
+
+#[test]
+fn test_merge() {
+    let mut ctx = create_ctx();
+    let src = df! {
+      "id" => [2, 3, 4, 5],
+      "name" => ["x", "y", "z", "w"],
+      "value" => [200.0, -1.0, 400.0, 500.0]
+    }
+    .unwrap()
+    .lazy();
+    ctx.register("src", src);
+
+    let actual = execute_statements(
+        &mut ctx,
+        &[r#"
+          MERGE INTO tbl AS t
+          USING src AS s
+          ON t.id = s.id
+          WHEN MATCHED AND s.value < 0 THEN DELETE
+          WHEN MATCHED THEN UPDATE SET name = s.name, value = t.value + s.value
+          WHEN NOT MATCHED AND s.id < 5 THEN INSERT (id, name, value) VALUES (s.id, s.name, s.value)
+        "#],
+    );
+    let expected = df! {
+      "id" => [1, 2, 4],
+      "name" => ["a", "x", "z"],
+      "value" => [10.0, 220.0, 400.0],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_merge_cardinality_violation() {
+    let mut ctx = create_ctx();
+    let src = df! {
+      "id" => [2, 2, 3],
+      "value" => [200.0, 201.0, 300.0]
+    }
+    .unwrap()
+    .lazy();
+    ctx.register("src", src.clone());
+
+    let sql = r#"
+      MERGE INTO tbl
+      USING src
+      ON tbl.id = src.id
+      WHEN MATCHED THEN UPDATE SET value = src.value
+    "#;
+    ctx.execute(sql).unwrap().collect().unwrap();
+
+    // the violation is raised when the table is collected (also if the rows are deleted)
+    let err = ctx
+        .execute("SELECT * FROM tbl")
+        .unwrap()
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("cardinality violation"), "{err}");
+
+    let mut ctx = create_ctx();
+    ctx.register("src", src);
+    let sql = r#"
+      MERGE INTO tbl
+      USING src
+      ON tbl.id = src.id
+      WHEN MATCHED THEN DELETE
+    "#;
+    ctx.execute(sql).unwrap().collect().unwrap();
+    let err = ctx
+        .execute("SELECT count(*) FROM tbl")
+        .unwrap()
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("cardinality violation"), "{err}");
+}
+
+#[test]
+fn test_dml_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "INSERT INTO missing VALUES (1, 'a', 1.0)",
+        "INSERT INTO tbl VALUES (1, 'a')",
+        "INSERT INTO tbl (id, other) VALUES (1, 2)",
+        "UPDATE tbl SET other = 1",
+        "UPDATE tbl SET id = 1, id = 2",
+        "DELETE FROM missing WHERE id = 1",
+        "MERGE INTO tbl USING tbl AS s ON tbl.id = s.id WHEN MATCHED THEN INSERT VALUES (1, 'a', 1.0)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
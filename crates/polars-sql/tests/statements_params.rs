@@ -0,0 +1,245 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_plan::prelude::{GetOutput, UserDefinedFunction};
+use polars_sql::function_registry::FunctionRegistry;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "id" => [1, 2, 3, 4],
+      "name" => ["a", "b", "c", "d"],
+      "value" => [10.0, 20.0, 30.0, 40.0]
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("tbl", df);
+    ctx
+}
+
+fn execute_with_params(sql: &str, params: SQLParams) -> DataFrame {
+    create_ctx()
+        .execute_with_params(sql, params)
+        .unwrap()
+        .collect()
+        .unwrap()
+}
+
+#[test]
+fn test_positional_params() {
+    let expected = df! { "id" => [2, 3] }.unwrap();
+    for sql in [
+        "SELECT id FROM tbl WHERE value > $1 AND name <> $2 ORDER BY id",
+        "SELECT id FROM tbl WHERE value > ?1 AND name <> ?2 ORDER BY id",
+        "SELECT id FROM tbl WHERE value > ? AND name <> ? ORDER BY id",
+    ] {
+        let params = SQLParams::new()
+            .with_value(10.0)
+            .with_value(Scalar::new(DataType::String, "d".into()));
+        let actual = execute_with_params(sql, params);
+        assert!(actual.equals(&expected), "{sql}: {actual:?}");
+    }
+
+    // parameters can be used in IN lists, and more than once
+    let sql = "SELECT id FROM tbl WHERE id IN ($1, $2) OR id = $1 + 2 ORDER BY id";
+    let actual = execute_with_params(sql, vec![Scalar::from(1), Scalar::from(4)].into());
+    let expected = df! { "id" => [1, 3, 4] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_named_params() {
+    let params = SQLParams::new()
+        .with_named_value("name", Scalar::new(DataType::String, "b".into()))
+        .with_named_value("factor", 2.5);
+    let sql = "SELECT id, value * :factor AS value FROM tbl WHERE name = :name OR name = $name";
+    let actual = execute_with_params(sql, params);
+    let expected = df! { "id" => [2], "value" => [50.0] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // values are bound as literals, not interpolated into the query
+    let params = SQLParams::new().with_named_value(
+        "name",
+        Scalar::new(DataType::String, "x' OR '1' = '1".into()),
+    );
+    let sql = "SELECT id FROM tbl WHERE name = :name";
+    assert_eq!(execute_with_params(sql, params).height(), 0);
+}
+
+#[test]
+fn test_prepare_execute() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      PREPARE by_value (FLOAT, INT) AS
+      SELECT id, name FROM tbl WHERE value >= $1 AND id <> $2 ORDER BY id"#;
+    ctx.execute(sql).unwrap().collect().unwrap();
+
+    let actual = ctx
+        .execute("EXECUTE by_value (25, 4)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "id" => [3], "name" => ["c"] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // prepared statements can be executed repeatedly (and see the current table data)
+    ctx.execute("INSERT INTO tbl VALUES (5, 'e', 50)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let actual = ctx
+        .execute("EXECUTE by_value ('15.5', 3)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "id" => [2, 4, 5], "name" => ["b", "d", "e"] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // prepared DML statements
+    ctx.execute("PREPARE del AS DELETE FROM tbl WHERE name = $1")
+        .unwrap()
+        .collect()
+        .unwrap();
+    ctx.execute("EXECUTE del ('e')").unwrap().collect().unwrap();
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    assert_eq!(actual.height(), 4);
+
+    ctx.execute("DEALLOCATE by_value")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(ctx.execute("EXECUTE by_value (25, 4)").is_err());
+}
+
+// a registry with a single UDF, counting how often it is looked up (ie: planned)
+struct CountingRegistry {
+    lookups: Arc<AtomicUsize>,
+}
+
+impl FunctionRegistry for CountingRegistry {
+    fn register(&mut self, _name: &str, _fun: UserDefinedFunction) -> PolarsResult<()> {
+        unimplemented!()
+    }
+
+    fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>> {
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        Ok(self.contains(name).then(|| {
+            UserDefinedFunction::new(
+                name.into(),
+                vec![Field::new("value".into(), DataType::Float64)],
+                GetOutput::same_type(),
+                |c: &mut [Column]| Ok(Some(&c[0] * 2)),
+            )
+        }))
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        name == "double"
+    }
+}
+
+#[test]
+fn test_prepare_execute_plan_reuse() {
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let mut ctx = create_ctx().with_function_registry(Arc::new(CountingRegistry {
+        lookups: lookups.clone(),
+    }));
+    let sql = r#"
+      PREPARE q (INT) AS
+      SELECT id, double(value) AS value FROM tbl WHERE id > $1 ORDER BY id"#;
+    ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(lookups.load(Ordering::Relaxed), 1);
+
+    // the query is planned by PREPARE, and executed with the bound values
+    for (min_id, expected) in [
+        (2, df! { "id" => [3, 4], "value" => [60.0, 80.0] }),
+        (3, df! { "id" => [4], "value" => [80.0] }),
+    ] {
+        let sql = format!("EXECUTE q ({min_id})");
+        let actual = ctx.execute(&sql).unwrap().collect().unwrap();
+        assert!(actual.equals(&expected.unwrap()), "{sql}: {actual:?}");
+    }
+    assert_eq!(lookups.load(Ordering::Relaxed), 1);
+
+    // ...until a table changes
+    ctx.execute("DELETE FROM tbl WHERE id = 4")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let actual = ctx.execute("EXECUTE q (2)").unwrap().collect().unwrap();
+    let expected = df! { "id" => [3], "value" => [60.0] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+    assert_eq!(lookups.load(Ordering::Relaxed), 2);
+
+    // without declared types, the query is planned by EXECUTE; it's planned with the values
+    // when the plan depends on them (as for IN lists)
+    ctx.execute("PREPARE r AS SELECT id FROM tbl WHERE name = $1 OR id IN ($2) ORDER BY id")
+        .unwrap()
+        .collect()
+        .unwrap();
+    for (sql, expected) in [
+        ("EXECUTE r ('a', 2)", [1, 2].as_slice()),
+        ("EXECUTE r ('c', 1)", &[1, 3]),
+        ("EXECUTE r ('x', 3)", &[3]),
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        let expected = df! { "id" => expected }.unwrap();
+        assert!(actual.equals(&expected), "{sql}: {actual:?}");
+    }
+
+    // ...also with declared types
+    ctx.execute("PREPARE s (INT) AS SELECT id FROM tbl WHERE id IN ($1)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let actual = ctx.execute("EXECUTE s (2)").unwrap().collect().unwrap();
+    let expected = df! { "id" => [2] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_param_errors() {
+    let mut ctx = create_ctx();
+    for (sql, params) in [
+        ("SELECT * FROM tbl WHERE id = $1", SQLParams::new()),
+        (
+            "SELECT * FROM tbl WHERE id = $2",
+            SQLParams::new().with_value(1),
+        ),
+        (
+            "SELECT * FROM tbl WHERE id = $0",
+            SQLParams::new().with_value(1),
+        ),
+        (
+            "SELECT * FROM tbl WHERE id = :id",
+            SQLParams::new().with_value(1),
+        ),
+    ] {
+        assert!(ctx.execute_with_params(sql, params).is_err(), "{sql}");
+    }
+
+    ctx.execute("PREPARE q (INT) AS SELECT * FROM tbl WHERE id = $1")
+        .unwrap()
+        .collect()
+        .unwrap();
+    for sql in [
+        "EXECUTE q",
+        "EXECUTE q (1, 2)",
+        "EXECUTE q ('one')",
+        "EXECUTE missing (1)",
+        "DEALLOCATE missing",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+
+    // queries with declared parameter types are planned (and checked) by PREPARE
+    for sql in [
+        "PREPARE r (INT) AS SELECT * FROM missing WHERE id = $1",
+        "PREPARE r (INT) AS SELECT missing FROM tbl WHERE id = $1",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+    assert!(ctx.execute("EXECUTE r (1)").is_err());
+}
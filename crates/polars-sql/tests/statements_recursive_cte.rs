@@ -0,0 +1,107 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let employees = df! {
+      "id" => [1, 2, 3, 4, 5],
+      "name" => ["Ann", "Bob", "Cid", "Dee", "Eve"],
+      "manager_id" => [None, Some(1), Some(1), Some(2), Some(4)],
+    }
+    .unwrap()
+    .lazy();
+    let edges = df! {
+      "src" => [1, 2, 3, 3],
+      "dst" => [2, 3, 1, 4],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("employees", employees);
+    ctx.register("edges", edges);
+    ctx
+}
+
+#[test]
+fn test_recursive_cte_sequence() {
+    let sql = r#"
+      WITH RECURSIVE seq(n) AS (
+          SELECT 1
+          UNION ALL
+          SELECT n + 1 FROM seq WHERE n < 5
+      )
+      SELECT n FROM seq"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "n" => [1, 2, 3, 4, 5] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() {
+    let sql = r#"
+      WITH RECURSIVE chain AS (
+          SELECT id, name, 0 AS depth FROM employees WHERE manager_id IS NULL
+          UNION ALL
+          SELECT e.id, e.name, c.depth + 1
+          FROM employees e
+          JOIN chain c ON e.manager_id = c.id
+      )
+      SELECT name, depth FROM chain ORDER BY depth, name"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "name" => ["Ann", "Bob", "Cid", "Dee", "Eve"],
+      "depth" => [0, 1, 1, 2, 3],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_recursive_cte_cycle() {
+    // UNION (without ALL) discards the rows that were already produced,
+    // so traversing a cyclic graph reaches a fixpoint
+    let sql = r#"
+      WITH RECURSIVE reachable(node) AS (
+          SELECT 1
+          UNION
+          SELECT edges.dst FROM edges JOIN reachable ON edges.src = reachable.node
+      )
+      SELECT node FROM reachable ORDER BY node"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "node" => [1, 2, 3, 4] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // ... whereas UNION ALL never completes
+    let sql = sql.replace("UNION\n", "UNION ALL\n");
+    let mut ctx = create_ctx().with_recursion_limit(10);
+    let err = ctx.execute(&sql).err().unwrap();
+    assert!(err.to_string().contains("10 iterations"), "{err}");
+}
+
+#[test]
+fn test_recursive_cte_without_self_reference() {
+    // a UNION CTE that does not reference itself is not iterated (even under RECURSIVE)
+    let sql = r#"
+      WITH RECURSIVE a AS (SELECT 1 AS n UNION ALL SELECT 2 AS n)
+      SELECT * FROM a ORDER BY n"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "n" => [1, 2] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_recursive_cte_order_by_limit() {
+    // ORDER BY and LIMIT of the CTE apply once, to the result of the recursion
+    let sql = r#"
+      WITH RECURSIVE seq(n) AS (
+          SELECT 1
+          UNION ALL
+          SELECT n + 1 FROM seq WHERE n < 5
+          ORDER BY n DESC
+          LIMIT 3
+      )
+      SELECT n FROM seq"#;
+    let actual = create_ctx().execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "n" => [5, 4, 3] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
@@ -134,6 +134,7 @@ fn visualize_plan_rec(
                 FileScan::Parquet { .. } => "parquet-source",
                 FileScan::Csv { .. } => "csv-source",
                 FileScan::Ipc { .. } => "ipc-source",
+                FileScan::IpcStream { .. } => "ipc-stream-source",
                 FileScan::NDJson { .. } => "ndjson-source",
                 FileScan::Anonymous { .. } => "anonymous-source",
             };
@@ -177,6 +177,28 @@ fn deflate() -> PolarsResult<()> {
     roundtrip(Some(Compression::Deflate))
 }
 
+#[test]
+fn empty_list_before_non_empty_list() -> PolarsResult<()> {
+    let list_dt = ArrowDataType::List(Box::new(Field::new(
+        "item".into(),
+        ArrowDataType::Int32,
+        true,
+    )));
+    let schema = ArrowSchema::from_iter([Field::new("list".into(), list_dt.clone(), false)]);
+    let expected = RecordBatchT::new(vec![Box::new(ListArray::<i32>::new(
+        list_dt,
+        vec![0, 0, 2, 2, 3].try_into().unwrap(),
+        Box::new(PrimitiveArray::<i32>::from_slice([1, 2, 3])),
+        None,
+    )) as Box<dyn Array>]);
+
+    let data = write_avro(&expected, &schema, None)?;
+    let (result, _) = read_avro(&data, None)?;
+
+    assert_eq!(result.columns()[0].as_ref(), expected.columns()[0].as_ref());
+    Ok(())
+}
+
 fn large_format_schema() -> ArrowSchema {
     ArrowSchema::from_iter([
         Field::new("large_utf8".into(), ArrowDataType::LargeUtf8, false),
@@ -39,6 +39,94 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+#[cfg(feature = "decompress")]
+fn write_csv_compressed() -> PolarsResult<()> {
+    use polars::io::utils::compression::TextCompression;
+
+    let mut df = create_df();
+    for compression in [
+        TextCompression::Gzip(Some(1)),
+        TextCompression::Zstd(Some(5)),
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_compression(Some(compression))
+            .finish(&mut df)?;
+
+        let out = CsvReader::new(Cursor::new(buf)).finish()?;
+        assert!(out.equals(&df));
+    }
+
+    let mut buf: Vec<u8> = Vec::new();
+    let result = CsvWriter::new(&mut buf)
+        .with_compression(Some(TextCompression::Zstd(Some(23))))
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "decompress", feature = "lazy"))]
+fn scan_csv_compressed_skip_rows_across_chunks() -> PolarsResult<()> {
+    use std::io::Write;
+
+    use polars::io::utils::compression::{MaybeCompressedWriter, TextCompression};
+
+    let mut data = "skip\n".repeat(50) + "a,b\n";
+    for i in 0..100 {
+        data += &format!("{i},\"x\ny\"\n");
+    }
+    let mut writer = MaybeCompressedWriter::new(vec![], Some(TextCompression::Gzip(None)))?;
+    writer.write_all(data.as_bytes())?;
+    writer.finish()?;
+
+    let path = std::env::temp_dir().join("polars_scan_csv_compressed_skip_rows.csv.gz");
+    std::fs::write(&path, writer.get_ref())?;
+
+    // Small chunks so that the rows to skip span many chunks.
+    std::env::set_var("POLARS_DECOMPRESS_CHUNK_SIZE", "16");
+    let out = LazyCsvReader::new(&path)
+        .with_skip_rows(50)
+        .with_skip_rows_after_header(30)
+        .finish()?
+        .collect();
+    std::env::remove_var("POLARS_DECOMPRESS_CHUNK_SIZE");
+    let out = out?;
+
+    assert_eq!(out.get_column_names(), ["a", "b"]);
+    assert_eq!(out.height(), 70);
+    assert_eq!(out.column("a")?.i64()?.get(0), Some(30));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "decompress", feature = "lazy"))]
+fn scan_csv_compressed_count_across_chunks() -> PolarsResult<()> {
+    use std::io::Write;
+
+    use polars::io::utils::compression::{MaybeCompressedWriter, TextCompression};
+
+    let mut data = "\n\na,b\n".to_string();
+    for i in 0..100 {
+        data += &format!("{i},\"x\ny\"\n");
+    }
+    let mut writer = MaybeCompressedWriter::new(vec![], Some(TextCompression::Gzip(None)))?;
+    writer.write_all(data.as_bytes())?;
+    writer.finish()?;
+
+    let path = std::env::temp_dir().join("polars_scan_csv_compressed_count.csv.gz");
+    std::fs::write(&path, writer.get_ref())?;
+
+    // Small chunks so that the file is counted in many chunks.
+    std::env::set_var("POLARS_DECOMPRESS_CHUNK_SIZE", "16");
+    let out = LazyCsvReader::new(&path).finish()?.select([len()]).collect();
+    std::env::remove_var("POLARS_DECOMPRESS_CHUNK_SIZE");
+
+    assert_eq!(out?.column("len")?.idx()?.get(0), Some(100));
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "timezones")]
 fn write_dates() {
@@ -185,3 +185,91 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn read_ndjson_strict() {
+    let data = r#"{"a": 1, "b": {"c": "x"}}
+
+{"a": 1.5, "b": {"c": "y", "d": 1}}
+{"a": 3, "b": {"c": 4}, "e": null}
+"#;
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new(
+            "b".into(),
+            DataType::Struct(vec![Field::new("c".into(), DataType::String)]),
+        ),
+    ]);
+
+    let err = JsonLineReader::new(Cursor::new(data))
+        .with_schema(Arc::new(schema.clone()))
+        .with_strict(true)
+        .finish()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("line 3: 'a' has type Int64, got float"),
+        "{err}"
+    );
+    assert!(err.contains("line 3: unknown field 'b.d'"), "{err}");
+    assert!(
+        err.contains("line 4: 'b.c' has type Utf8View, got integer"),
+        "{err}"
+    );
+    assert!(err.contains("line 4: unknown field 'e'"), "{err}");
+
+    let df = JsonLineReader::new(Cursor::new(&data[..26]))
+        .with_schema(Arc::new(schema.clone()))
+        .with_strict(true)
+        .finish()
+        .unwrap();
+    assert_eq!(df.shape(), (1, 2));
+
+    let err = JsonLineReader::new(Cursor::new(
+        "{\"a\": 1}\n{\"a\": null, \"b\": {\"c\": \"x\"}}\n",
+    ))
+    .with_schema(Arc::new(schema))
+    .with_strict(true)
+    .with_required_fields(Some(["a".into(), "b".into()].into()))
+    .finish()
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("line 1: missing required field 'b'"), "{err}");
+    assert!(err.contains("line 2: missing required field 'a'"), "{err}");
+}
+
+#[test]
+#[cfg(feature = "decompress")]
+fn write_json_compressed() -> PolarsResult<()> {
+    use polars::io::utils::compression::{maybe_decompress_bytes, TextCompression};
+
+    let mut df = df!(
+        "a" => [1i64, 2, 3],
+        "b" => [Some("x"), None, Some("z")],
+    )?;
+    for json_lines in [true, false] {
+        let json_format = || match json_lines {
+            true => JsonFormat::JsonLines,
+            false => JsonFormat::Json,
+        };
+        for compression in [
+            TextCompression::Gzip(Some(1)),
+            TextCompression::Zstd(Some(5)),
+        ] {
+            let mut buf: Vec<u8> = Vec::new();
+            JsonWriter::new(&mut buf)
+                .with_json_format(json_format())
+                .with_compression(Some(compression))
+                .finish(&mut df)?;
+
+            let mut decompressed = vec![];
+            let bytes = maybe_decompress_bytes(&buf, &mut decompressed)?;
+            assert_ne!(bytes, buf);
+            let out = JsonReader::new(Cursor::new(bytes))
+                .with_json_format(json_format())
+                .finish()?;
+            assert!(out.equals_missing(&df));
+        }
+    }
+    Ok(())
+}
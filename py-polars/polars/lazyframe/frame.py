@@ -2485,6 +2485,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
         maintain_order: bool = True,
+        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression_level: int | None = None,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2560,6 +2562,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the output file with this codec.
+        compression_level
+            The level of compression to use. Higher compression means smaller files on
+            disk.
+
+            - "gzip" : min-level: 0, max-level: 9.
+            - "zstd" : min-level: 1, max-level: 22.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2617,6 +2627,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             null_value=null_value,
             quote_style=quote_style,
             maintain_order=maintain_order,
+            compression=compression,
+            compression_level=compression_level,
         )
 
     @unstable()
@@ -2625,6 +2637,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path,
         *,
         maintain_order: bool = True,
+        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression_level: int | None = None,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2649,6 +2663,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the output file with this codec.
+        compression_level
+            The level of compression to use. Higher compression means smaller files on
+            disk.
+
+            - "gzip" : min-level: 0, max-level: 9.
+            - "zstd" : min-level: 1, max-level: 22.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2683,7 +2705,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             no_optimization=no_optimization,
         )
 
-        return lf.sink_json(path=path, maintain_order=maintain_order)
+        return lf.sink_json(
+            path=path,
+            maintain_order=maintain_order,
+            compression=compression,
+            compression_level=compression_level,
+        )
 
     def _set_sink_optimizations(
         self,
@@ -83,6 +83,18 @@ def test_string_concat_errors(invalid_concat: str) -> None:
         pl.SQLContext(data=lf).execute(f"SELECT {invalid_concat} FROM data")
 
 
+def test_string_hash_digest() -> None:
+    df = pl.DataFrame({"txt": ["abc", None]})
+    res = df.sql("SELECT MD5(txt) AS md5, SHA256(txt) AS sha256 FROM self")
+    assert res.to_dict(as_series=False) == {
+        "md5": ["900150983cd24fb0d6963f7d28e17f72", None],
+        "sha256": [
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            None,
+        ],
+    }
+
+
 def test_string_left_right_reverse() -> None:
     df = pl.DataFrame({"txt": ["abcde", "abc", "a", None]})
     ctx = pl.SQLContext(df=df)
@@ -154,6 +154,8 @@ def test_sink_csv_with_options() -> None:
             null_value="BOOM",
             quote_style="always",
             maintain_order=False,
+            compression="gzip",
+            compression_level=3,
         )
 
         ldf.optimization_toggle().sink_csv.assert_called_with(
@@ -172,9 +174,31 @@ def test_sink_csv_with_options() -> None:
             null_value="BOOM",
             quote_style="always",
             maintain_order=False,
+            compression="gzip",
+            compression_level=3,
         )
 
 
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("compression", "magic"), [("gzip", b"\x1f\x8b"), ("zstd", b"\x28\xb5\x2f\xfd")]
+)
+def test_sink_csv_compressed(compression: Any, magic: bytes, tmp_path: Path) -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": ["x", "y", None]})
+    path = tmp_path / "sink.csv"
+
+    lf.sink_csv(path, compression=compression, compression_level=3)
+
+    assert path.read_bytes().startswith(magic)
+    assert_frame_equal(pl.scan_csv(path).collect(), lf.collect())
+
+
+def test_sink_csv_invalid_compression() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    with pytest.raises(ValueError, match="`compression` must be one of"):
+        lf.sink_csv("test.csv", compression="lz4")  # type: ignore[arg-type]
+
+
 @pytest.mark.parametrize(("value"), ["abc", ""])
 def test_sink_csv_exception_for_separator(value: str) -> None:
     df = pl.LazyFrame({"dummy": ["abc"]})
@@ -247,6 +271,20 @@ def test_sink_ndjson_should_write_same_data(
     assert_frame_equal(df, expected)
 
 
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("compression", "magic"), [("gzip", b"\x1f\x8b"), ("zstd", b"\x28\xb5\x2f\xfd")]
+)
+def test_sink_ndjson_compressed(compression: Any, magic: bytes, tmp_path: Path) -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": ["x", "y", None]})
+    path = tmp_path / "sink.ndjson"
+
+    lf.sink_ndjson(path, compression=compression)
+
+    assert path.read_bytes().startswith(magic)
+    assert_frame_equal(pl.scan_ndjson(path).collect(), lf.collect())
+
+
 @pytest.mark.write_disk
 def test_parquet_eq_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)